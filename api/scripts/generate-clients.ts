@@ -3,9 +3,17 @@
 import { execSync } from "child_process";
 import { join } from "path";
 import { parseArgs } from "util";
-import { mkdirSync, existsSync, copyFileSync, rmSync, readdirSync } from "fs";
-
-const LANGUAGE_OPTIONS = ["typescript", "python"] as string[];
+import { tmpdir } from "os";
+import {
+  mkdirSync,
+  mkdtempSync,
+  existsSync,
+  copyFileSync,
+  rmSync,
+  readdirSync,
+} from "fs";
+
+const LANGUAGE_OPTIONS = ["typescript", "python", "rust"] as string[];
 type Language = (typeof LANGUAGE_OPTIONS)[number];
 
 const API_OPTIONS = ["algod" /*, "indexer", "kmd"*/] as string[];
@@ -31,6 +39,42 @@ const OUTPUT_DIR = join(process.cwd(), "..", "packages");
 const TEMPLATES_DIR = join(process.cwd(), "oas_templates");
 const TYPESCRIPT_TEMPLATE = join(TEMPLATES_DIR, "typescript");
 const PYTHON_TEMPLATE = join(TEMPLATES_DIR, "python");
+const RUST_TEMPLATE = join(TEMPLATES_DIR, "rust_msgpack");
+
+// The algod models which can be returned as msgpack, generated into the algokit_transact crate
+const RUST_OUTPUT_DIR = join(
+  process.cwd(),
+  "..",
+  "crates",
+  "algokit_transact",
+  "src",
+  "msgpack",
+  "models",
+  "algod",
+);
+const RUST_MODELS = [
+  "Account",
+  "AccountApplicationInformation200Response",
+  "AccountAssetInformation200Response",
+  "AccountParticipation",
+  "AccountStateDelta",
+  "Application",
+  "ApplicationLocalState",
+  "ApplicationParams",
+  "ApplicationStateSchema",
+  "Asset",
+  "AssetHolding",
+  "AssetParams",
+  "Box",
+  "EvalDelta",
+  "EvalDeltaKeyValue",
+  "PendingTransactionResponse",
+  "StateProof",
+  "StateProofMessage",
+  "TealKeyValue",
+  "TealValue",
+  "TransactionParams200Response",
+];
 
 if (!existsSync(OUTPUT_DIR)) {
   mkdirSync(OUTPUT_DIR, { recursive: true });
@@ -97,6 +141,39 @@ function generatePythonClient(outputDir: string) {
   console.log(`Executing: ${cmd}`);
   execSync(cmd, { stdio: "inherit" });
 }
+
+function generateRustModels(outputDir: string) {
+  // The rust generator can only write a whole crate, so only the models are copied from a temp dir
+  const generatedDir = mkdtempSync(join(tmpdir(), "algod-rust-"));
+  copyIgnoreFile(RUST_TEMPLATE, generatedDir);
+
+  const cmd = [
+    "bunx openapi-generator-cli generate",
+    `-i ${SPEC_PATH}`,
+    "-g rust",
+    `-o ${generatedDir}`,
+    `-t ${RUST_TEMPLATE}`,
+    `-c ${RUST_TEMPLATE}/openapi-config.yaml`,
+    `--global-property=models="${RUST_MODELS.join(":")}",supportingFiles,modelDocs=false,modelTests=false`,
+  ].join(" ");
+
+  console.log(`Executing: ${cmd}`);
+  execSync(cmd, { stdio: "inherit" });
+
+  rmSync(outputDir, { recursive: true, force: true });
+  mkdirSync(outputDir, { recursive: true });
+  const modelsDir = join(generatedDir, "src", "models");
+  for (const file of readdirSync(modelsDir).filter((f) => f.endsWith(".rs"))) {
+    copyFileSync(join(modelsDir, file), join(outputDir, file));
+  }
+  rmSync(generatedDir, { recursive: true, force: true });
+
+  execSync("cargo fmt -p algokit_transact", {
+    stdio: "inherit",
+    cwd: join(process.cwd(), ".."),
+  });
+}
+
 function main() {
   try {
     for (const api of apis) {
@@ -117,6 +194,12 @@ function main() {
         generatePythonClient(outputDir);
         console.log(`Python ${apis} client generated successfully!`);
       }
+
+      if (languages.includes("rust")) {
+        console.log(`Generating Rust ${apis} models...`);
+        generateRustModels(RUST_OUTPUT_DIR);
+        console.log(`Rust ${apis} models generated successfully!`);
+      }
     }
 
     console.log("Client generation completed!");
@@ -0,0 +1,11 @@
+#![no_main]
+
+use algokit_transact::{AlgorandMsgpack, SignedTransaction};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(signed_transaction) = SignedTransaction::decode(data) {
+        // Anything which decodes must re-encode
+        signed_transaction.encode().unwrap();
+    }
+});
@@ -0,0 +1,11 @@
+#![no_main]
+
+use algokit_transact::{AlgorandMsgpack, Transaction};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(transaction) = Transaction::decode(data) {
+        // Anything which decodes must re-encode
+        transaction.encode().unwrap();
+    }
+});
@@ -0,0 +1,13 @@
+#![no_main]
+
+use algokit_transact::{decode_msgpack_to_json, encode_json_to_msgpack, supported_models};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    for model_type in supported_models() {
+        let _ = decode_msgpack_to_json(model_type, data);
+        if let Ok(json) = std::str::from_utf8(data) {
+            let _ = encode_json_to_msgpack(model_type, json);
+        }
+    }
+});
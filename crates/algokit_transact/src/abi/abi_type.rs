@@ -0,0 +1,264 @@
+use crate::constants::ALGORAND_PUBLIC_KEY_BYTE_LENGTH;
+use crate::error::AlgoKitTransactError;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::str::FromStr;
+
+const MAX_UINT_BIT_SIZE: u16 = 512;
+const MAX_UFIXED_PRECISION: u8 = 160;
+/// The maximum nesting of arrays and tuples in a type string, so untrusted type strings can't
+/// overflow the stack when parsed.
+const MAX_TYPE_DEPTH: usize = 128;
+
+/// An ARC-4 ABI type.
+///
+/// Types are parsed from, and displayed as, their ARC-4 type string, e.g.
+/// `"(uint64,byte[32])[]".parse::<AbiType>()`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum AbiType {
+    /// An unsigned integer of `bit_size` bits (`uint<N>`).
+    Uint(u16),
+
+    /// An unsigned fixed-point decimal of `bit_size` bits with `precision` decimal places
+    /// (`ufixed<N>x<M>`).
+    Ufixed { bit_size: u16, precision: u8 },
+
+    /// A single byte (`byte`).
+    Byte,
+
+    /// A boolean (`bool`).
+    Bool,
+
+    /// A 32-byte Algorand address (`address`).
+    Address,
+
+    /// A UTF-8 string (`string`).
+    String,
+
+    /// A fixed length array (`<type>[<N>]`).
+    StaticArray(Box<AbiType>, usize),
+
+    /// A variable length array (`<type>[]`).
+    DynamicArray(Box<AbiType>),
+
+    /// A tuple of types (`(<type>,...)`).
+    Tuple(Vec<AbiType>),
+}
+
+impl AbiType {
+    /// Returns true if the encoded length of values of this type varies, i.e. the type is or
+    /// contains a `string` or dynamic array.
+    pub fn is_dynamic(&self) -> bool {
+        match self {
+            AbiType::String | AbiType::DynamicArray(_) => true,
+            AbiType::StaticArray(child, _) => child.is_dynamic(),
+            AbiType::Tuple(children) => children.iter().any(AbiType::is_dynamic),
+            _ => false,
+        }
+    }
+
+    /// Returns the encoded length in bytes of values of this static type.
+    ///
+    /// # Returns
+    /// The encoded length or an error if the type is dynamic or its length overflows a `usize`.
+    pub fn byte_len(&self) -> Result<usize, AlgoKitTransactError> {
+        let too_large =
+            || AlgoKitTransactError::InvalidAbiType(format!("{} is too large to be encoded", self));
+        match self {
+            AbiType::Uint(bit_size) | AbiType::Ufixed { bit_size, .. } => {
+                Ok(*bit_size as usize / 8)
+            }
+            AbiType::Byte | AbiType::Bool => Ok(1),
+            AbiType::Address => Ok(ALGORAND_PUBLIC_KEY_BYTE_LENGTH),
+            AbiType::StaticArray(child, length) => match child.as_ref() {
+                AbiType::Bool => Ok(length.div_ceil(8)),
+                child => child.byte_len()?.checked_mul(*length).ok_or_else(too_large),
+            },
+            AbiType::Tuple(children) => {
+                let mut len: usize = 0;
+                let mut i = 0;
+                while i < children.len() {
+                    let child_len = if children[i] == AbiType::Bool {
+                        i += consecutive_bool_count(&children[i..]);
+                        1
+                    } else {
+                        i += 1;
+                        children[i - 1].byte_len()?
+                    };
+                    len = len.checked_add(child_len).ok_or_else(too_large)?;
+                }
+                Ok(len)
+            }
+            AbiType::String | AbiType::DynamicArray(_) => {
+                Err(AlgoKitTransactError::InvalidAbiType(format!(
+                    "{} is a dynamic type and has no fixed length",
+                    self
+                )))
+            }
+        }
+    }
+}
+
+/// Returns the number of consecutive `bool` types at the start of `types`, up to the 8 which
+/// can be packed into a single byte.
+pub(super) fn consecutive_bool_count(types: &[AbiType]) -> usize {
+    types
+        .iter()
+        .take(8)
+        .take_while(|t| **t == AbiType::Bool)
+        .count()
+}
+
+impl FromStr for AbiType {
+    type Err = AlgoKitTransactError;
+
+    /// Parses an ARC-4 ABI type string.
+    ///
+    /// # Parameters
+    /// * `s` - The type string, e.g. `uint64` or `(address,string[])`
+    ///
+    /// # Returns
+    /// The ABI type or an error if the type string is invalid.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_type(s, 0)
+    }
+}
+
+/// Parses a type string nested within `depth` arrays and tuples.
+fn parse_type(s: &str, depth: usize) -> Result<AbiType, AlgoKitTransactError> {
+    let invalid = |reason: &str| AlgoKitTransactError::InvalidAbiType(format!("{}: {}", s, reason));
+    if depth > MAX_TYPE_DEPTH {
+        return Err(invalid("arrays and tuples are nested too deeply"));
+    }
+
+    if let Some(inner) = s.strip_suffix(']') {
+        let open = inner
+            .rfind('[')
+            .ok_or_else(|| invalid("array is missing an opening bracket"))?;
+        let child = parse_type(&inner[..open], depth + 1)?;
+        let length = &inner[open + 1..];
+
+        if length.is_empty() {
+            return Ok(AbiType::DynamicArray(Box::new(child)));
+        }
+        if !length.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(invalid("array length must be a number"));
+        }
+        let length = length
+            .parse()
+            .map_err(|_| invalid("array length is too large"))?;
+        return Ok(AbiType::StaticArray(Box::new(child), length));
+    }
+
+    if let Some(inner) = s.strip_prefix('(') {
+        let inner = inner
+            .strip_suffix(')')
+            .ok_or_else(|| invalid("tuple is missing a closing parenthesis"))?;
+        return Ok(AbiType::Tuple(
+            split_tuple_types(inner)
+                .ok_or_else(|| invalid("tuple has unbalanced parentheses"))?
+                .into_iter()
+                .map(|child| parse_type(child, depth + 1))
+                .collect::<Result<_, _>>()?,
+        ));
+    }
+
+    match s {
+        "byte" => return Ok(AbiType::Byte),
+        "bool" => return Ok(AbiType::Bool),
+        "address" => return Ok(AbiType::Address),
+        "string" => return Ok(AbiType::String),
+        _ => {}
+    }
+
+    if let Some(bit_size) = s.strip_prefix("uint") {
+        return Ok(AbiType::Uint(parse_bit_size(bit_size).ok_or_else(
+            || invalid("bit size must be a multiple of 8 between 8 and 512"),
+        )?));
+    }
+
+    if let Some(sizes) = s.strip_prefix("ufixed") {
+        let (bit_size, precision) = sizes
+            .split_once('x')
+            .ok_or_else(|| invalid("ufixed must be of the form ufixed<N>x<M>"))?;
+        let bit_size = parse_bit_size(bit_size)
+            .ok_or_else(|| invalid("bit size must be a multiple of 8 between 8 and 512"))?;
+        let precision = parse_number(precision)
+            .filter(|p| (1..=MAX_UFIXED_PRECISION as usize).contains(p))
+            .ok_or_else(|| invalid("precision must be between 1 and 160"))?;
+        return Ok(AbiType::Ufixed {
+            bit_size,
+            precision: precision as u8,
+        });
+    }
+
+    Err(invalid("unknown type"))
+}
+
+fn parse_number(s: &str) -> Option<usize> {
+    if s.is_empty() || !s.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    s.parse().ok()
+}
+
+fn parse_bit_size(s: &str) -> Option<u16> {
+    parse_number(s)
+        .filter(|n| n % 8 == 0 && (8..=MAX_UINT_BIT_SIZE as usize).contains(n))
+        .map(|n| n as u16)
+}
+
+/// Splits the inside of a tuple type string on the commas which are not within a nested tuple.
+pub(super) fn split_tuple_types(s: &str) -> Option<Vec<&str>> {
+    if s.is_empty() {
+        return Some(vec![]);
+    }
+
+    let mut types = vec![];
+    let mut depth = 0usize;
+    let mut start = 0;
+    for (i, c) in s.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth = depth.checked_sub(1)?,
+            ',' if depth == 0 => {
+                types.push(&s[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    if depth != 0 {
+        return None;
+    }
+    types.push(&s[start..]);
+    Some(types)
+}
+
+impl Display for AbiType {
+    /// Formats the type as its ARC-4 type string.
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            AbiType::Uint(bit_size) => write!(f, "uint{}", bit_size),
+            AbiType::Ufixed {
+                bit_size,
+                precision,
+            } => write!(f, "ufixed{}x{}", bit_size, precision),
+            AbiType::Byte => write!(f, "byte"),
+            AbiType::Bool => write!(f, "bool"),
+            AbiType::Address => write!(f, "address"),
+            AbiType::String => write!(f, "string"),
+            AbiType::StaticArray(child, length) => write!(f, "{}[{}]", child, length),
+            AbiType::DynamicArray(child) => write!(f, "{}[]", child),
+            AbiType::Tuple(children) => {
+                write!(f, "(")?;
+                for (i, child) in children.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{}", child)?;
+                }
+                write!(f, ")")
+            }
+        }
+    }
+}
@@ -0,0 +1,90 @@
+use crate::address::Address;
+use num_bigint::BigUint;
+
+/// A value of an ARC-4 ABI type.
+#[derive(Debug, PartialEq, Clone)]
+pub enum AbiValue {
+    /// A value of a `uint<N>` type, or the raw (unscaled) value of a `ufixed<N>x<M>` type.
+    Uint(BigUint),
+
+    /// A value of the `bool` type.
+    Bool(bool),
+
+    /// A value of the `byte` type.
+    Byte(u8),
+
+    /// A value of the `address` type.
+    Address(Address),
+
+    /// A value of the `string` type.
+    String(String),
+
+    /// The elements of a static array, dynamic array or tuple.
+    Array(Vec<AbiValue>),
+}
+
+impl AbiValue {
+    /// Creates an array of `byte` values, as used by the `byte[N]` and `byte[]` types.
+    ///
+    /// # Parameters
+    /// * `bytes` - The bytes of the array
+    pub fn from_bytes(bytes: &[u8]) -> Self {
+        AbiValue::Array(bytes.iter().copied().map(AbiValue::Byte).collect())
+    }
+
+    /// Returns the bytes of an array of `byte` values, or `None` if this is not a byte array.
+    pub fn as_bytes(&self) -> Option<Vec<u8>> {
+        match self {
+            AbiValue::Array(values) => values
+                .iter()
+                .map(|value| match value {
+                    AbiValue::Byte(byte) => Some(*byte),
+                    _ => None,
+                })
+                .collect(),
+            _ => None,
+        }
+    }
+}
+
+impl From<u64> for AbiValue {
+    fn from(value: u64) -> Self {
+        AbiValue::Uint(BigUint::from(value))
+    }
+}
+
+impl From<BigUint> for AbiValue {
+    fn from(value: BigUint) -> Self {
+        AbiValue::Uint(value)
+    }
+}
+
+impl From<bool> for AbiValue {
+    fn from(value: bool) -> Self {
+        AbiValue::Bool(value)
+    }
+}
+
+impl From<Address> for AbiValue {
+    fn from(value: Address) -> Self {
+        AbiValue::Address(value)
+    }
+}
+
+impl From<String> for AbiValue {
+    fn from(value: String) -> Self {
+        AbiValue::String(value)
+    }
+}
+
+impl From<&str> for AbiValue {
+    fn from(value: &str) -> Self {
+        AbiValue::String(value.to_string())
+    }
+}
+
+impl From<Vec<AbiValue>> for AbiValue {
+    fn from(value: Vec<AbiValue>) -> Self {
+        AbiValue::Array(value)
+    }
+}
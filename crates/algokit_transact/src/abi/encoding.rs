@@ -0,0 +1,348 @@
+use super::abi_type::consecutive_bool_count;
+use super::{AbiType, AbiValue};
+use crate::address::Address;
+use crate::constants::ALGORAND_PUBLIC_KEY_BYTE_LENGTH;
+use crate::error::AlgoKitTransactError;
+use num_bigint::BigUint;
+
+/// The byte length of the length prefix of dynamic arrays and strings, and of the offsets of
+/// dynamic values within a tuple.
+const LENGTH_ENCODE_BYTE_SIZE: usize = 2;
+
+const BOOL_TRUE: u8 = 0x80;
+const BOOL_FALSE: u8 = 0x00;
+
+impl AbiType {
+    /// Encodes a value of this type according to the ARC-4 encoding rules.
+    ///
+    /// # Parameters
+    /// * `value` - The value to encode
+    ///
+    /// # Returns
+    /// The encoded bytes or an error if the value is not a valid value of this type.
+    pub fn encode(&self, value: &AbiValue) -> Result<Vec<u8>, AlgoKitTransactError> {
+        match (self, value) {
+            (AbiType::Uint(bit_size), AbiValue::Uint(n))
+            | (AbiType::Ufixed { bit_size, .. }, AbiValue::Uint(n)) => {
+                encode_uint(self, *bit_size, n)
+            }
+            (AbiType::Bool, AbiValue::Bool(b)) => Ok(vec![if *b { BOOL_TRUE } else { BOOL_FALSE }]),
+            (AbiType::Byte, AbiValue::Byte(b)) => Ok(vec![*b]),
+            (AbiType::Address, AbiValue::Address(address)) => Ok(address.pub_key.to_vec()),
+            (AbiType::String, AbiValue::String(s)) => {
+                let mut encoded = encode_length(self, s.len())?;
+                encoded.extend_from_slice(s.as_bytes());
+                Ok(encoded)
+            }
+            (AbiType::StaticArray(child, length), AbiValue::Array(values)) => {
+                if values.len() != *length {
+                    return Err(encoding_error(
+                        self,
+                        format!("expected {} elements but got {}", length, values.len()),
+                    ));
+                }
+                encode_tuple(&vec![child.as_ref().clone(); *length], values)
+            }
+            (AbiType::DynamicArray(child), AbiValue::Array(values)) => {
+                let mut encoded = encode_length(self, values.len())?;
+                encoded.extend(encode_tuple(
+                    &vec![child.as_ref().clone(); values.len()],
+                    values,
+                )?);
+                Ok(encoded)
+            }
+            (AbiType::Tuple(children), AbiValue::Array(values)) => {
+                if values.len() != children.len() {
+                    return Err(encoding_error(
+                        self,
+                        format!(
+                            "expected {} elements but got {}",
+                            children.len(),
+                            values.len()
+                        ),
+                    ));
+                }
+                encode_tuple(children, values)
+            }
+            _ => Err(encoding_error(
+                self,
+                format!("{:?} is not a valid value", value),
+            )),
+        }
+    }
+
+    /// Decodes a value of this type according to the ARC-4 encoding rules.
+    ///
+    /// # Parameters
+    /// * `bytes` - The encoded value
+    ///
+    /// # Returns
+    /// The decoded value or an error if the bytes are not a valid encoding of this type.
+    pub fn decode(&self, bytes: &[u8]) -> Result<AbiValue, AlgoKitTransactError> {
+        if !self.is_dynamic() {
+            let expected = self.byte_len()?;
+            if bytes.len() != expected {
+                return Err(decoding_error(
+                    self,
+                    format!("expected {} bytes but got {}", expected, bytes.len()),
+                ));
+            }
+        }
+
+        match self {
+            AbiType::Uint(_) | AbiType::Ufixed { .. } => {
+                Ok(AbiValue::Uint(BigUint::from_bytes_be(bytes)))
+            }
+            AbiType::Bool => match bytes[0] {
+                BOOL_TRUE => Ok(AbiValue::Bool(true)),
+                BOOL_FALSE => Ok(AbiValue::Bool(false)),
+                b => Err(decoding_error(self, format!("{:#04x} is not a boolean", b))),
+            },
+            AbiType::Byte => Ok(AbiValue::Byte(bytes[0])),
+            AbiType::Address => {
+                let mut pub_key = [0u8; ALGORAND_PUBLIC_KEY_BYTE_LENGTH];
+                pub_key.copy_from_slice(bytes);
+                Ok(AbiValue::Address(Address::from_pubkey(&pub_key)))
+            }
+            AbiType::String => {
+                let (length, content) = decode_length(self, bytes)?;
+                if content.len() != length {
+                    return Err(decoding_error(
+                        self,
+                        format!("expected {} bytes but got {}", length, content.len()),
+                    ));
+                }
+                String::from_utf8(content.to_vec())
+                    .map(AbiValue::String)
+                    .map_err(|e| decoding_error(self, e.to_string()))
+            }
+            AbiType::StaticArray(child, length) => {
+                // Static elements have been checked against the length of the bytes, but empty
+                // elements and the heads of dynamic elements must be checked before allocating
+                let fits = if child.is_dynamic() {
+                    length
+                        .checked_mul(LENGTH_ENCODE_BYTE_SIZE)
+                        .is_some_and(|heads_len| heads_len <= bytes.len())
+                } else {
+                    self.byte_len()? > 0 || *length <= u16::MAX as usize
+                };
+                if !fits {
+                    return Err(decoding_error(
+                        self,
+                        format!(
+                            "{} elements can't be decoded from {} bytes",
+                            length,
+                            bytes.len()
+                        ),
+                    ));
+                }
+                decode_tuple(self, &vec![child.as_ref().clone(); *length], bytes)
+            }
+            AbiType::DynamicArray(child) => {
+                let (length, content) = decode_length(self, bytes)?;
+                decode_tuple(self, &vec![child.as_ref().clone(); length], content)
+            }
+            AbiType::Tuple(children) => decode_tuple(self, children, bytes),
+        }
+    }
+}
+
+fn encoding_error(abi_type: &AbiType, reason: String) -> AlgoKitTransactError {
+    AlgoKitTransactError::AbiEncodingError(format!("{}: {}", abi_type, reason))
+}
+
+fn decoding_error(abi_type: &AbiType, reason: String) -> AlgoKitTransactError {
+    AlgoKitTransactError::AbiDecodingError(format!("{}: {}", abi_type, reason))
+}
+
+fn encode_uint(
+    abi_type: &AbiType,
+    bit_size: u16,
+    value: &BigUint,
+) -> Result<Vec<u8>, AlgoKitTransactError> {
+    if value.bits() > bit_size as u64 {
+        return Err(encoding_error(
+            abi_type,
+            format!("{} does not fit in {} bits", value, bit_size),
+        ));
+    }
+
+    let byte_len = bit_size as usize / 8;
+    let value_bytes = value.to_bytes_be();
+    let mut encoded = vec![0u8; byte_len];
+    encoded[byte_len - value_bytes.len()..].copy_from_slice(&value_bytes);
+    Ok(encoded)
+}
+
+fn encode_length(abi_type: &AbiType, length: usize) -> Result<Vec<u8>, AlgoKitTransactError> {
+    u16::try_from(length)
+        .map(|length| length.to_be_bytes().to_vec())
+        .map_err(|_| {
+            encoding_error(
+                abi_type,
+                format!("length {} exceeds the maximum of {}", length, u16::MAX),
+            )
+        })
+}
+
+fn decode_length<'a>(
+    abi_type: &AbiType,
+    bytes: &'a [u8],
+) -> Result<(usize, &'a [u8]), AlgoKitTransactError> {
+    if bytes.len() < LENGTH_ENCODE_BYTE_SIZE {
+        return Err(decoding_error(
+            abi_type,
+            "not enough bytes for the length prefix".to_string(),
+        ));
+    }
+    let (length, content) = bytes.split_at(LENGTH_ENCODE_BYTE_SIZE);
+    Ok((u16::from_be_bytes([length[0], length[1]]) as usize, content))
+}
+
+/// Encodes a sequence of values as the concatenation of their heads followed by the tails of
+/// the dynamic values, with consecutive booleans packed into single bytes.
+fn encode_tuple(types: &[AbiType], values: &[AbiValue]) -> Result<Vec<u8>, AlgoKitTransactError> {
+    let mut heads: Vec<Vec<u8>> = Vec::with_capacity(types.len());
+    let mut tails: Vec<Vec<u8>> = Vec::with_capacity(types.len());
+    let mut dynamic_indexes = Vec::new();
+
+    let mut i = 0;
+    while i < types.len() {
+        let abi_type = &types[i];
+        if abi_type.is_dynamic() {
+            dynamic_indexes.push(heads.len());
+            heads.push(vec![0u8; LENGTH_ENCODE_BYTE_SIZE]);
+            tails.push(abi_type.encode(&values[i])?);
+            i += 1;
+        } else if *abi_type == AbiType::Bool {
+            let count = consecutive_bool_count(&types[i..]);
+            let mut packed = 0u8;
+            for (bit, value) in values[i..i + count].iter().enumerate() {
+                match value {
+                    AbiValue::Bool(true) => packed |= BOOL_TRUE >> bit,
+                    AbiValue::Bool(false) => {}
+                    _ => {
+                        return Err(encoding_error(
+                            abi_type,
+                            format!("{:?} is not a valid value", value),
+                        ))
+                    }
+                }
+            }
+            heads.push(vec![packed]);
+            tails.push(vec![]);
+            i += count;
+        } else {
+            heads.push(abi_type.encode(&values[i])?);
+            tails.push(vec![]);
+            i += 1;
+        }
+    }
+
+    let mut offset: usize = heads.iter().map(Vec::len).sum();
+    for (index, tail) in tails.iter().enumerate() {
+        if dynamic_indexes.contains(&index) {
+            let encoded_offset = u16::try_from(offset).map_err(|_| {
+                AlgoKitTransactError::AbiEncodingError(format!(
+                    "offset {} of dynamic value exceeds the maximum of {}",
+                    offset,
+                    u16::MAX
+                ))
+            })?;
+            heads[index] = encoded_offset.to_be_bytes().to_vec();
+        }
+        offset += tail.len();
+    }
+
+    Ok(heads.into_iter().chain(tails).flatten().collect())
+}
+
+/// Decodes a sequence of values encoded by [`encode_tuple`].
+fn decode_tuple(
+    parent: &AbiType,
+    types: &[AbiType],
+    bytes: &[u8],
+) -> Result<AbiValue, AlgoKitTransactError> {
+    let mut values: Vec<Option<AbiValue>> = vec![None; types.len()];
+    // The index in `types` and start offset of each dynamic value
+    let mut dynamic_offsets: Vec<(usize, usize)> = Vec::new();
+    let mut cursor = 0;
+
+    let read = |start: usize, len: usize| {
+        start
+            .checked_add(len)
+            .and_then(|end| bytes.get(start..end))
+            .ok_or_else(|| decoding_error(parent, "not enough bytes".to_string()))
+    };
+
+    let mut i = 0;
+    while i < types.len() {
+        let abi_type = &types[i];
+        if abi_type.is_dynamic() {
+            let offset = read(cursor, LENGTH_ENCODE_BYTE_SIZE)?;
+            dynamic_offsets.push((i, u16::from_be_bytes([offset[0], offset[1]]) as usize));
+            cursor += LENGTH_ENCODE_BYTE_SIZE;
+            i += 1;
+        } else if *abi_type == AbiType::Bool {
+            let count = consecutive_bool_count(&types[i..]);
+            let packed = read(cursor, 1)?[0];
+            let unused_bits = 0xffu8.checked_shr(count as u32).unwrap_or(0);
+            if packed & unused_bits != 0 {
+                return Err(decoding_error(
+                    parent,
+                    format!(
+                        "{:#04x} is not a valid packing of {} booleans",
+                        packed, count
+                    ),
+                ));
+            }
+            for bit in 0..count {
+                values[i + bit] = Some(AbiValue::Bool(packed & (BOOL_TRUE >> bit) != 0));
+            }
+            cursor += 1;
+            i += count;
+        } else {
+            let len = abi_type.byte_len()?;
+            values[i] = Some(abi_type.decode(read(cursor, len)?)?);
+            cursor += len;
+            i += 1;
+        }
+    }
+
+    let mut expected_offset = cursor;
+    for (n, (index, offset)) in dynamic_offsets.iter().enumerate() {
+        if *offset != expected_offset {
+            return Err(decoding_error(
+                parent,
+                format!(
+                    "expected dynamic value at offset {} but got {}",
+                    expected_offset, offset
+                ),
+            ));
+        }
+        let end = match dynamic_offsets.get(n + 1) {
+            Some((_, next_offset)) if *next_offset >= *offset => *next_offset,
+            Some((_, next_offset)) => {
+                return Err(decoding_error(
+                    parent,
+                    format!("dynamic value offset {} is out of order", next_offset),
+                ))
+            }
+            None => bytes.len(),
+        };
+        values[*index] = Some(types[*index].decode(read(*offset, end - *offset)?)?);
+        expected_offset = end;
+    }
+
+    if expected_offset != bytes.len() {
+        return Err(decoding_error(
+            parent,
+            format!(
+                "{} unexpected trailing bytes",
+                bytes.len() - expected_offset
+            ),
+        ));
+    }
+
+    Ok(AbiValue::Array(values.into_iter().flatten().collect()))
+}
@@ -0,0 +1,108 @@
+use super::{AbiType, AbiValue};
+use crate::address::Address;
+use crate::error::AlgoKitTransactError;
+use num_bigint::BigUint;
+use serde_json::Value;
+
+impl AbiType {
+    /// Converts a JSON value to a value of this type.
+    ///
+    /// Integers are JSON numbers or, when too large for a JSON number, decimal strings. The
+    /// value of a `ufixed` type is its raw (unscaled) integer. Bytes are numbers, addresses are
+    /// base32 address strings, and arrays and tuples are JSON arrays.
+    ///
+    /// # Parameters
+    /// * `json` - The JSON value
+    ///
+    /// # Returns
+    /// The ABI value or an error if the JSON value is not a valid value of this type.
+    pub fn value_from_json(&self, json: &Value) -> Result<AbiValue, AlgoKitTransactError> {
+        let invalid = || {
+            AlgoKitTransactError::AbiEncodingError(format!(
+                "{}: {} is not a valid value",
+                self, json
+            ))
+        };
+
+        match (self, json) {
+            (AbiType::Uint(_) | AbiType::Ufixed { .. }, Value::Number(n)) => {
+                n.as_u64().map(AbiValue::from).ok_or_else(invalid)
+            }
+            (AbiType::Uint(_) | AbiType::Ufixed { .. }, Value::String(s)) => {
+                BigUint::parse_bytes(s.as_bytes(), 10)
+                    .map(AbiValue::Uint)
+                    .ok_or_else(invalid)
+            }
+            (AbiType::Bool, Value::Bool(b)) => Ok(AbiValue::Bool(*b)),
+            (AbiType::Byte, Value::Number(n)) => n
+                .as_u64()
+                .and_then(|n| u8::try_from(n).ok())
+                .map(AbiValue::Byte)
+                .ok_or_else(invalid),
+            (AbiType::Address, Value::String(s)) => s
+                .parse::<Address>()
+                .map(AbiValue::Address)
+                .map_err(|_| invalid()),
+            (AbiType::String, Value::String(s)) => Ok(AbiValue::String(s.clone())),
+            (
+                AbiType::StaticArray(child, _) | AbiType::DynamicArray(child),
+                Value::Array(items),
+            ) => items
+                .iter()
+                .map(|item| child.value_from_json(item))
+                .collect::<Result<_, _>>()
+                .map(AbiValue::Array),
+            (AbiType::Tuple(children), Value::Array(items)) if children.len() == items.len() => {
+                children
+                    .iter()
+                    .zip(items)
+                    .map(|(child, item)| child.value_from_json(item))
+                    .collect::<Result<_, _>>()
+                    .map(AbiValue::Array)
+            }
+            _ => Err(invalid()),
+        }
+    }
+
+    /// Converts a value of this type to JSON, using the representation described in
+    /// [`AbiType::value_from_json`].
+    ///
+    /// # Parameters
+    /// * `value` - The ABI value
+    ///
+    /// # Returns
+    /// The JSON value or an error if the value is not a valid value of this type.
+    pub fn value_to_json(&self, value: &AbiValue) -> Result<Value, AlgoKitTransactError> {
+        match (self, value) {
+            (AbiType::Uint(_) | AbiType::Ufixed { .. }, AbiValue::Uint(n)) => {
+                Ok(u64::try_from(n).map_or(Value::String(n.to_string()), Value::from))
+            }
+            (AbiType::Bool, AbiValue::Bool(b)) => Ok(Value::Bool(*b)),
+            (AbiType::Byte, AbiValue::Byte(b)) => Ok(Value::from(*b)),
+            (AbiType::Address, AbiValue::Address(address)) => {
+                Ok(Value::String(address.to_string()))
+            }
+            (AbiType::String, AbiValue::String(s)) => Ok(Value::String(s.clone())),
+            (
+                AbiType::StaticArray(child, _) | AbiType::DynamicArray(child),
+                AbiValue::Array(items),
+            ) => items
+                .iter()
+                .map(|item| child.value_to_json(item))
+                .collect::<Result<_, _>>()
+                .map(Value::Array),
+            (AbiType::Tuple(children), AbiValue::Array(items)) if children.len() == items.len() => {
+                children
+                    .iter()
+                    .zip(items)
+                    .map(|(child, item)| child.value_to_json(item))
+                    .collect::<Result<_, _>>()
+                    .map(Value::Array)
+            }
+            _ => Err(AlgoKitTransactError::AbiEncodingError(format!(
+                "{}: {:?} is not a valid value",
+                self, value
+            ))),
+        }
+    }
+}
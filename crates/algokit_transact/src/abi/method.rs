@@ -0,0 +1,491 @@
+use super::abi_type::split_tuple_types;
+use super::{AbiType, AbiValue};
+use crate::address::Address;
+use crate::constants::ABI_RETURN_PREFIX;
+use crate::error::AlgoKitTransactError;
+use crate::utils::hash;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::str::FromStr;
+
+/// The maximum number of application args, including the method selector.
+const MAX_APP_ARGS: usize = 16;
+
+/// The type of a transaction argument, which is passed as a preceding transaction in the group
+/// rather than in the application args.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum AbiTransactionType {
+    /// Any transaction type (`txn`).
+    Txn,
+    /// A payment transaction (`pay`).
+    Payment,
+    /// A key registration transaction (`keyreg`).
+    KeyRegistration,
+    /// An asset configuration transaction (`acfg`).
+    AssetConfig,
+    /// An asset transfer transaction (`axfer`).
+    AssetTransfer,
+    /// An asset freeze transaction (`afrz`).
+    AssetFreeze,
+    /// An application call transaction (`appl`).
+    ApplicationCall,
+}
+
+/// The type of a reference argument, which is passed as a `uint8` index into one of the foreign
+/// arrays of the application call.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum AbiReferenceType {
+    /// An account, indexing the account references (`account`).
+    Account,
+    /// An asset, indexing the asset references (`asset`).
+    Asset,
+    /// An application, indexing the application references (`application`).
+    Application,
+}
+
+/// The type of an ABI method argument.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum AbiMethodArgType {
+    /// A value which is ABI encoded in the application args.
+    Value(AbiType),
+    /// A transaction which precedes the application call in the group.
+    Transaction(AbiTransactionType),
+    /// A reference to an account, asset or application in the foreign arrays.
+    Reference(AbiReferenceType),
+}
+
+/// An argument of an ABI method.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct AbiMethodArg {
+    /// The type of the argument.
+    pub arg_type: AbiMethodArgType,
+    /// The optional name of the argument.
+    pub name: Option<String>,
+    /// The optional description of the argument.
+    pub description: Option<String>,
+}
+
+/// An ARC-4 ABI method.
+///
+/// Methods can be parsed from their signature, e.g. `transfer(address,uint64)bool`, or
+/// deserialized from their ARC-4 JSON description.
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(try_from = "Arc4Method", into = "Arc4Method")]
+pub struct AbiMethod {
+    /// The name of the method.
+    pub name: String,
+    /// The optional description of the method.
+    pub description: Option<String>,
+    /// The arguments of the method.
+    pub args: Vec<AbiMethodArg>,
+    /// The return type of the method, or `None` if the method returns `void`.
+    pub returns: Option<AbiType>,
+    /// The optional description of the return value.
+    pub returns_description: Option<String>,
+}
+
+/// The value of an argument of an ABI method which is passed in the application args.
+#[derive(Debug, PartialEq, Clone)]
+pub enum AbiMethodArgValue {
+    /// The value of an argument of an ABI type.
+    Value(AbiValue),
+    /// The value of an `account` reference argument.
+    Account(Address),
+    /// The ID of an `asset` reference argument.
+    Asset(u64),
+    /// The ID of an `application` reference argument.
+    Application(u64),
+}
+
+/// The application args and foreign arrays of an ABI method call.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct AbiMethodCallArgs {
+    /// The application args, starting with the method selector.
+    pub args: Vec<Vec<u8>>,
+    /// The accounts referenced by `account` arguments.
+    pub account_references: Vec<Address>,
+    /// The applications referenced by `application` arguments.
+    pub app_references: Vec<u64>,
+    /// The assets referenced by `asset` arguments.
+    pub asset_references: Vec<u64>,
+}
+
+impl AbiMethodArgType {
+    /// Returns true if this is a transaction argument.
+    pub fn is_transaction(&self) -> bool {
+        matches!(self, AbiMethodArgType::Transaction(_))
+    }
+}
+
+impl AbiMethod {
+    /// Returns the signature of the method, e.g. `transfer(address,uint64)bool`.
+    pub fn signature(&self) -> String {
+        let args: Vec<String> = self
+            .args
+            .iter()
+            .map(|arg| arg.arg_type.to_string())
+            .collect();
+        let returns = self
+            .returns
+            .as_ref()
+            .map_or("void".to_string(), AbiType::to_string);
+        format!("{}({}){}", self.name, args.join(","), returns)
+    }
+
+    /// Returns the 4-byte selector of the method, which is the first 4 bytes of the SHA-512/256
+    /// hash of the method signature.
+    pub fn selector(&self) -> [u8; 4] {
+        let mut selector = [0u8; 4];
+        selector.copy_from_slice(&hash(&self.signature().into_bytes())[..4]);
+        selector
+    }
+
+    /// Encodes the application args and foreign arrays of a call to this method.
+    ///
+    /// The first application arg is the method selector, followed by the encoded arguments. If
+    /// there are more than 15 arguments, the arguments from the 15th onwards are encoded
+    /// together as a tuple in the last application arg.
+    ///
+    /// Reference arguments are encoded as a `uint8` index into the corresponding foreign array,
+    /// which they are added to if not already present. Index 0 of the accounts and applications
+    /// refers to the sender and the called application respectively.
+    ///
+    /// # Parameters
+    /// * `values` - The values of the arguments, excluding transaction arguments
+    /// * `sender` - The sender of the application call
+    /// * `app_id` - The ID of the called application
+    ///
+    /// # Returns
+    /// The application args and foreign arrays, or an error if the values do not match the
+    /// arguments of the method.
+    pub fn encode_call_args(
+        &self,
+        values: &[AbiMethodArgValue],
+        sender: &Address,
+        app_id: u64,
+    ) -> Result<AbiMethodCallArgs, AlgoKitTransactError> {
+        let args: Vec<&AbiMethodArg> = self
+            .args
+            .iter()
+            .filter(|arg| !arg.arg_type.is_transaction())
+            .collect();
+        if values.len() != args.len() {
+            return Err(AlgoKitTransactError::AbiEncodingError(format!(
+                "{}: expected {} argument values but got {}",
+                self.signature(),
+                args.len(),
+                values.len()
+            )));
+        }
+
+        let mut call_args = AbiMethodCallArgs::default();
+        let mut encoded_args: Vec<(AbiType, AbiValue)> = Vec::with_capacity(args.len());
+
+        for (arg, value) in args.into_iter().zip(values) {
+            let reference_index = match (&arg.arg_type, value) {
+                (AbiMethodArgType::Value(abi_type), AbiMethodArgValue::Value(value)) => {
+                    encoded_args.push((abi_type.clone(), value.clone()));
+                    continue;
+                }
+                (
+                    AbiMethodArgType::Reference(AbiReferenceType::Account),
+                    AbiMethodArgValue::Account(address),
+                ) => reference_index(&mut call_args.account_references, address, Some(sender)),
+                (
+                    AbiMethodArgType::Reference(AbiReferenceType::Asset),
+                    AbiMethodArgValue::Asset(asset_id),
+                ) => reference_index(&mut call_args.asset_references, asset_id, None),
+                (
+                    AbiMethodArgType::Reference(AbiReferenceType::Application),
+                    AbiMethodArgValue::Application(id),
+                ) => reference_index(&mut call_args.app_references, id, Some(&app_id)),
+                (arg_type, value) => {
+                    return Err(AlgoKitTransactError::AbiEncodingError(format!(
+                        "{}: {:?} is not a valid value for an argument of type {}",
+                        self.signature(),
+                        value,
+                        arg_type
+                    )))
+                }
+            };
+            let reference_index = u8::try_from(reference_index).map_err(|_| {
+                AlgoKitTransactError::AbiEncodingError(format!(
+                    "{}: too many references to encode as a uint8 index",
+                    self.signature()
+                ))
+            })?;
+            encoded_args.push((AbiType::Uint(8), (reference_index as u64).into()));
+        }
+
+        call_args.args.push(self.selector().to_vec());
+        if encoded_args.len() >= MAX_APP_ARGS {
+            let (types, values): (Vec<AbiType>, Vec<AbiValue>) =
+                encoded_args.split_off(MAX_APP_ARGS - 2).into_iter().unzip();
+            encoded_args.push((AbiType::Tuple(types), AbiValue::Array(values)));
+        }
+        for (abi_type, value) in encoded_args {
+            call_args.args.push(abi_type.encode(&value)?);
+        }
+
+        Ok(call_args)
+    }
+
+    /// Decodes the return value of this method from the logs of a confirmed or simulated call.
+    ///
+    /// The return value is the last log, which is prefixed with `151f7c75`.
+    ///
+    /// # Parameters
+    /// * `logs` - The logs of the application call
+    ///
+    /// # Returns
+    /// The return value, `None` if the method returns `void`, or an error if the last log is
+    /// not a valid return value.
+    pub fn decode_return_value(
+        &self,
+        logs: &[Vec<u8>],
+    ) -> Result<Option<AbiValue>, AlgoKitTransactError> {
+        let Some(returns) = &self.returns else {
+            return Ok(None);
+        };
+
+        let encoded = logs
+            .last()
+            .and_then(|log| log.strip_prefix(ABI_RETURN_PREFIX))
+            .ok_or_else(|| {
+                AlgoKitTransactError::AbiDecodingError(format!(
+                    "{}: the last log is not a return value",
+                    self.signature()
+                ))
+            })?;
+
+        returns.decode(encoded).map(Some)
+    }
+}
+
+/// Returns the index of `item` in `references`, adding it if not present. If `implicit` is
+/// set, it is available at index 0 without being added and the indexes of the references start
+/// at 1.
+fn reference_index<T: PartialEq + Clone>(
+    references: &mut Vec<T>,
+    item: &T,
+    implicit: Option<&T>,
+) -> usize {
+    let start = if implicit.is_some() { 1 } else { 0 };
+    if implicit == Some(item) {
+        return 0;
+    }
+    let position = references
+        .iter()
+        .position(|reference| reference == item)
+        .unwrap_or_else(|| {
+            references.push(item.clone());
+            references.len() - 1
+        });
+    position + start
+}
+
+impl FromStr for AbiMethod {
+    type Err = AlgoKitTransactError;
+
+    /// Parses a method signature, e.g. `transfer(address,uint64)bool`.
+    ///
+    /// # Parameters
+    /// * `s` - The method signature
+    ///
+    /// # Returns
+    /// The method or an error if the signature is invalid.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid =
+            |reason: &str| AlgoKitTransactError::InvalidAbiMethod(format!("{}: {}", s, reason));
+
+        let open = s
+            .find('(')
+            .ok_or_else(|| invalid("signature is missing an argument list"))?;
+        let name = &s[..open];
+        if name.is_empty() {
+            return Err(invalid("method name is empty"));
+        }
+
+        let mut depth = 0usize;
+        let close = s[open..]
+            .char_indices()
+            .find_map(|(i, c)| {
+                match c {
+                    '(' => depth += 1,
+                    ')' => {
+                        depth -= 1;
+                        if depth == 0 {
+                            return Some(open + i);
+                        }
+                    }
+                    _ => {}
+                }
+                None
+            })
+            .ok_or_else(|| invalid("argument list is missing a closing parenthesis"))?;
+
+        let args = split_tuple_types(&s[open + 1..close])
+            .ok_or_else(|| invalid("argument list has unbalanced parentheses"))?
+            .into_iter()
+            .map(|arg_type| {
+                Ok(AbiMethodArg {
+                    arg_type: arg_type.parse()?,
+                    name: None,
+                    description: None,
+                })
+            })
+            .collect::<Result<Vec<_>, AlgoKitTransactError>>()?;
+
+        Ok(AbiMethod {
+            name: name.to_string(),
+            description: None,
+            args,
+            returns: parse_returns(&s[close + 1..])?,
+            returns_description: None,
+        })
+    }
+}
+
+fn parse_returns(s: &str) -> Result<Option<AbiType>, AlgoKitTransactError> {
+    match s {
+        "void" => Ok(None),
+        s => s.parse().map(Some),
+    }
+}
+
+impl Display for AbiMethod {
+    /// Formats the method as its signature.
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}", self.signature())
+    }
+}
+
+impl FromStr for AbiMethodArgType {
+    type Err = AlgoKitTransactError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "txn" => AbiMethodArgType::Transaction(AbiTransactionType::Txn),
+            "pay" => AbiMethodArgType::Transaction(AbiTransactionType::Payment),
+            "keyreg" => AbiMethodArgType::Transaction(AbiTransactionType::KeyRegistration),
+            "acfg" => AbiMethodArgType::Transaction(AbiTransactionType::AssetConfig),
+            "axfer" => AbiMethodArgType::Transaction(AbiTransactionType::AssetTransfer),
+            "afrz" => AbiMethodArgType::Transaction(AbiTransactionType::AssetFreeze),
+            "appl" => AbiMethodArgType::Transaction(AbiTransactionType::ApplicationCall),
+            "account" => AbiMethodArgType::Reference(AbiReferenceType::Account),
+            "asset" => AbiMethodArgType::Reference(AbiReferenceType::Asset),
+            "application" => AbiMethodArgType::Reference(AbiReferenceType::Application),
+            s => AbiMethodArgType::Value(s.parse()?),
+        })
+    }
+}
+
+impl Display for AbiMethodArgType {
+    /// Formats the argument type as its ARC-4 type string.
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            AbiMethodArgType::Value(abi_type) => write!(f, "{}", abi_type),
+            AbiMethodArgType::Transaction(transaction_type) => {
+                let type_str = match transaction_type {
+                    AbiTransactionType::Txn => "txn",
+                    AbiTransactionType::Payment => "pay",
+                    AbiTransactionType::KeyRegistration => "keyreg",
+                    AbiTransactionType::AssetConfig => "acfg",
+                    AbiTransactionType::AssetTransfer => "axfer",
+                    AbiTransactionType::AssetFreeze => "afrz",
+                    AbiTransactionType::ApplicationCall => "appl",
+                };
+                write!(f, "{}", type_str)
+            }
+            AbiMethodArgType::Reference(reference_type) => {
+                let type_str = match reference_type {
+                    AbiReferenceType::Account => "account",
+                    AbiReferenceType::Asset => "asset",
+                    AbiReferenceType::Application => "application",
+                };
+                write!(f, "{}", type_str)
+            }
+        }
+    }
+}
+
+/// The ARC-4 JSON description of a method.
+#[derive(Serialize, Deserialize)]
+struct Arc4Method {
+    name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    desc: Option<String>,
+    args: Vec<Arc4MethodArg>,
+    returns: Arc4MethodReturns,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Arc4MethodArg {
+    #[serde(rename = "type")]
+    arg_type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    desc: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Arc4MethodReturns {
+    #[serde(rename = "type")]
+    return_type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    desc: Option<String>,
+}
+
+impl TryFrom<Arc4Method> for AbiMethod {
+    type Error = AlgoKitTransactError;
+
+    fn try_from(method: Arc4Method) -> Result<Self, Self::Error> {
+        Ok(AbiMethod {
+            name: method.name,
+            description: method.desc,
+            args: method
+                .args
+                .into_iter()
+                .map(|arg| {
+                    Ok(AbiMethodArg {
+                        arg_type: arg.arg_type.parse()?,
+                        name: arg.name,
+                        description: arg.desc,
+                    })
+                })
+                .collect::<Result<_, AlgoKitTransactError>>()?,
+            returns: parse_returns(&method.returns.return_type)?,
+            returns_description: method.returns.desc,
+        })
+    }
+}
+
+impl From<AbiMethod> for Arc4Method {
+    fn from(method: AbiMethod) -> Self {
+        Arc4Method {
+            name: method.name,
+            desc: method.description,
+            args: method
+                .args
+                .into_iter()
+                .map(|arg| Arc4MethodArg {
+                    arg_type: arg.arg_type.to_string(),
+                    name: arg.name,
+                    desc: arg.description,
+                })
+                .collect(),
+            returns: Arc4MethodReturns {
+                return_type: method
+                    .returns
+                    .as_ref()
+                    .map_or("void".to_string(), AbiType::to_string),
+                desc: method.returns_description,
+            },
+        }
+    }
+}
@@ -0,0 +1,22 @@
+//! ARC-4 Application Binary Interface (ABI) support.
+//!
+//! This module provides [`AbiType`], which is parsed from an ARC-4 type string such as
+//! `uint64`, `byte[32]` or `(address,bool[])`, and [`AbiValue`], which holds a value of an
+//! ABI type. Values are encoded and decoded according to the ARC-4 encoding rules, including
+//! the packing of consecutive booleans and the head/tail layout of dynamic types.
+//!
+//! [`AbiMethod`] describes an ARC-4 method, and is used to encode the application args of a
+//! method call and to decode the return value from the logs of the call.
+
+mod abi_type;
+mod abi_value;
+mod encoding;
+mod json;
+mod method;
+
+pub use abi_type::AbiType;
+pub use abi_value::AbiValue;
+pub use method::{
+    AbiMethod, AbiMethodArg, AbiMethodArgType, AbiMethodArgValue, AbiMethodCallArgs,
+    AbiReferenceType, AbiTransactionType,
+};
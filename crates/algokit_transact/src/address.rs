@@ -5,10 +5,12 @@
 //! are base32-encoded strings that represent a public key with a checksum.
 
 use crate::constants::{
-    Byte32, ALGORAND_ADDRESS_LENGTH, ALGORAND_CHECKSUM_BYTE_LENGTH, ALGORAND_PUBLIC_KEY_BYTE_LENGTH,
+    Byte32, ALGORAND_ADDRESS_LENGTH, ALGORAND_CHECKSUM_BYTE_LENGTH,
+    ALGORAND_PUBLIC_KEY_BYTE_LENGTH, APP_ID_PREFIX, MULTISIG_ADDRESS_PREFIX, PROGRAM_PREFIX,
 };
 use crate::error::AlgoKitTransactError;
-use crate::utils::pub_key_to_checksum;
+use crate::multisig::validate_multisig_params;
+use crate::utils::{hash, pub_key_to_checksum};
 use serde::{Deserialize, Serialize};
 use serde_with::{serde_as, Bytes};
 use std::fmt::{Display, Formatter, Result as FmtResult};
@@ -21,7 +23,7 @@ use std::str::FromStr;
 /// This struct encapsulates the underlying public key and provides
 /// methods for creating, validating, and converting human-readable addresses.
 #[serde_as]
-#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Hash, Clone, Default)]
 #[serde(transparent)]
 pub struct Address {
     /// The 32-byte Ed25519 public key associated with this address.
@@ -41,6 +43,63 @@ impl Address {
         Address { pub_key: *pub_key }
     }
 
+    /// Derives the address of an application account.
+    ///
+    /// The address is the hash of the `appID` domain separation prefix followed by the
+    /// big-endian application ID.
+    ///
+    /// # Parameters
+    /// * `app_id` - The ID of the application
+    ///
+    /// # Returns
+    /// The address of the application account.
+    pub fn from_app_id(app_id: u64) -> Self {
+        Address::from_pubkey(&hash(&[APP_ID_PREFIX, &app_id.to_be_bytes()].concat()))
+    }
+
+    /// Derives the address of a multisig account.
+    ///
+    /// The address is the hash of the `MultisigAddr` domain separation prefix, the version,
+    /// the threshold and the public keys of the participants in order.
+    ///
+    /// # Parameters
+    /// * `version` - The multisig version, which must currently be 1
+    /// * `threshold` - The number of participant signatures required
+    /// * `participants` - The addresses of the participants, in order
+    ///
+    /// # Returns
+    /// The multisig address or an error if the multisig parameters are invalid.
+    pub fn from_multisig(
+        version: u8,
+        threshold: u8,
+        participants: &[Address],
+    ) -> Result<Self, AlgoKitTransactError> {
+        validate_multisig_params(version, threshold, participants.len())?;
+
+        let mut bytes = MULTISIG_ADDRESS_PREFIX.to_vec();
+        bytes.push(version);
+        bytes.push(threshold);
+        for participant in participants {
+            bytes.extend_from_slice(&participant.pub_key);
+        }
+
+        Ok(Address::from_pubkey(&hash(&bytes)))
+    }
+
+    /// Derives the address of a program (contract account).
+    ///
+    /// The address is the hash of the `Program` domain separation prefix followed by the
+    /// compiled program.
+    ///
+    /// # Parameters
+    /// * `program` - The compiled TEAL program
+    ///
+    /// # Returns
+    /// The address of the program.
+    pub fn from_program(program: &[u8]) -> Self {
+        Address::from_pubkey(&hash(&[PROGRAM_PREFIX, program].concat()))
+    }
+
     /// Calculates the 4-byte checksum for this address's public key.
     ///
     /// # Returns
@@ -59,37 +118,57 @@ impl FromStr for Address {
     /// * `s` - A 58-character base32-encoded Algorand address string
     ///
     /// # Returns
-    /// The Address or an error if the string is invalid (wrong length, invalid base32,
-    /// invalid format, or checksum mismatch, etc.).
+    /// The Address or an `InvalidAddress` error describing why the string is invalid (wrong
+    /// length, lowercase or other non-base32 characters, non-canonical encoding or checksum
+    /// mismatch).
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         if s.len() != ALGORAND_ADDRESS_LENGTH {
+            return Err(AlgoKitTransactError::InvalidAddress(format!(
+                "address length is {} but must be {}",
+                s.len(),
+                ALGORAND_ADDRESS_LENGTH
+            )));
+        }
+
+        if let Some(c) = s.chars().find(|c| !matches!(c, 'A'..='Z' | '2'..='7')) {
             return Err(AlgoKitTransactError::InvalidAddress(
-                "address length is not 58".to_string(),
+                if c.is_ascii_lowercase() {
+                    "address must be uppercase".to_string()
+                } else {
+                    format!("address contains invalid base32 character {:?}", c)
+                },
             ));
         }
-        let decoded = base32::decode(base32::Alphabet::Rfc4648 { padding: false }, s)
-            .expect("decoded value should exist");
 
-        let pub_key: [u8; ALGORAND_PUBLIC_KEY_BYTE_LENGTH] = decoded
-            [..ALGORAND_PUBLIC_KEY_BYTE_LENGTH]
-            .try_into()
-            .map_err(|_| {
-                AlgoKitTransactError::InvalidAddress(
-                    "could not decode address into 32-byte public key".to_string(),
-                )
+        let decoded =
+            base32::decode(base32::Alphabet::Rfc4648 { padding: false }, s).ok_or_else(|| {
+                AlgoKitTransactError::InvalidAddress("address is not valid base32".to_string())
             })?;
 
-        let checksum: [u8; 4] = decoded[ALGORAND_PUBLIC_KEY_BYTE_LENGTH..]
-            .try_into()
-            .map_err(|_| {
+        let (pub_key, checksum) = decoded
+            .split_first_chunk::<ALGORAND_PUBLIC_KEY_BYTE_LENGTH>()
+            .ok_or_else(|| {
                 AlgoKitTransactError::InvalidAddress(
-                    "could not get 4-byte checksum from decoded address".to_string(),
+                    "could not decode address into 32-byte public key".to_string(),
                 )
             })?;
+        let pub_key = *pub_key;
 
-        let computed_checksum = pub_key_to_checksum(&pub_key);
+        let checksum: [u8; ALGORAND_CHECKSUM_BYTE_LENGTH] = checksum.try_into().map_err(|_| {
+            AlgoKitTransactError::InvalidAddress(
+                "could not get 4-byte checksum from decoded address".to_string(),
+            )
+        })?;
+
+        // 58 base32 characters hold 290 bits, so the last 2 bits of a canonical encoding of the
+        // 36 address bytes are zero
+        if base32::encode(base32::Alphabet::Rfc4648 { padding: false }, &decoded) != s {
+            return Err(AlgoKitTransactError::InvalidAddress(
+                "address is not canonically encoded".to_string(),
+            ));
+        }
 
-        if computed_checksum != checksum {
+        if pub_key_to_checksum(&pub_key) != checksum {
             return Err(AlgoKitTransactError::InvalidAddress(
                 "checksum is invalid".to_string(),
             ));
@@ -0,0 +1,351 @@
+use super::arc56::{
+    Actions, Arc56Contract, Arc56Method, ContractState, DefaultValue, DefaultValueSource,
+    MethodArg, MethodReturns, Network, OnCompleteAction, Programs, Schema, StateSchemas,
+    StorageKey, StorageKeys, StructField, StructFieldType,
+};
+use crate::abi::{AbiMethod, AbiType, AbiValue};
+use crate::error::AlgoKitTransactError;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use serde::Deserialize;
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+/// An ARC-32 application specification.
+#[derive(Deserialize)]
+struct Arc32Contract {
+    #[serde(default)]
+    hints: BTreeMap<String, Arc32Hint>,
+    #[serde(default)]
+    source: Option<Programs>,
+    #[serde(default)]
+    state: Arc32State,
+    #[serde(default)]
+    schema: Arc32Schema,
+    contract: Arc32AbiContract,
+    #[serde(default)]
+    bare_call_config: BTreeMap<String, String>,
+}
+
+/// The ARC-4 contract description embedded in an ARC-32 application specification.
+#[derive(Deserialize)]
+struct Arc32AbiContract {
+    name: String,
+    #[serde(default)]
+    desc: Option<String>,
+    #[serde(default)]
+    networks: Option<BTreeMap<String, Network>>,
+    methods: Vec<AbiMethod>,
+}
+
+#[derive(Deserialize, Default)]
+struct Arc32Hint {
+    #[serde(default)]
+    structs: BTreeMap<String, Arc32Struct>,
+    #[serde(default)]
+    read_only: Option<bool>,
+    #[serde(default)]
+    default_arguments: BTreeMap<String, Arc32DefaultArgument>,
+    #[serde(default)]
+    call_config: Option<BTreeMap<String, String>>,
+}
+
+#[derive(Deserialize)]
+struct Arc32Struct {
+    name: String,
+    elements: Vec<(String, String)>,
+}
+
+#[derive(Deserialize)]
+struct Arc32DefaultArgument {
+    source: String,
+    data: Value,
+}
+
+#[derive(Deserialize, Default)]
+struct Arc32State {
+    #[serde(default)]
+    global: Arc32StateSchema,
+    #[serde(default)]
+    local: Arc32StateSchema,
+}
+
+#[derive(Deserialize, Default)]
+struct Arc32StateSchema {
+    #[serde(default)]
+    num_uints: u64,
+    #[serde(default)]
+    num_byte_slices: u64,
+}
+
+#[derive(Deserialize, Default)]
+struct Arc32Schema {
+    #[serde(default)]
+    global: Arc32SchemaSpec,
+    #[serde(default)]
+    local: Arc32SchemaSpec,
+}
+
+#[derive(Deserialize, Default)]
+struct Arc32SchemaSpec {
+    #[serde(default)]
+    declared: BTreeMap<String, Arc32DeclaredValue>,
+}
+
+#[derive(Deserialize)]
+struct Arc32DeclaredValue {
+    #[serde(rename = "type")]
+    value_type: String,
+    key: String,
+    #[serde(default)]
+    descr: Option<String>,
+}
+
+impl Arc56Contract {
+    /// Parses an ARC-32 application specification, converting it to ARC-56.
+    ///
+    /// Methods without a call config in the ARC-32 hints are assumed to be callable with a
+    /// `NoOp` application call.
+    ///
+    /// # Parameters
+    /// * `json` - The ARC-32 JSON
+    ///
+    /// # Returns
+    /// The application specification or an error if the JSON is not a valid ARC-32
+    /// specification.
+    pub fn from_arc32_json(json: &str) -> Result<Self, AlgoKitTransactError> {
+        let arc32: Arc32Contract = serde_json::from_str(json)
+            .map_err(|e| AlgoKitTransactError::InvalidAppSpec(e.to_string()))?;
+
+        let mut structs = BTreeMap::new();
+        let mut methods = Vec::with_capacity(arc32.contract.methods.len());
+
+        for method in arc32.contract.methods {
+            let signature = method.signature();
+            let hint = arc32.hints.get(&signature);
+            let struct_name = |name: &str| {
+                hint.and_then(|hint| hint.structs.get(name))
+                    .map(|s| s.name.clone())
+            };
+
+            if let Some(hint) = hint {
+                for hint_struct in hint.structs.values() {
+                    structs.insert(
+                        hint_struct.name.clone(),
+                        hint_struct
+                            .elements
+                            .iter()
+                            .map(|(name, field_type)| StructField {
+                                name: name.clone(),
+                                field_type: StructFieldType::Type(field_type.clone()),
+                            })
+                            .collect(),
+                    );
+                }
+            }
+
+            let args = method
+                .args
+                .iter()
+                .map(|arg| {
+                    let default_value = match (&arg.name, hint) {
+                        (Some(name), Some(hint)) => hint
+                            .default_arguments
+                            .get(name)
+                            .map(|default| convert_default_argument(&signature, default))
+                            .transpose()?,
+                        _ => None,
+                    };
+                    Ok(MethodArg {
+                        arg_type: arg.arg_type.to_string(),
+                        struct_name: arg.name.as_deref().and_then(struct_name),
+                        name: arg.name.clone(),
+                        desc: arg.description.clone(),
+                        default_value,
+                    })
+                })
+                .collect::<Result<Vec<_>, AlgoKitTransactError>>()?;
+
+            let actions = match hint.and_then(|hint| hint.call_config.as_ref()) {
+                Some(call_config) => convert_call_config(call_config)?,
+                None => Actions {
+                    create: vec![],
+                    call: vec![OnCompleteAction::NoOp],
+                },
+            };
+
+            methods.push(Arc56Method {
+                name: method.name,
+                desc: method.description,
+                args,
+                returns: MethodReturns {
+                    return_type: method
+                        .returns
+                        .as_ref()
+                        .map_or("void".to_string(), AbiType::to_string),
+                    struct_name: struct_name("output"),
+                    desc: method.returns_description,
+                },
+                actions,
+                readonly: hint.and_then(|hint| hint.read_only),
+                events: None,
+                recommendations: None,
+            });
+        }
+
+        Ok(Arc56Contract {
+            arcs: vec![],
+            name: arc32.contract.name,
+            desc: arc32.contract.desc,
+            networks: arc32.contract.networks,
+            structs,
+            methods,
+            state: ContractState {
+                schema: StateSchemas {
+                    global: Schema {
+                        ints: arc32.state.global.num_uints,
+                        bytes: arc32.state.global.num_byte_slices,
+                    },
+                    local: Schema {
+                        ints: arc32.state.local.num_uints,
+                        bytes: arc32.state.local.num_byte_slices,
+                    },
+                },
+                keys: StorageKeys {
+                    global: convert_declared_keys(arc32.schema.global.declared),
+                    local: convert_declared_keys(arc32.schema.local.declared),
+                    boxes: BTreeMap::new(),
+                },
+                maps: Default::default(),
+            },
+            bare_actions: convert_call_config(&arc32.bare_call_config)?,
+            source_info: None,
+            source: arc32.source,
+            byte_code: None,
+            compiler_info: None,
+            events: None,
+            template_variables: None,
+            scratch_variables: None,
+        })
+    }
+}
+
+fn convert_call_config(
+    call_config: &BTreeMap<String, String>,
+) -> Result<Actions, AlgoKitTransactError> {
+    let mut actions = Actions::default();
+    for (on_complete, config) in call_config {
+        let action = match on_complete.as_str() {
+            "no_op" => OnCompleteAction::NoOp,
+            "opt_in" => OnCompleteAction::OptIn,
+            "close_out" => OnCompleteAction::CloseOut,
+            "clear_state" => OnCompleteAction::ClearState,
+            "update_application" => OnCompleteAction::UpdateApplication,
+            "delete_application" => OnCompleteAction::DeleteApplication,
+            _ => {
+                return Err(AlgoKitTransactError::InvalidAppSpec(format!(
+                    "unknown on completion action {}",
+                    on_complete
+                )))
+            }
+        };
+        let (create, call) = match config.as_str() {
+            "ALL" => (true, true),
+            "CREATE" => (true, false),
+            "CALL" => (false, true),
+            "NEVER" => (false, false),
+            _ => {
+                return Err(AlgoKitTransactError::InvalidAppSpec(format!(
+                    "unknown call config {}",
+                    config
+                )))
+            }
+        };
+        if create {
+            actions.create.push(action);
+        }
+        if call {
+            actions.call.push(action);
+        }
+    }
+    Ok(actions)
+}
+
+fn convert_default_argument(
+    signature: &str,
+    default: &Arc32DefaultArgument,
+) -> Result<DefaultValue, AlgoKitTransactError> {
+    let invalid = || {
+        AlgoKitTransactError::InvalidAppSpec(format!(
+            "invalid {} default argument of {}: {}",
+            default.source, signature, default.data
+        ))
+    };
+
+    let state_key = || {
+        default
+            .data
+            .as_str()
+            .map(|key| BASE64.encode(key))
+            .ok_or_else(invalid)
+    };
+
+    Ok(match default.source.as_str() {
+        "global-state" => DefaultValue {
+            data: state_key()?,
+            value_type: None,
+            source: DefaultValueSource::Global,
+        },
+        "local-state" => DefaultValue {
+            data: state_key()?,
+            value_type: None,
+            source: DefaultValueSource::Local,
+        },
+        "abi-method" => {
+            let method: AbiMethod =
+                serde_json::from_value(default.data.clone()).map_err(|_| invalid())?;
+            DefaultValue {
+                data: method.signature(),
+                value_type: None,
+                source: DefaultValueSource::Method,
+            }
+        }
+        "constant" => {
+            let (value_type, value) = match &default.data {
+                Value::Number(n) => (AbiType::Uint(64), n.as_u64().map(AbiValue::from)),
+                Value::String(s) => (AbiType::String, Some(AbiValue::from(s.as_str()))),
+                _ => (AbiType::String, None),
+            };
+            let value = value.ok_or_else(invalid)?;
+            DefaultValue {
+                data: BASE64.encode(value_type.encode(&value)?),
+                value_type: Some(value_type.to_string()),
+                source: DefaultValueSource::Literal,
+            }
+        }
+        _ => return Err(invalid()),
+    })
+}
+
+fn convert_declared_keys(
+    declared: BTreeMap<String, Arc32DeclaredValue>,
+) -> BTreeMap<String, StorageKey> {
+    declared
+        .into_iter()
+        .map(|(name, value)| {
+            let value_type = match value.value_type.as_str() {
+                "uint64" => "AVMUint64",
+                _ => "AVMBytes",
+            };
+            (
+                name,
+                StorageKey {
+                    desc: value.descr.filter(|descr| !descr.is_empty()),
+                    key_type: "AVMString".to_string(),
+                    value_type: value_type.to_string(),
+                    key: BASE64.encode(value.key),
+                },
+            )
+        })
+        .collect()
+}
@@ -0,0 +1,643 @@
+use crate::abi::{AbiMethod, AbiMethodArg};
+use crate::error::AlgoKitTransactError;
+use crate::transactions::OnApplicationComplete;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// An ARC-56 application specification.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Arc56Contract {
+    /// The ARCs used and/or supported by the contract.
+    pub arcs: Vec<u32>,
+
+    /// The name of the contract.
+    pub name: String,
+
+    /// An optional description of the contract.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub desc: Option<String>,
+
+    /// The IDs of the application on the networks it is deployed to, keyed by network name or
+    /// genesis hash.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub networks: Option<BTreeMap<String, Network>>,
+
+    /// Named structs used by the contract, as lists of fields.
+    #[serde(default)]
+    pub structs: BTreeMap<String, Vec<StructField>>,
+
+    /// The ABI methods of the contract.
+    pub methods: Vec<Arc56Method>,
+
+    /// The state schema, keys and maps of the contract.
+    pub state: ContractState,
+
+    /// The on completion actions supported by bare (non-ABI) application calls.
+    pub bare_actions: Actions,
+
+    /// Information for mapping program counters to source locations and error messages.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub source_info: Option<ProgramsSourceInfo>,
+
+    /// The base64 encoded TEAL source of the programs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub source: Option<Programs>,
+
+    /// The base64 encoded byte code of the programs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub byte_code: Option<Programs>,
+
+    /// Information about the compiler which produced the byte code.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub compiler_info: Option<CompilerInfo>,
+
+    /// ARC-28 events which may be emitted by the contract.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub events: Option<Vec<Event>>,
+
+    /// Template variables which must be substituted into the source before compilation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub template_variables: Option<BTreeMap<String, TemplateVariable>>,
+
+    /// Scratch variables used by the contract.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub scratch_variables: Option<BTreeMap<String, ScratchVariable>>,
+}
+
+/// The deployment of an application on a network.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct Network {
+    /// The ID of the application.
+    #[serde(rename = "appID")]
+    pub app_id: u64,
+}
+
+/// A field of a named struct.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct StructField {
+    /// The name of the field.
+    pub name: String,
+
+    /// The type of the field.
+    #[serde(rename = "type")]
+    pub field_type: StructFieldType,
+}
+
+/// The type of a struct field.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(untagged)]
+pub enum StructFieldType {
+    /// An ABI type or the name of a struct.
+    Type(String),
+
+    /// The fields of an anonymous nested struct.
+    Fields(Vec<StructField>),
+}
+
+/// An on completion action of an application call.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Copy)]
+pub enum OnCompleteAction {
+    NoOp,
+    OptIn,
+    CloseOut,
+    ClearState,
+    UpdateApplication,
+    DeleteApplication,
+}
+
+impl From<OnCompleteAction> for OnApplicationComplete {
+    fn from(action: OnCompleteAction) -> Self {
+        match action {
+            OnCompleteAction::NoOp => OnApplicationComplete::NoOp,
+            OnCompleteAction::OptIn => OnApplicationComplete::OptIn,
+            OnCompleteAction::CloseOut => OnApplicationComplete::CloseOut,
+            OnCompleteAction::ClearState => OnApplicationComplete::ClearState,
+            OnCompleteAction::UpdateApplication => OnApplicationComplete::UpdateApplication,
+            OnCompleteAction::DeleteApplication => OnApplicationComplete::DeleteApplication,
+        }
+    }
+}
+
+/// The on completion actions allowed when creating and calling an application.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+pub struct Actions {
+    /// The actions allowed when creating the application.
+    pub create: Vec<OnCompleteAction>,
+
+    /// The actions allowed when calling an existing application.
+    pub call: Vec<OnCompleteAction>,
+}
+
+/// An ABI method of an ARC-56 contract.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Arc56Method {
+    /// The name of the method.
+    pub name: String,
+
+    /// An optional description of the method.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub desc: Option<String>,
+
+    /// The arguments of the method.
+    pub args: Vec<MethodArg>,
+
+    /// The return value of the method.
+    pub returns: MethodReturns,
+
+    /// The on completion actions the method may be called with.
+    pub actions: Actions,
+
+    /// Whether the method is read-only, and so can be simulated rather than sent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub readonly: Option<bool>,
+
+    /// ARC-28 events which may be emitted by the method.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub events: Option<Vec<Event>>,
+
+    /// Recommended resources for calling the method.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub recommendations: Option<Recommendations>,
+}
+
+/// An argument of an ARC-56 method.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MethodArg {
+    /// The ARC-4 type of the argument.
+    #[serde(rename = "type")]
+    pub arg_type: String,
+
+    /// The name of the struct the argument is an instance of.
+    #[serde(rename = "struct")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub struct_name: Option<String>,
+
+    /// The name of the argument.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub name: Option<String>,
+
+    /// An optional description of the argument.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub desc: Option<String>,
+
+    /// The value used when the argument is not provided.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub default_value: Option<DefaultValue>,
+}
+
+/// The return value of an ARC-56 method.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct MethodReturns {
+    /// The ARC-4 return type, or `void`.
+    #[serde(rename = "type")]
+    pub return_type: String,
+
+    /// The name of the struct the return value is an instance of.
+    #[serde(rename = "struct")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub struct_name: Option<String>,
+
+    /// An optional description of the return value.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub desc: Option<String>,
+}
+
+/// Where the default value of a method argument comes from.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Copy)]
+#[serde(rename_all = "lowercase")]
+pub enum DefaultValueSource {
+    /// The value of the box whose key is `data`.
+    Box,
+    /// The value of the global state key `data`.
+    Global,
+    /// The value of the sender's local state key `data`.
+    Local,
+    /// The literal value `data`.
+    Literal,
+    /// The return value of calling the method with the signature `data`.
+    Method,
+}
+
+/// The default value of a method argument.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct DefaultValue {
+    /// The base64 encoded key, base64 encoded literal, or method signature, depending on the
+    /// source.
+    pub data: String,
+
+    /// The ABI or AVM type of `data`, if it differs from the type of the argument.
+    #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub value_type: Option<String>,
+
+    /// Where the default value comes from.
+    pub source: DefaultValueSource,
+}
+
+/// An ARC-28 event.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct Event {
+    /// The name of the event.
+    pub name: String,
+
+    /// An optional description of the event.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub desc: Option<String>,
+
+    /// The arguments of the event.
+    pub args: Vec<EventArg>,
+}
+
+/// An argument of an ARC-28 event.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct EventArg {
+    /// The ARC-4 type of the argument.
+    #[serde(rename = "type")]
+    pub arg_type: String,
+
+    /// The name of the argument.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub name: Option<String>,
+
+    /// An optional description of the argument.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub desc: Option<String>,
+
+    /// The name of the struct the argument is an instance of.
+    #[serde(rename = "struct")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub struct_name: Option<String>,
+}
+
+/// Recommended resources for calling a method.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct Recommendations {
+    /// The number of inner transactions the method issues.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub inner_transaction_count: Option<u64>,
+
+    /// A box accessed by the method.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub boxes: Option<BoxRecommendation>,
+
+    /// Accounts accessed by the method.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub accounts: Option<Vec<String>>,
+
+    /// Applications accessed by the method.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub apps: Option<Vec<u64>>,
+
+    /// Assets accessed by the method.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub assets: Option<Vec<u64>>,
+}
+
+/// A box accessed by a method.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BoxRecommendation {
+    /// The application the box belongs to, if not the called application.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub app: Option<u64>,
+
+    /// The base64 encoded box key.
+    pub key: String,
+
+    /// The number of bytes read from the box.
+    pub read_bytes: u64,
+
+    /// The number of bytes written to the box.
+    pub write_bytes: u64,
+}
+
+/// The state of a contract.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+pub struct ContractState {
+    /// The global and local state schema.
+    pub schema: StateSchemas,
+
+    /// Named storage keys.
+    pub keys: StorageKeys,
+
+    /// Named storage maps.
+    pub maps: StorageMaps,
+}
+
+/// The global and local state schema of a contract.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+pub struct StateSchemas {
+    /// The global state schema.
+    pub global: Schema,
+
+    /// The local state schema.
+    pub local: Schema,
+}
+
+/// The number of integer and byte slice values in a state schema.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+pub struct Schema {
+    /// The number of integer values.
+    pub ints: u64,
+
+    /// The number of byte slice values.
+    pub bytes: u64,
+}
+
+/// Named storage keys in global, local and box storage.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+pub struct StorageKeys {
+    /// The global state keys.
+    pub global: BTreeMap<String, StorageKey>,
+
+    /// The local state keys.
+    pub local: BTreeMap<String, StorageKey>,
+
+    /// The box keys.
+    #[serde(rename = "box")]
+    pub boxes: BTreeMap<String, StorageKey>,
+}
+
+/// Named storage maps in global, local and box storage.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+pub struct StorageMaps {
+    /// The global state maps.
+    pub global: BTreeMap<String, StorageMap>,
+
+    /// The local state maps.
+    pub local: BTreeMap<String, StorageMap>,
+
+    /// The box maps.
+    #[serde(rename = "box")]
+    pub boxes: BTreeMap<String, StorageMap>,
+}
+
+/// A storage key.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct StorageKey {
+    /// An optional description of the key.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub desc: Option<String>,
+
+    /// The ABI type, AVM type or struct name of the key.
+    pub key_type: String,
+
+    /// The ABI type, AVM type or struct name of the value.
+    pub value_type: String,
+
+    /// The base64 encoded key.
+    pub key: String,
+}
+
+/// A storage map, which is the set of keys starting with a common prefix.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct StorageMap {
+    /// An optional description of the map.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub desc: Option<String>,
+
+    /// The ABI type, AVM type or struct name of the keys.
+    pub key_type: String,
+
+    /// The ABI type, AVM type or struct name of the values.
+    pub value_type: String,
+
+    /// The base64 encoded prefix of the keys.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub prefix: Option<String>,
+}
+
+/// The approval and clear state programs of a contract.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct Programs {
+    /// The approval program.
+    pub approval: String,
+
+    /// The clear state program.
+    pub clear: String,
+}
+
+/// Source information of the approval and clear state programs.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct ProgramsSourceInfo {
+    /// Source information of the approval program.
+    pub approval: ProgramSourceInfo,
+
+    /// Source information of the clear state program.
+    pub clear: ProgramSourceInfo,
+}
+
+/// How program counters in source information are offset.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Copy)]
+#[serde(rename_all = "lowercase")]
+pub enum PcOffsetMethod {
+    /// Program counters are not offset.
+    None,
+    /// Program counters are offset by the size of the constant blocks at the start of the
+    /// program, as they may vary when template variables are substituted.
+    Cblocks,
+}
+
+/// Source information of a program.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ProgramSourceInfo {
+    /// How the program counters are offset.
+    pub pc_offset_method: PcOffsetMethod,
+
+    /// The source information, keyed by program counters.
+    pub source_info: Vec<SourceInfo>,
+}
+
+/// Source information of one or more program counters.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SourceInfo {
+    /// The program counters this information applies to.
+    pub pc: Vec<u64>,
+
+    /// A human readable error message for failures at these program counters.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub error_message: Option<String>,
+
+    /// The line of the TEAL source.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub teal: Option<u64>,
+
+    /// The location in the original (pre-TEAL) source.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub source: Option<String>,
+}
+
+/// The compiler which produced the byte code of a contract.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CompilerInfo {
+    /// The name of the compiler, e.g. `algod` or `puya`.
+    pub compiler: String,
+
+    /// The version of the compiler.
+    pub compiler_version: CompilerVersion,
+}
+
+/// A compiler version.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CompilerVersion {
+    pub major: u64,
+    pub minor: u64,
+    pub patch: u64,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub commit_hash: Option<String>,
+}
+
+/// A template variable.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct TemplateVariable {
+    /// The ABI type, AVM type or struct name of the variable.
+    #[serde(rename = "type")]
+    pub value_type: String,
+
+    /// The base64 encoded value of the variable, if it is fixed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub value: Option<String>,
+}
+
+/// A scratch variable.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct ScratchVariable {
+    /// The scratch slot of the variable.
+    pub slot: u64,
+
+    /// The ABI type, AVM type or struct name of the variable.
+    #[serde(rename = "type")]
+    pub value_type: String,
+}
+
+impl Arc56Contract {
+    /// Parses an ARC-56 application specification.
+    ///
+    /// # Parameters
+    /// * `json` - The ARC-56 JSON
+    ///
+    /// # Returns
+    /// The application specification or an error if the JSON is not a valid ARC-56
+    /// specification.
+    pub fn from_json(json: &str) -> Result<Self, AlgoKitTransactError> {
+        serde_json::from_str(json).map_err(|e| AlgoKitTransactError::InvalidAppSpec(e.to_string()))
+    }
+
+    /// Serializes the application specification to ARC-56 JSON.
+    pub fn to_json(&self) -> Result<String, AlgoKitTransactError> {
+        serde_json::to_string(self).map_err(|e| AlgoKitTransactError::InvalidAppSpec(e.to_string()))
+    }
+
+    /// Finds a method by name or signature.
+    ///
+    /// # Parameters
+    /// * `name_or_signature` - The name of the method, or its signature if the name is shared by
+    ///   more than one method
+    ///
+    /// # Returns
+    /// The method, or an error if there is no method, or more than one method, with the name.
+    pub fn find_method(
+        &self,
+        name_or_signature: &str,
+    ) -> Result<&Arc56Method, AlgoKitTransactError> {
+        if name_or_signature.contains('(') {
+            for method in &self.methods {
+                if method.to_abi_method()?.signature() == name_or_signature {
+                    return Ok(method);
+                }
+            }
+            return Err(AlgoKitTransactError::InvalidAppSpec(format!(
+                "{} has no method {}",
+                self.name, name_or_signature
+            )));
+        }
+
+        let mut methods = self.methods.iter().filter(|m| m.name == name_or_signature);
+        match (methods.next(), methods.next()) {
+            (Some(method), None) => Ok(method),
+            (None, _) => Err(AlgoKitTransactError::InvalidAppSpec(format!(
+                "{} has no method {}",
+                self.name, name_or_signature
+            ))),
+            (Some(_), Some(_)) => Err(AlgoKitTransactError::InvalidAppSpec(format!(
+                "{} has more than one method named {}, use the method signature instead",
+                self.name, name_or_signature
+            ))),
+        }
+    }
+}
+
+impl Arc56Method {
+    /// Returns the ABI method, which is used to encode calls to the method.
+    pub fn to_abi_method(&self) -> Result<AbiMethod, AlgoKitTransactError> {
+        Ok(AbiMethod {
+            name: self.name.clone(),
+            description: self.desc.clone(),
+            args: self
+                .args
+                .iter()
+                .map(|arg| {
+                    Ok(AbiMethodArg {
+                        arg_type: arg.arg_type.parse()?,
+                        name: arg.name.clone(),
+                        description: arg.desc.clone(),
+                    })
+                })
+                .collect::<Result<_, AlgoKitTransactError>>()?,
+            returns: match self.returns.return_type.as_str() {
+                "void" => None,
+                return_type => Some(return_type.parse()?),
+            },
+            returns_description: self.returns.desc.clone(),
+        })
+    }
+}
@@ -0,0 +1,16 @@
+//! ARC-56 application specification support.
+//!
+//! [`Arc56Contract`] is a typed model of an ARC-56 application specification, which describes
+//! the methods, structs, state, bare actions, programs and events of an application. ARC-32
+//! specifications can also be loaded, and are converted to ARC-56.
+
+mod arc32;
+mod arc56;
+
+pub use arc56::{
+    Actions, Arc56Contract, Arc56Method, BoxRecommendation, CompilerInfo, CompilerVersion,
+    ContractState, DefaultValue, DefaultValueSource, Event, EventArg, MethodArg, MethodReturns,
+    Network, OnCompleteAction, PcOffsetMethod, ProgramSourceInfo, Programs, ProgramsSourceInfo,
+    Recommendations, Schema, ScratchVariable, SourceInfo, StateSchemas, StorageKey, StorageKeys,
+    StorageMap, StorageMaps, StructField, StructFieldType, TemplateVariable,
+};
@@ -0,0 +1,347 @@
+//! Composition of atomic transaction groups.
+//!
+//! This module is only available when the `signing` feature is enabled. It provides the
+//! [`TransactionComposer`], which collects transactions and ABI method calls, applies the
+//! suggested params of the network, pools the fees of the group, assigns the group and signs
+//! each transaction with the signer registered for its sender.
+
+use crate::abi::{AbiMethod, AbiMethodArgType, AbiMethodArgValue, AbiTransactionType};
+use crate::address::Address;
+use crate::constants::{Byte32, MAX_TX_GROUP_SIZE};
+use crate::error::AlgoKitTransactError;
+use crate::signing::TransactionSigner;
+use crate::traits::Transactions;
+use crate::transactions::{
+    ApplicationCallTransactionFields, GroupFeeParams, SignedTransaction, Transaction,
+    TransactionFeeParams,
+};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// The suggested params of the network, used for the transactions of a group.
+#[derive(Debug, PartialEq, Clone)]
+pub struct SuggestedParams {
+    /// The fee per byte of the transactions, in microALGO.
+    pub fee_per_byte: u64,
+
+    /// The minimum fee of a transaction, in microALGO.
+    pub min_fee: u64,
+
+    /// The first round the transactions are valid.
+    pub first_valid: u64,
+
+    /// The last round the transactions are valid.
+    pub last_valid: u64,
+
+    /// The genesis hash of the network.
+    pub genesis_hash: Byte32,
+
+    /// The genesis ID of the network.
+    pub genesis_id: String,
+}
+
+/// A transaction or ABI method call to add to a [`TransactionComposer`], along with its fee
+/// parameters.
+#[derive(Debug, Clone)]
+pub struct ComposerTransaction {
+    /// The transaction or method call.
+    pub transaction: ComposerTransactionType,
+
+    /// The fee parameters of the transaction, used when pooling the fees of the group.
+    pub fee_params: TransactionFeeParams,
+}
+
+/// The type of a [`ComposerTransaction`].
+#[derive(Debug, Clone)]
+pub enum ComposerTransactionType {
+    /// A transaction.
+    Transaction(Transaction),
+
+    /// An ABI method call.
+    MethodCall(MethodCall),
+}
+
+/// An ABI method call.
+///
+/// The application args of the application call are set from the method call, and the reference
+/// args are added to the front of its foreign arrays. Transaction args are added to the group
+/// immediately before the application call.
+#[derive(Debug, Clone)]
+pub struct MethodCall {
+    /// The application call, without application args.
+    pub app_call: ApplicationCallTransactionFields,
+
+    /// The called method.
+    pub method: AbiMethod,
+
+    /// The values of the method args, including transaction args, in order.
+    pub args: Vec<MethodCallArg>,
+}
+
+/// The value of an arg of an ABI method call.
+#[derive(Debug, Clone)]
+pub enum MethodCallArg {
+    /// An ABI value or reference.
+    Value(AbiMethodArgValue),
+
+    /// A transaction, which may itself be a method call.
+    Transaction(ComposerTransaction),
+}
+
+impl ComposerTransaction {
+    /// Sets the fee parameters of the transaction.
+    pub fn with_fee_params(mut self, fee_params: TransactionFeeParams) -> Self {
+        self.fee_params = fee_params;
+        self
+    }
+}
+
+impl From<Transaction> for ComposerTransaction {
+    fn from(transaction: Transaction) -> Self {
+        ComposerTransaction {
+            transaction: ComposerTransactionType::Transaction(transaction),
+            fee_params: TransactionFeeParams::default(),
+        }
+    }
+}
+
+impl From<MethodCall> for ComposerTransaction {
+    fn from(method_call: MethodCall) -> Self {
+        ComposerTransaction {
+            transaction: ComposerTransactionType::MethodCall(method_call),
+            fee_params: TransactionFeeParams::default(),
+        }
+    }
+}
+
+/// Composes an atomic group of transactions and ABI method calls.
+pub struct TransactionComposer {
+    suggested_params: SuggestedParams,
+    transactions: Vec<ComposerTransaction>,
+    signers: HashMap<Address, Arc<dyn TransactionSigner>>,
+}
+
+impl TransactionComposer {
+    /// Creates a composer which uses the supplied suggested params.
+    pub fn new(suggested_params: SuggestedParams) -> Self {
+        TransactionComposer {
+            suggested_params,
+            transactions: Vec::new(),
+            signers: HashMap::new(),
+        }
+    }
+
+    /// Registers the signer of the transactions sent by an account.
+    ///
+    /// # Parameters
+    /// * `sender` - The sender of the transactions
+    /// * `signer` - The signer, which signs for a different account if the sender is rekeyed
+    pub fn add_signer(&mut self, sender: Address, signer: Arc<dyn TransactionSigner>) -> &mut Self {
+        self.signers.insert(sender, signer);
+        self
+    }
+
+    /// Adds a transaction or ABI method call to the group.
+    pub fn add_transaction(&mut self, transaction: impl Into<ComposerTransaction>) -> &mut Self {
+        self.transactions.push(transaction.into());
+        self
+    }
+
+    /// Builds the transactions of the group.
+    ///
+    /// Header fields which are not set (first and last valid rounds, genesis hash and genesis ID)
+    /// are taken from the suggested params. Any fee or group already set is replaced by the pooled
+    /// fee of the group, and the group is assigned if there is more than one transaction.
+    ///
+    /// # Returns
+    /// The transactions in group order, or an error if a method call cannot be encoded, the
+    /// fees cannot be covered or the group is empty or too large.
+    pub fn build(&self) -> Result<Vec<Transaction>, AlgoKitTransactError> {
+        let mut transactions = Vec::new();
+        let mut fee_params = Vec::new();
+        for transaction in &self.transactions {
+            self.flatten(transaction, &mut transactions, &mut fee_params)?;
+        }
+
+        if transactions.is_empty() {
+            return Err(AlgoKitTransactError::InputError(String::from(
+                "Transaction group size cannot be 0",
+            )));
+        }
+        if transactions.len() > MAX_TX_GROUP_SIZE {
+            return Err(AlgoKitTransactError::InputError(format!(
+                "Transaction group size {} exceeds the max limit of {}",
+                transactions.len(),
+                MAX_TX_GROUP_SIZE
+            )));
+        }
+
+        let transactions = transactions.assign_fees(&GroupFeeParams {
+            fee_per_byte: self.suggested_params.fee_per_byte,
+            min_fee: self.suggested_params.min_fee,
+            transactions: fee_params,
+        })?;
+
+        if transactions.len() == 1 {
+            return Ok(transactions);
+        }
+        transactions.assign_group()
+    }
+
+    /// Builds the transactions of the group, as described in [`TransactionComposer::build`], and
+    /// signs each one with the signer registered for its sender.
+    ///
+    /// # Returns
+    /// The signed transactions in group order, or an error if building fails, a sender has no
+    /// registered signer or signing fails.
+    pub fn sign(&self) -> Result<Vec<SignedTransaction>, AlgoKitTransactError> {
+        self.build()?
+            .iter()
+            .map(|transaction| {
+                let sender = &transaction.header().sender;
+                let signer = self.signers.get(sender).ok_or_else(|| {
+                    AlgoKitTransactError::InputError(format!("No signer for sender {}", sender))
+                })?;
+                transaction.sign(signer.as_ref())
+            })
+            .collect()
+    }
+
+    fn flatten(
+        &self,
+        transaction: &ComposerTransaction,
+        transactions: &mut Vec<Transaction>,
+        fee_params: &mut Vec<TransactionFeeParams>,
+    ) -> Result<(), AlgoKitTransactError> {
+        let built = match &transaction.transaction {
+            ComposerTransactionType::Transaction(transaction) => transaction.clone(),
+            ComposerTransactionType::MethodCall(method_call) => {
+                self.flatten_method_call(method_call, transactions, fee_params)?
+            }
+        };
+        transactions.push(self.apply_suggested_params(built));
+        fee_params.push(transaction.fee_params.clone());
+        Ok(())
+    }
+
+    fn flatten_method_call(
+        &self,
+        method_call: &MethodCall,
+        transactions: &mut Vec<Transaction>,
+        fee_params: &mut Vec<TransactionFeeParams>,
+    ) -> Result<Transaction, AlgoKitTransactError> {
+        let method = &method_call.method;
+        if method.args.len() != method_call.args.len() {
+            return Err(AlgoKitTransactError::InputError(format!(
+                "{} expects {} args but got {}",
+                method.signature(),
+                method.args.len(),
+                method_call.args.len()
+            )));
+        }
+
+        let mut values = Vec::with_capacity(method_call.args.len());
+        for (arg, value) in method.args.iter().zip(&method_call.args) {
+            match (&arg.arg_type, value) {
+                (
+                    AbiMethodArgType::Transaction(transaction_type),
+                    MethodCallArg::Transaction(transaction),
+                ) => {
+                    self.flatten(transaction, transactions, fee_params)?;
+                    let arg_transaction = &transactions[transactions.len() - 1];
+                    if !is_transaction_type(arg_transaction, *transaction_type) {
+                        return Err(AlgoKitTransactError::InputError(format!(
+                            "{}: {} must be a {} transaction",
+                            method.signature(),
+                            arg.name.as_deref().unwrap_or("arg"),
+                            arg.arg_type
+                        )));
+                    }
+                }
+                (AbiMethodArgType::Transaction(_), MethodCallArg::Value(_))
+                | (_, MethodCallArg::Transaction(_)) => {
+                    return Err(AlgoKitTransactError::InputError(format!(
+                        "{}: {} is not a valid arg of type {}",
+                        method.signature(),
+                        arg.name.as_deref().unwrap_or("arg"),
+                        arg.arg_type
+                    )));
+                }
+                (_, MethodCallArg::Value(value)) => values.push(value.clone()),
+            }
+        }
+
+        let mut app_call = method_call.app_call.clone();
+        if app_call.args.is_some() {
+            return Err(AlgoKitTransactError::InputError(format!(
+                "{}: application args are set by the method call",
+                method.signature()
+            )));
+        }
+
+        let call_args =
+            method.encode_call_args(&values, &app_call.header.sender, app_call.app_id)?;
+        app_call.args = Some(call_args.args);
+        app_call.account_references =
+            merge_references(call_args.account_references, app_call.account_references);
+        app_call.app_references =
+            merge_references(call_args.app_references, app_call.app_references);
+        app_call.asset_references =
+            merge_references(call_args.asset_references, app_call.asset_references);
+
+        Ok(Transaction::ApplicationCall(app_call))
+    }
+
+    fn apply_suggested_params(&self, mut transaction: Transaction) -> Transaction {
+        let params = &self.suggested_params;
+        let header = transaction.header_mut();
+        header.group = None;
+        if header.first_valid == 0 {
+            header.first_valid = params.first_valid;
+        }
+        if header.last_valid == 0 {
+            header.last_valid = params.last_valid;
+        }
+        header.genesis_hash.get_or_insert(params.genesis_hash);
+        header
+            .genesis_id
+            .get_or_insert_with(|| params.genesis_id.clone());
+        transaction
+    }
+}
+
+fn is_transaction_type(transaction: &Transaction, transaction_type: AbiTransactionType) -> bool {
+    matches!(
+        (transaction_type, transaction),
+        (AbiTransactionType::Txn, _)
+            | (AbiTransactionType::Payment, Transaction::Payment(_))
+            | (
+                AbiTransactionType::KeyRegistration,
+                Transaction::KeyRegistration(_)
+            )
+            | (AbiTransactionType::AssetConfig, Transaction::AssetConfig(_))
+            | (
+                AbiTransactionType::AssetTransfer,
+                Transaction::AssetTransfer(_)
+            )
+            | (AbiTransactionType::AssetFreeze, Transaction::AssetFreeze(_))
+            | (
+                AbiTransactionType::ApplicationCall,
+                Transaction::ApplicationCall(_)
+            )
+    )
+}
+
+/// Appends the existing references of an application call after those referenced by the method
+/// args, so the indexes encoded in the args remain valid.
+fn merge_references<T: PartialEq>(
+    mut references: Vec<T>,
+    existing: Option<Vec<T>>,
+) -> Option<Vec<T>> {
+    for reference in existing.into_iter().flatten() {
+        if !references.contains(&reference) {
+            references.push(reference);
+        }
+    }
+    (!references.is_empty()).then_some(references)
+}
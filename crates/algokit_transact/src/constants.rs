@@ -3,7 +3,20 @@ pub const ALGORAND_CHECKSUM_BYTE_LENGTH: usize = 4;
 pub const ALGORAND_ADDRESS_LENGTH: usize = 58;
 pub const ALGORAND_PUBLIC_KEY_BYTE_LENGTH: usize = 32;
 pub const ALGORAND_SECRET_KEY_BYTE_LENGTH: usize = 32;
+pub const ALGORAND_MNEMONIC_WORD_COUNT: usize = 25;
 pub const ALGORAND_SIGNATURE_BYTE_LENGTH: usize = 64;
 pub const ALGORAND_SIGNATURE_ENCODING_INCR: usize = 75;
+pub const ALGORAND_STATE_PROOF_KEY_BYTE_LENGTH: usize = 64;
 pub type Byte32 = [u8; 32];
 pub const MAX_TX_GROUP_SIZE: usize = 16;
+pub const MULTISIG_VERSION: u8 = 1;
+pub const MULTISIG_ADDRESS_PREFIX: &[u8] = b"MultisigAddr";
+pub const PROGRAM_PREFIX: &[u8] = b"Program";
+pub const PROGRAM_DATA_PREFIX: &[u8] = b"ProgData";
+pub const MULTISIG_PROGRAM_PREFIX: &[u8] = b"MsigProgram";
+pub const APP_ID_PREFIX: &[u8] = b"appID";
+pub const ABI_RETURN_PREFIX: &[u8] = &[0x15, 0x1f, 0x7c, 0x75];
+pub const MAX_ASSET_DECIMALS: u32 = 19;
+pub const MAX_ASSET_UNIT_NAME_LENGTH: usize = 8;
+pub const MAX_ASSET_NAME_LENGTH: usize = 32;
+pub const MAX_ASSET_URL_LENGTH: usize = 96;
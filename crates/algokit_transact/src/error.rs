@@ -32,4 +32,37 @@ pub enum AlgoKitTransactError {
 
     #[error("{0}")]
     InvalidAddress(String),
+
+    #[error("{0}")]
+    InvalidSignature(String),
+
+    #[error("Invalid mnemonic: expected 25 words but got {0}")]
+    InvalidMnemonicLength(usize),
+
+    #[error("Invalid mnemonic: '{0}' is not in the wordlist")]
+    InvalidMnemonicWord(String),
+
+    #[error("Invalid mnemonic: checksum word does not match")]
+    InvalidMnemonicChecksum,
+
+    #[error("Invalid ABI type {0}")]
+    InvalidAbiType(String),
+
+    #[error("Invalid ABI method {0}")]
+    InvalidAbiMethod(String),
+
+    #[error("Error occurred during ABI encoding of {0}")]
+    AbiEncodingError(String),
+
+    #[error("Error occurred during ABI decoding of {0}")]
+    AbiDecodingError(String),
+
+    #[error("Invalid app spec: {0}")]
+    InvalidAppSpec(String),
+
+    #[error("Non-canonical encoding: {0}")]
+    NonCanonicalEncoding(String),
+
+    #[error("Invalid transaction: {}", .0.join("; "))]
+    InvalidTransaction(Vec<String>),
 }
@@ -1,29 +1,72 @@
+mod abi;
 mod address;
+pub mod app_spec;
+#[cfg(feature = "signing")]
+mod composer;
 pub mod constants;
 mod error;
+mod logic_signature;
+mod mnemonic;
 pub mod msgpack;
+mod multisig;
+#[cfg(feature = "signing")]
+mod signing;
 mod traits;
 mod transactions;
 mod utils;
+mod value_serializer;
 
 // Re-export all the public items
+pub use abi::{
+    AbiMethod, AbiMethodArg, AbiMethodArgType, AbiMethodArgValue, AbiMethodCallArgs,
+    AbiReferenceType, AbiTransactionType, AbiType, AbiValue,
+};
 pub use address::Address;
+pub use app_spec::Arc56Contract;
+#[cfg(feature = "signing")]
+pub use composer::{
+    ComposerTransaction, ComposerTransactionType, MethodCall, MethodCallArg, SuggestedParams,
+    TransactionComposer,
+};
 pub use constants::*;
 pub use error::AlgoKitTransactError;
+pub use logic_signature::LogicSignature;
+pub use mnemonic::{mnemonic_from_seed, seed_from_mnemonic};
+pub use multisig::{MultisigSignature, MultisigSubsignature};
+#[cfg(feature = "signing")]
+pub use signing::TransactionSigner;
 pub use traits::{AlgorandMsgpack, EstimateTransactionSize, TransactionId, Transactions};
 pub use transactions::{
-    AssetTransferTransactionBuilder, AssetTransferTransactionFields, FeeParams,
-    PaymentTransactionBuilder, PaymentTransactionFields, SignedTransaction, Transaction,
-    TransactionHeader, TransactionHeaderBuilder,
+    ApplicationCallTransactionBuilder, ApplicationCallTransactionFields,
+    AssetConfigTransactionBuilder, AssetConfigTransactionFields, AssetFreezeTransactionBuilder,
+    AssetFreezeTransactionFields, AssetParams, AssetTransferTransactionBuilder,
+    AssetTransferTransactionFields, BoxReference, ConsensusParams, FalconVerifier, FeeParams,
+    GroupFeeParams, HashFactory, HeartbeatParams, HeartbeatProof, HeartbeatTransactionFields,
+    KeyRegistrationTransactionBuilder, KeyRegistrationTransactionFields, MerkleArrayProof,
+    MerkleSignature, MerkleSignatureVerifier, OnApplicationComplete, Participant,
+    PaymentTransactionBuilder, PaymentTransactionFields, Reveal, SignedTransaction, SigslotCommit,
+    StateProof, StateProofMessage, StateProofTransactionFields, StateSchema, Transaction,
+    TransactionFeeParams, TransactionHeader, TransactionHeaderBuilder, UnknownFields,
 };
 
 // Re-export msgpack functionality
 pub use msgpack::{
-    decode_base64_msgpack_to_json, decode_msgpack_to_json, encode_json_to_base64_msgpack,
-    encode_json_to_msgpack, sort_and_filter_json, supported_models, AlgoKitMsgPackError,
-    ModelRegistry, ModelType, ToMsgPack,
+    decode_base64_msgpack_to_json, decode_msgpack_to_json, decode_msgpack_to_json_lossless,
+    encode_json_to_base64_msgpack, encode_json_to_msgpack, encode_json_to_msgpack_lossless,
+    sort_and_filter_json, stream_msgpack_to_json, supported_models, AlgoKitMsgPackError,
+    BlockReader, BytesFormat, ModelRegistry, ModelType, ToMsgPack,
 };
 
+// The generated algod models refer to each other through `crate::models`
+use msgpack::algod as models;
+
+// Re-export the signing key type so callers don't need to depend on the same ed25519-dalek version
+#[cfg(feature = "signing")]
+pub use ed25519_dalek::SigningKey;
+
+// Re-export the big integer type used for ABI uint values
+pub use num_bigint::BigUint;
+
 #[cfg(test)]
 mod tests;
 
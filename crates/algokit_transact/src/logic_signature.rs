@@ -0,0 +1,141 @@
+//! Logic signature (smart signature) support for Algorand transactions.
+//!
+//! This module provides the [`LogicSignature`] type which is attached to a signed transaction
+//! when the transaction is authorized by a TEAL program rather than (or in addition to) a key.
+
+use crate::address::Address;
+use crate::constants::{
+    ALGORAND_SIGNATURE_BYTE_LENGTH, MULTISIG_PROGRAM_PREFIX, PROGRAM_DATA_PREFIX, PROGRAM_PREFIX,
+};
+use crate::multisig::MultisigSignature;
+use crate::transactions::UnknownFields;
+use crate::utils::is_empty_vec_opt;
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, Bytes};
+
+/// A logic signature, which authorizes a transaction with a TEAL program.
+///
+/// - Escrow (contract account): only the program and args are set, and the transaction sender
+///   is the address of the program.
+/// - Delegated: the program has additionally been signed by the sender's key (`signature`) or
+///   multisig account (`logic_multisignature`, or the legacy `multisignature`), allowing the
+///   program to authorize transactions on behalf of that account.
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+pub struct LogicSignature {
+    /// The compiled TEAL program.
+    #[serde(rename = "l")]
+    #[serde_as(as = "Bytes")]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
+    pub logic: Vec<u8>,
+
+    /// The arguments passed to the program.
+    #[serde(rename = "arg")]
+    #[serde_as(as = "Option<Vec<Bytes>>")]
+    #[serde(skip_serializing_if = "is_empty_vec_opt")]
+    #[serde(default)]
+    pub args: Option<Vec<Vec<u8>>>,
+
+    /// The Ed25519 signature of the program by the delegating account.
+    #[serde(rename = "sig")]
+    #[serde_as(as = "Option<Bytes>")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub signature: Option<[u8; ALGORAND_SIGNATURE_BYTE_LENGTH]>,
+
+    /// The multisig signature of the program by the delegating multisig account.
+    ///
+    /// The signature covers only the program, so can be reused by any multisig account of the
+    /// same participants. Prefer `logic_multisignature`.
+    #[serde(rename = "msig")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub multisignature: Option<MultisigSignature>,
+
+    /// The multisig signature of the program and the address of the delegating multisig account.
+    #[serde(rename = "lmsig")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub logic_multisignature: Option<MultisigSignature>,
+
+    /// Fields of the logic signature which are not recognised by this crate.
+    #[serde(flatten)]
+    #[serde(skip_serializing_if = "UnknownFields::is_empty")]
+    #[serde(default)]
+    pub unknown_fields: UnknownFields,
+}
+
+impl LogicSignature {
+    /// Creates an escrow logic signature for the supplied program and args.
+    ///
+    /// # Parameters
+    /// * `logic` - The compiled TEAL program
+    /// * `args` - The optional arguments passed to the program
+    pub fn new(logic: Vec<u8>, args: Option<Vec<Vec<u8>>>) -> Self {
+        Self {
+            logic,
+            args,
+            ..Default::default()
+        }
+    }
+
+    /// Drops the fields which aren't recognised, including those of the multisig signature.
+    pub(crate) fn drop_unknown_fields(&mut self) {
+        self.unknown_fields.clear();
+        for multisig in [&mut self.multisignature, &mut self.logic_multisignature]
+            .into_iter()
+            .flatten()
+        {
+            multisig.unknown_fields.clear();
+        }
+    }
+
+    /// Returns the address of the program, which is the sender of escrow transactions.
+    pub fn address(&self) -> Address {
+        Address::from_program(&self.logic)
+    }
+
+    /// Returns true if the program has been signed by a delegating account.
+    pub fn is_delegated(&self) -> bool {
+        self.signature.is_some()
+            || self.multisignature.is_some()
+            || self.logic_multisignature.is_some()
+    }
+
+    /// Returns the bytes a delegating account must sign to delegate to this program.
+    ///
+    /// This is the `Program` domain separation prefix followed by the program.
+    pub fn bytes_to_sign(&self) -> Vec<u8> {
+        [PROGRAM_PREFIX, self.logic.as_slice()].concat()
+    }
+
+    /// Returns the bytes the participants of a delegating multisig account must sign for
+    /// `logic_multisignature`.
+    ///
+    /// This is the `MsigProgram` domain separation prefix followed by the multisig address and
+    /// the program.
+    ///
+    /// # Parameters
+    /// * `multisig_address` - The address of the delegating multisig account
+    pub fn multisig_bytes_to_sign(&self, multisig_address: &Address) -> Vec<u8> {
+        [
+            MULTISIG_PROGRAM_PREFIX,
+            &multisig_address.pub_key,
+            self.logic.as_slice(),
+        ]
+        .concat()
+    }
+
+    /// Returns the bytes which must be signed to produce a signature which this program can
+    /// verify with the `ed25519verify` opcode.
+    ///
+    /// This is the `ProgData` domain separation prefix followed by the program address and
+    /// the data.
+    ///
+    /// # Parameters
+    /// * `data` - The data to be verified by the program
+    pub fn program_data_to_sign(&self, data: &[u8]) -> Vec<u8> {
+        [PROGRAM_DATA_PREFIX, &self.address().pub_key, data].concat()
+    }
+}
@@ -0,0 +1,131 @@
+//! Algorand 25-word mnemonic encoding and decoding of account keys.
+//!
+//! A mnemonic encodes a 32-byte Ed25519 seed (secret key) as 24 words from the BIP-39 English
+//! wordlist, each word holding 11 bits of the seed in little-endian order, followed by a
+//! checksum word derived from the SHA-512/256 hash of the seed.
+//!
+//! Note that while the wordlist is shared with BIP-39, the encoding is not BIP-39 compatible.
+
+mod wordlist;
+
+use crate::constants::{Byte32, ALGORAND_MNEMONIC_WORD_COUNT, ALGORAND_SECRET_KEY_BYTE_LENGTH};
+use crate::error::AlgoKitTransactError;
+use crate::utils::hash;
+use wordlist::ENGLISH;
+
+const BITS_PER_WORD: usize = 11;
+const WORD_MASK: u32 = (1 << BITS_PER_WORD) - 1;
+
+/// Encodes a 32-byte seed as a 25-word mnemonic.
+///
+/// # Parameters
+/// * `seed` - The 32-byte Ed25519 seed (secret key) of the account
+///
+/// # Returns
+/// The mnemonic as space separated words.
+pub fn mnemonic_from_seed(seed: &Byte32) -> String {
+    let mut words: Vec<&str> = to_u11(seed)
+        .into_iter()
+        .map(|index| ENGLISH[index as usize])
+        .collect();
+    words.push(checksum_word(seed));
+    words.join(" ")
+}
+
+/// Decodes a 25-word mnemonic into the 32-byte seed it encodes.
+///
+/// Words are separated by whitespace and matched case-insensitively.
+///
+/// # Parameters
+/// * `mnemonic` - The 25-word mnemonic
+///
+/// # Returns
+/// The 32-byte seed or an error if the mnemonic has the wrong number of words, contains a word
+/// which is not in the wordlist, or the checksum word does not match.
+pub fn seed_from_mnemonic(mnemonic: &str) -> Result<Byte32, AlgoKitTransactError> {
+    let words: Vec<String> = mnemonic.split_whitespace().map(str::to_lowercase).collect();
+
+    if words.len() != ALGORAND_MNEMONIC_WORD_COUNT {
+        return Err(AlgoKitTransactError::InvalidMnemonicLength(words.len()));
+    }
+
+    let indexes = words
+        .iter()
+        .map(|word| {
+            ENGLISH
+                .binary_search(&word.as_str())
+                .map(|index| index as u32)
+                .map_err(|_| AlgoKitTransactError::InvalidMnemonicWord(word.clone()))
+        })
+        .collect::<Result<Vec<u32>, _>>()?;
+
+    let (checksum, key_words) = indexes.split_last().expect("mnemonic has 25 words");
+    let bytes = from_u11(key_words);
+
+    // 24 words hold 264 bits, so the final byte is padding and must be empty
+    if bytes[ALGORAND_SECRET_KEY_BYTE_LENGTH..]
+        .iter()
+        .any(|b| *b != 0)
+    {
+        return Err(AlgoKitTransactError::InvalidMnemonicChecksum);
+    }
+
+    let mut seed = [0u8; ALGORAND_SECRET_KEY_BYTE_LENGTH];
+    seed.copy_from_slice(&bytes[..ALGORAND_SECRET_KEY_BYTE_LENGTH]);
+
+    if ENGLISH[*checksum as usize] != checksum_word(&seed) {
+        return Err(AlgoKitTransactError::InvalidMnemonicChecksum);
+    }
+
+    Ok(seed)
+}
+
+/// Returns the checksum word, which is the first 11 bits of the SHA-512/256 hash of the seed.
+fn checksum_word(seed: &Byte32) -> &'static str {
+    let digest = hash(&seed.to_vec());
+    ENGLISH[to_u11(&digest[..2])[0] as usize]
+}
+
+/// Packs bytes into 11-bit values, least significant bit first.
+fn to_u11(bytes: &[u8]) -> Vec<u32> {
+    let mut values = Vec::with_capacity((bytes.len() * 8).div_ceil(BITS_PER_WORD));
+    let mut buffer = 0u32;
+    let mut bits = 0;
+
+    for byte in bytes {
+        buffer |= (*byte as u32) << bits;
+        bits += 8;
+        if bits >= BITS_PER_WORD {
+            values.push(buffer & WORD_MASK);
+            buffer >>= BITS_PER_WORD;
+            bits -= BITS_PER_WORD;
+        }
+    }
+    if bits > 0 {
+        values.push(buffer & WORD_MASK);
+    }
+
+    values
+}
+
+/// Unpacks 11-bit values into bytes, least significant bit first.
+fn from_u11(values: &[u32]) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity((values.len() * BITS_PER_WORD).div_ceil(8));
+    let mut buffer = 0u32;
+    let mut bits = 0;
+
+    for value in values {
+        buffer |= value << bits;
+        bits += BITS_PER_WORD;
+        while bits >= 8 {
+            bytes.push((buffer & 0xff) as u8);
+            buffer >>= 8;
+            bits -= 8;
+        }
+    }
+    if bits > 0 {
+        bytes.push((buffer & 0xff) as u8);
+    }
+
+    bytes
+}
@@ -7,7 +7,12 @@ use std::collections::{BTreeMap, HashMap};
 use thiserror::Error;
 
 mod models;
+mod schema;
+mod stream;
+pub(crate) use models::transaction::signed_transaction_json;
 pub use models::*;
+pub use schema::{decode_msgpack_to_json_lossless, encode_json_to_msgpack_lossless, BytesFormat};
+pub use stream::{stream_msgpack_to_json, BlockReader, BlockTransactions};
 
 #[derive(Debug, Error)]
 pub enum AlgoKitMsgPackError {
@@ -27,6 +32,10 @@ pub enum AlgoKitMsgPackError {
     IoError(String),
     #[error("Error occurred during value writing: {0}")]
     ValueWriteError(String),
+    #[error("Error occurred during transaction encoding or decoding: {0}")]
+    TransactionError(#[from] crate::AlgoKitTransactError),
+    #[error("Value doesn't match the model schema: {0}")]
+    SchemaError(String),
 }
 
 impl From<std::io::Error> for AlgoKitMsgPackError {
@@ -162,6 +171,20 @@ impl ModelRegistry {
             .insert(model_type, Box::new(TypedModelHandler::<T>::new()));
     }
 
+    /// Registers a model which algod only returns, so can be decoded from msgpack but not encoded.
+    ///
+    /// The msgpack is decoded to JSON, with binary values base64 encoded, then checked against the
+    /// model.
+    pub fn register_response<T>(&mut self, model_type: ModelType)
+    where
+        T: DeserializeOwned + Serialize + 'static,
+    {
+        self.registry.insert(
+            model_type,
+            Box::new(ResponseModelHandler::<T>::new(model_type)),
+        );
+    }
+
     pub fn encode_json_to_msgpack(&self, model_type: ModelType, json_str: &str) -> Result<Vec<u8>> {
         if let Some(handler) = self.registry.get(&model_type) {
             handler.encode_json_to_msgpack(json_str)
@@ -255,10 +278,118 @@ where
     }
 }
 
+struct ResponseModelHandler<T>
+where
+    T: DeserializeOwned + Serialize,
+{
+    model_type: ModelType,
+    _phantom: std::marker::PhantomData<T>,
+}
+
+impl<T> ResponseModelHandler<T>
+where
+    T: DeserializeOwned + Serialize,
+{
+    fn new(model_type: ModelType) -> Self {
+        Self {
+            model_type,
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<T> ModelHandler for ResponseModelHandler<T>
+where
+    T: DeserializeOwned + Serialize,
+{
+    fn encode_json_to_msgpack(&self, _json_str: &str) -> Result<Vec<u8>> {
+        Err(AlgoKitMsgPackError::MsgpackWriteError(format!(
+            "{} encoding is not supported",
+            self.model_type
+        )))
+    }
+
+    fn decode_msgpack_to_json(&self, msgpack_bytes: &[u8]) -> Result<String> {
+        let root: rmpv::Value = rmpv::decode::read_value(&mut &msgpack_bytes[..])
+            .map_err(|e| AlgoKitMsgPackError::IoError(e.to_string()))?;
+        let model: T = serde_json::from_value(rmpv_to_json(&root))?;
+        Ok(serde_json::to_string(&model)?)
+    }
+}
+
+/// Converts a msgpack value to JSON, base64 encoding binary values.
+pub(crate) fn rmpv_to_json(value: &rmpv::Value) -> Value {
+    use rmpv::Value as V;
+    match value {
+        V::Nil => Value::Null,
+        V::Boolean(b) => Value::Bool(*b),
+        V::Integer(i) => {
+            if let Some(n) = i.as_i64() {
+                Value::Number(n.into())
+            } else if let Some(n) = i.as_u64() {
+                Value::Number(serde_json::Number::from(n))
+            } else {
+                Value::String(i.to_string())
+            }
+        }
+        // JSON has no representation of NaN or infinite numbers
+        V::F32(f) => serde_json::Number::from_f64(*f as f64).map_or(Value::Null, Into::into),
+        V::F64(f) => serde_json::Number::from_f64(*f).map_or(Value::Null, Into::into),
+        V::String(s) => Value::String(s.as_str().unwrap_or_default().into()),
+        V::Binary(b) | V::Ext(_, b) => Value::String(BASE64.encode(b)),
+        V::Array(arr) => Value::Array(arr.iter().map(rmpv_to_json).collect()),
+        V::Map(map) if map.first().is_some_and(|(k, _)| is_struct_key(k)) => Value::Array(
+            map.iter()
+                .map(|(k, v)| struct_key_entry(rmpv_to_json(k), rmpv_to_json(v)))
+                .collect(),
+        ),
+        V::Map(map) => {
+            let mut m = serde_json::Map::with_capacity(map.len());
+            for (k, v) in map {
+                m.insert(json_key(k), rmpv_to_json(v));
+            }
+            Value::Object(m)
+        }
+    }
+}
+
+/// Whether a msgpack map key is a map or array, such as the `Txlease` keys of a ledger state
+/// delta, which can't be a JSON object key without losing information.
+pub(crate) fn is_struct_key(key: &rmpv::Value) -> bool {
+    matches!(key, rmpv::Value::Map(_) | rmpv::Value::Array(_))
+}
+
+/// Renders an entry of a map with struct keys, which is represented as an array of these.
+pub(crate) fn struct_key_entry(key: Value, value: Value) -> Value {
+    let mut entry = serde_json::Map::with_capacity(2);
+    entry.insert("key".into(), key);
+    entry.insert("value".into(), value);
+    Value::Object(entry)
+}
+
+/// Converts a msgpack map key to a JSON object key.
+pub(crate) fn json_key(key: &rmpv::Value) -> String {
+    match key {
+        rmpv::Value::String(s) => s.as_str().unwrap_or_default().to_string(),
+        // e.g. the transaction IDs of a ledger state delta
+        rmpv::Value::Binary(b) => BASE64.encode(b),
+        _ => key.to_string(),
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum ModelType {
     SimulateRequest,
     SimulateTransaction200Response,
+    Account,
+    AccountAssetInformation,
+    AccountApplicationInformation,
+    Block,
+    PendingTransactionResponse,
+    LedgerStateDelta,
+    TransactionParams,
+    Box,
+    StateProof,
 }
 
 impl ModelType {
@@ -267,6 +398,15 @@ impl ModelType {
         match self {
             ModelType::SimulateRequest => "SimulateRequest",
             ModelType::SimulateTransaction200Response => "SimulateTransaction200Response",
+            ModelType::Account => "Account",
+            ModelType::AccountAssetInformation => "AccountAssetInformation",
+            ModelType::AccountApplicationInformation => "AccountApplicationInformation",
+            ModelType::Block => "Block",
+            ModelType::PendingTransactionResponse => "PendingTransactionResponse",
+            ModelType::LedgerStateDelta => "LedgerStateDelta",
+            ModelType::TransactionParams => "TransactionParams",
+            ModelType::Box => "Box",
+            ModelType::StateProof => "StateProof",
         }
     }
 
@@ -276,6 +416,15 @@ impl ModelType {
         match s {
             "SimulateRequest" => Some(ModelType::SimulateRequest),
             "SimulateTransaction200Response" => Some(ModelType::SimulateTransaction200Response),
+            "Account" => Some(ModelType::Account),
+            "AccountAssetInformation" => Some(ModelType::AccountAssetInformation),
+            "AccountApplicationInformation" => Some(ModelType::AccountApplicationInformation),
+            "Block" => Some(ModelType::Block),
+            "PendingTransactionResponse" => Some(ModelType::PendingTransactionResponse),
+            "LedgerStateDelta" => Some(ModelType::LedgerStateDelta),
+            "TransactionParams" => Some(ModelType::TransactionParams),
+            "Box" => Some(ModelType::Box),
+            "StateProof" => Some(ModelType::StateProof),
             _ => None,
         }
     }
@@ -285,6 +434,15 @@ impl ModelType {
         vec![
             ModelType::SimulateRequest,
             ModelType::SimulateTransaction200Response,
+            ModelType::Account,
+            ModelType::AccountAssetInformation,
+            ModelType::AccountApplicationInformation,
+            ModelType::Block,
+            ModelType::PendingTransactionResponse,
+            ModelType::LedgerStateDelta,
+            ModelType::TransactionParams,
+            ModelType::Box,
+            ModelType::StateProof,
         ]
     }
 }
@@ -315,6 +473,27 @@ pub fn supported_models() -> Vec<ModelType> {
     registry.list_models()
 }
 
+// Allow users to use the standard `str::parse()` style API as well.
+impl std::str::FromStr for ModelType {
+    type Err = ();
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        ModelType::from_str(s).ok_or(())
+    }
+}
+
+// Provide a blanket implementation so every `Serialize` type automatically
+// gets the `ToMsgPack` methods.
+impl<T> ToMsgPack for T where T: Serialize {}
+
+// Implement `Display` for `ModelType` so it can be printed directly and used in
+// other error types if needed.
+impl std::fmt::Display for ModelType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -370,25 +549,1203 @@ mod tests {
         let nested_str = serde_json::to_string(nested_obj).unwrap();
         assert!(nested_str.find("\"x\"").unwrap() < nested_str.find("\"y\"").unwrap());
     }
-}
 
-// Allow users to use the standard `str::parse()` style API as well.
-impl std::str::FromStr for ModelType {
-    type Err = ();
+    fn encode_rmpv(value: rmpv::Value) -> Vec<u8> {
+        let mut buf = Vec::new();
+        crate::utils::write_msgpack_value(&mut buf, &value).unwrap();
+        buf
+    }
 
-    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
-        ModelType::from_str(s).ok_or(())
+    fn rmpv_map(entries: Vec<(&str, rmpv::Value)>) -> rmpv::Value {
+        rmpv::Value::Map(
+            entries
+                .into_iter()
+                .map(|(key, value)| (rmpv::Value::from(key), value))
+                .collect(),
+        )
     }
-}
 
-// Provide a blanket implementation so every `Serialize` type automatically
-// gets the `ToMsgPack` methods.
-impl<T> ToMsgPack for T where T: Serialize {}
+    #[test]
+    fn test_model_types() {
+        let registry = ModelRegistry::default();
+        for model_type in ModelType::all() {
+            assert_eq!(ModelType::from_str(model_type.as_str()), Some(model_type));
+            assert!(registry.list_models().contains(&model_type));
+        }
+        assert_eq!(supported_models().len(), ModelType::all().len());
 
-// Implement `Display` for `ModelType` so it can be printed directly and used in
-// other error types if needed.
-impl std::fmt::Display for ModelType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.as_str())
+        let error = encode_json_to_msgpack(ModelType::Block, r#"{"block":{}}"#).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Error occurred during msgpack writing: Block encoding is not supported"
+        );
+    }
+
+    #[test]
+    fn test_decode_account() {
+        let manager = [7u8; 32];
+        let msgpack = encode_rmpv(rmpv_map(vec![
+            ("algo", rmpv::Value::from(5_000_000)),
+            (
+                "apar",
+                rmpv::Value::Map(vec![(
+                    rmpv::Value::from(1234),
+                    rmpv_map(vec![
+                        ("an", rmpv::Value::from("Token")),
+                        ("m", rmpv::Value::Binary(manager.to_vec())),
+                        ("t", rmpv::Value::from(u64::MAX)),
+                        ("un", rmpv::Value::Binary(vec![0xff])),
+                    ]),
+                )]),
+            ),
+            (
+                "appl",
+                rmpv::Value::Map(vec![(
+                    rmpv::Value::from(55),
+                    rmpv_map(vec![
+                        ("hsch", rmpv_map(vec![("nui", rmpv::Value::from(1))])),
+                        (
+                            "tkv",
+                            rmpv::Value::Map(vec![(
+                                rmpv::Value::from("counter"),
+                                rmpv_map(vec![
+                                    ("tt", rmpv::Value::from(2)),
+                                    ("ui", rmpv::Value::from(3)),
+                                ]),
+                            )]),
+                        ),
+                    ]),
+                )]),
+            ),
+            (
+                "asset",
+                rmpv::Value::Map(vec![(
+                    rmpv::Value::from(1234),
+                    rmpv_map(vec![("a", rmpv::Value::from(10))]),
+                )]),
+            ),
+            ("onl", rmpv::Value::from(1)),
+            ("vote", rmpv::Value::Binary(vec![1; 32])),
+            ("voteKD", rmpv::Value::from(10_000)),
+        ]));
+
+        let account = decode_account(&msgpack).unwrap();
+        let asset_params = &account.created_assets.as_ref().unwrap()[0];
+        assert_eq!(account.amount, 5_000_000);
+        assert_eq!(account.status, "Online");
+        assert_eq!(account.total_created_assets, 1);
+        assert_eq!(account.total_assets_opted_in, 1);
+        assert_eq!(account.total_apps_opted_in, 1);
+        assert_eq!(account.total_created_apps, 0);
+        assert_eq!(asset_params.index, 1234);
+        assert_eq!(asset_params.params.total, u64::MAX);
+        assert_eq!(asset_params.params.name.as_deref(), Some("Token"));
+        assert_eq!(asset_params.params.name_b64.as_deref(), Some(&b"Token"[..]));
+        assert_eq!(asset_params.params.unit_name, None);
+        assert_eq!(
+            asset_params.params.unit_name_b64.as_deref(),
+            Some(&[0xff][..])
+        );
+        assert_eq!(
+            asset_params.params.manager,
+            Some(crate::Address::from_pubkey(&manager).to_string())
+        );
+        assert_eq!(
+            account.assets,
+            Some(vec![AssetHolding {
+                amount: 10,
+                asset_id: 1234,
+                is_frozen: false,
+            }])
+        );
+        assert_eq!(
+            account.apps_local_state,
+            Some(vec![ApplicationLocalState {
+                id: 55,
+                schema: ApplicationStateSchema {
+                    num_uint: 1,
+                    num_byte_slice: 0,
+                },
+                key_value: Some(vec![TealKeyValue {
+                    key: BASE64.encode("counter"),
+                    value: TealValue {
+                        r#type: 2,
+                        bytes: String::new(),
+                        uint: 3,
+                    },
+                }]),
+            }])
+        );
+        assert_eq!(
+            account.participation.as_ref().unwrap().vote_key_dilution,
+            10_000
+        );
+
+        let json = decode_msgpack_to_json(ModelType::Account, &msgpack).unwrap();
+        let decoded: AccountData = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded, account);
+        assert_eq!(
+            serde_json::from_str::<Value>(&json).unwrap()["created-assets"][0]["params"]
+                ["name-b64"],
+            "VG9rZW4="
+        );
+    }
+
+    #[test]
+    fn test_decode_account_asset_and_application_information() {
+        let msgpack = encode_rmpv(rmpv_map(vec![
+            (
+                "asset-holding",
+                rmpv_map(vec![
+                    ("a", rmpv::Value::from(3)),
+                    ("f", rmpv::Value::from(true)),
+                ]),
+            ),
+            ("round", rmpv::Value::from(42)),
+        ]));
+        let info = decode_account_asset_information(&msgpack).unwrap();
+        assert_eq!(info.round, 42);
+        assert_eq!(info.created_asset, None);
+        assert_eq!(
+            info.asset_holding,
+            Some(AccountAssetHolding {
+                amount: 3,
+                is_frozen: true,
+            })
+        );
+
+        let msgpack = encode_rmpv(rmpv_map(vec![
+            (
+                "app-params",
+                rmpv_map(vec![
+                    ("approv", rmpv::Value::Binary(vec![0x0a, 0x81, 0x01])),
+                    ("clearp", rmpv::Value::Binary(vec![0x0a])),
+                    ("epp", rmpv::Value::from(1)),
+                ]),
+            ),
+            ("round", rmpv::Value::from(42)),
+        ]));
+        let info = decode_account_application_information(&msgpack).unwrap();
+        let app_params = info.created_app.unwrap();
+        assert_eq!(info.round, 42);
+        assert_eq!(app_params.approval_program, [0x0a, 0x81, 0x01]);
+        assert_eq!(app_params.clear_state_program, [0x0a]);
+        assert_eq!(app_params.extra_program_pages, Some(1));
+    }
+
+    #[test]
+    fn test_decode_pending_transaction_response() {
+        let msgpack = encode_rmpv(rmpv_map(vec![
+            ("confirmed-round", rmpv::Value::from(100)),
+            (
+                "global-state-delta",
+                rmpv::Value::Array(vec![rmpv_map(vec![
+                    ("key", rmpv::Value::from("Y291bnRlcg==")),
+                    ("value", rmpv_map(vec![("action", rmpv::Value::from(2))])),
+                ])]),
+            ),
+            (
+                "logs",
+                rmpv::Value::Array(vec![rmpv::Value::Binary(b"log".to_vec())]),
+            ),
+            ("pool-error", rmpv::Value::from("")),
+            ("txn", signed_transaction_value()),
+        ]));
+        let json = decode_msgpack_to_json(ModelType::PendingTransactionResponse, &msgpack).unwrap();
+        let response: PendingTransactionResponse = serde_json::from_str(&json).unwrap();
+        assert_eq!(response.confirmed_round, Some(100));
+        assert_eq!(response.pool_error, "");
+        assert_eq!(response.logs, Some(vec![BASE64.encode("log")]));
+        assert_eq!(
+            response.global_state_delta.unwrap()[0].value,
+            EvalDelta {
+                action: 2,
+                bytes: None,
+                uint: None,
+            }
+        );
+        assert_eq!(response.txn, signed_transaction());
+        assert_eq!(
+            serde_json::from_str::<Value>(&json).unwrap()["txn"],
+            rmpv_to_json(&signed_transaction_value())
+        );
+
+        let error = decode_msgpack_to_json(
+            ModelType::PendingTransactionResponse,
+            &encode_rmpv(rmpv_map(vec![("logs", rmpv::Value::from(1))])),
+        )
+        .unwrap_err();
+        assert!(matches!(error, AlgoKitMsgPackError::SerializationError(_)));
+    }
+
+    #[test]
+    fn test_decode_generated_models() {
+        let decode = |model_type| {
+            decode_msgpack_to_json(model_type, &encode_rmpv(lossless_fixture(model_type))).unwrap()
+        };
+
+        let model_box: ModelBox = serde_json::from_str(&decode(ModelType::Box)).unwrap();
+        assert_eq!(model_box, ModelBox::new(5, vec![2; 4], vec![3; 8]));
+
+        let params: TransactionParams200Response =
+            serde_json::from_str(&decode(ModelType::TransactionParams)).unwrap();
+        assert_eq!(params.genesis_hash, [2; 32]);
+        assert_eq!(params.min_fee, 1000);
+
+        let state_proof: StateProof = serde_json::from_str(&decode(ModelType::StateProof)).unwrap();
+        assert_eq!(state_proof.message.last_attested_round, 256);
+        assert_eq!(state_proof.message.voters_commitment, [3; 32]);
+        assert_eq!(state_proof.state_proof, [4; 64]);
+    }
+
+    #[test]
+    fn test_decode_block_and_ledger_state_delta() {
+        let msgpack = encode_rmpv(lossless_fixture(ModelType::Block));
+        let json = decode_msgpack_to_json(ModelType::Block, &msgpack).unwrap();
+        let block: Block = serde_json::from_str(&json).unwrap();
+        assert_eq!(block.block.round, 5);
+        assert_eq!(block.block.genesis_hash, Some(BASE64.encode([2; 32])));
+        assert_eq!(
+            block.block.state_proof_tracking.unwrap()["0"].next_round,
+            Some(256)
+        );
+        let transactions = block.block.transactions.unwrap();
+        assert_eq!(transactions[0].signed_transaction, signed_transaction());
+        assert_eq!(transactions[0].has_genesis_id, Some(true));
+        let eval_delta = transactions[0].eval_delta.as_ref().unwrap();
+        assert_eq!(eval_delta.logs, Some(vec!["log".to_string()]));
+        assert_eq!(eval_delta.global_delta.as_ref().unwrap()[""].action, 1);
+        let cert = block.cert.unwrap();
+        assert_eq!(cert.proposal.block_digest, Some(BASE64.encode([7; 32])));
+        assert_eq!(cert.votes.unwrap()[0].sender, BASE64.encode([12; 32]));
+
+        let msgpack = encode_rmpv(lossless_fixture(ModelType::LedgerStateDelta));
+        let json = decode_msgpack_to_json(ModelType::LedgerStateDelta, &msgpack).unwrap();
+        let delta: LedgerStateDelta = serde_json::from_str(&json).unwrap();
+        let account = &delta.accounts.unwrap().accounts.unwrap()[0];
+        assert_eq!(account.address, BASE64.encode([3; 32]));
+        assert_eq!(
+            account.account_data.account_base_data.micro_algos,
+            Some(1000)
+        );
+        assert_eq!(delta.header.unwrap().round, 5);
+        assert_eq!(delta.kv_mods.unwrap()[""].data, Some(BASE64.encode([5; 4])));
+        // Binary map keys are base64 encoded
+        assert_eq!(
+            delta.txids.unwrap()[&BASE64.encode([6; 32])].last_valid,
+            1000
+        );
+        // Struct map keys are kept as key and value entries
+        let txleases = delta.txleases.unwrap();
+        assert_eq!(txleases.len(), 2);
+        assert_eq!(txleases[1].lease.sender, BASE64.encode([3; 32]));
+        assert_eq!(txleases[1].lease.lease, BASE64.encode([9; 32]));
+        assert_eq!(txleases[1].expires, 1001);
+
+        let json = r#"{"Txleases":{}}"#;
+        let delta: LedgerStateDelta = serde_json::from_str(json).unwrap();
+        assert_eq!(delta.txleases, Some(Vec::new()));
+    }
+
+    #[test]
+    fn test_decode_simulate_response() {
+        let base64_msgpack = "hq5ldmFsLW92ZXJyaWRlc4S2YWxsb3ctZW1wdHktc2lnbmF0dXJlc8O3YWxsb3ctdW5uYW1lZC1yZXNvdXJjZXPDrW1heC1sb2ctY2FsbHPNCACsbWF4LWxvZy1zaXplzgABAACxZXhlYy10cmFjZS1jb25maWeEpmVuYWJsZcOuc2NyYXRjaC1jaGFuZ2XDrHN0YWNrLWNoYW5nZcOsc3RhdGUtY2hhbmdlw65pbml0aWFsLXN0YXRlc4CqbGFzdC1yb3VuZDWqdHhuLWdyb3Vwc5GBq3R4bi1yZXN1bHRzkYGqdHhuLXJlc3VsdIKqcG9vbC1lcnJvcqCjdHhugqNzaWfEQMRvOrLGLclzOfFppoyvhgTXsC+h/Qw59v5hc4k7CA9oVmEJZpcqjxweDlJg1C/vElTWwXL0zA/U59Ua/DjLhw+jdHhuiaNhbXTOAA9CQKNmZWXNA+iiZnY1o2dlbqxkb2NrZXJuZXQtdjGiZ2jEIEeJCm8ejvOqNCXVH+4GP95TdhioDiMH0wMRTIiwAmAUomx2zQQdo3JjdsQgOpJtq/2KwvdRn45on+Fhv0qXhguGb2ZMduXle8VCoPSjc25kxCA6km2r/YrC91Gfjmif4WG/SpeGC4ZvZkx25eV7xUKg9KR0eXBlo3Bhead2ZXJzaW9uAg==";
+        let json = decode_base64_msgpack_to_json(
+            ModelType::SimulateTransaction200Response,
+            base64_msgpack,
+        )
+        .unwrap();
+        let response: SimulateTransaction200Response = serde_json::from_str(&json).unwrap();
+        let txn_result = &response.txn_groups[0].txn_results[0].txn_result;
+        assert_eq!(response.last_round, 53);
+        assert_eq!(txn_result.pool_error, "");
+        let crate::Transaction::Payment(payment) = &txn_result.txn.transaction else {
+            panic!("expected a payment transaction");
+        };
+        assert_eq!(payment.amount, 1_000_000);
+        assert_eq!(
+            response.initial_states,
+            Some(SimulateInitialStates::default())
+        );
+        assert_eq!(response.eval_overrides.unwrap().max_log_calls, Some(2048));
+    }
+
+    #[test]
+    fn test_decode_simulate_request() {
+        let signed_txn = crate::SignedTransaction {
+            transaction: crate::test_utils::TransactionMother::simple_payment()
+                .build()
+                .unwrap(),
+            signature: Some([1; crate::ALGORAND_SIGNATURE_BYTE_LENGTH]),
+            auth_address: None,
+            multisignature: None,
+            logic_signature: None,
+            unknown_fields: Default::default(),
+        };
+        let mut request =
+            SimulateRequest::from_signed_transactions(vec![vec![signed_txn.clone()]]).unwrap();
+        request.allow_empty_signatures = Some(true);
+        request.round = Some(100);
+        assert_eq!(
+            request.txn_groups[0].txns[0],
+            BASE64.encode(crate::AlgorandMsgpack::encode(&signed_txn).unwrap())
+        );
+
+        let json = serde_json::to_string(&request).unwrap();
+        let msgpack = encode_json_to_msgpack(ModelType::SimulateRequest, &json).unwrap();
+        let decoded = decode_msgpack_to_json(ModelType::SimulateRequest, &msgpack).unwrap();
+        assert_eq!(
+            serde_json::from_str::<SimulateRequest>(&decoded).unwrap(),
+            request
+        );
+        assert_eq!(
+            encode_json_to_msgpack(ModelType::SimulateRequest, &decoded).unwrap(),
+            msgpack
+        );
+
+        let decoded =
+            decode_simulate_request_to_json(&msgpack, SimulateRequestTransactionFormat::Json)
+                .unwrap();
+        let decoded: Value = serde_json::from_str(&decoded).unwrap();
+        let txn = &decoded["txn-groups"][0]["txns"][0];
+        assert_eq!(decoded["round"], 100);
+        assert_eq!(txn["sig"], BASE64.encode([1; 64]));
+        assert_eq!(txn["txn"]["type"], "pay");
+        assert_eq!(txn["txn"]["amt"], 101_000);
+    }
+
+    #[test]
+    fn test_decode_simulate_request_invalid_transaction() {
+        let msgpack = encode_rmpv(rmpv_map(vec![(
+            "txn-groups",
+            rmpv::Value::Array(vec![rmpv_map(vec![(
+                "txns",
+                rmpv::Value::Array(vec![rmpv_map(vec![("sig", rmpv::Value::from(1))])]),
+            )])]),
+        )]));
+
+        let json =
+            decode_simulate_request_to_json(&msgpack, SimulateRequestTransactionFormat::Base64)
+                .unwrap();
+        assert_eq!(
+            json,
+            format!(
+                r#"{{"txn-groups":[{{"txns":["{}"]}}]}}"#,
+                BASE64.encode(encode_rmpv(rmpv_map(vec![("sig", rmpv::Value::from(1))])))
+            )
+        );
+
+        let error =
+            decode_simulate_request_to_json(&msgpack, SimulateRequestTransactionFormat::Json)
+                .unwrap_err();
+        assert!(matches!(error, AlgoKitMsgPackError::TransactionError(_)));
+
+        // The signed transactions keep their exact bytes, including non-UTF-8 strings
+        let txn = rmpv_map(vec![(
+            "txn",
+            rmpv_map(vec![
+                ("apar", rmpv_map(vec![("an", byte_string(&[0xff, 0x41]))])),
+                ("type", "acfg".into()),
+            ]),
+        )]);
+        let msgpack = encode_rmpv(rmpv_map(vec![(
+            "txn-groups",
+            rmpv::Value::Array(vec![rmpv_map(vec![(
+                "txns",
+                rmpv::Value::Array(vec![txn.clone()]),
+            )])]),
+        )]));
+        let json =
+            decode_simulate_request_to_json(&msgpack, SimulateRequestTransactionFormat::Base64)
+                .unwrap();
+        let decoded: Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(
+            BASE64
+                .decode(decoded["txn-groups"][0]["txns"][0].as_str().unwrap())
+                .unwrap(),
+            encode_rmpv(txn)
+        );
+
+        let error = decode_simulate_request_to_json(
+            &encode_rmpv(rmpv::Value::from(1)),
+            SimulateRequestTransactionFormat::Base64,
+        )
+        .unwrap_err();
+        assert!(matches!(
+            error,
+            AlgoKitMsgPackError::MsgpackDecodingError(_)
+        ));
+    }
+
+    #[test]
+    fn test_simulate_response_models() {
+        let json = json!({
+            "last-round": 10,
+            "version": 2,
+            "txn-groups": [{
+                "app-budget-added": 700,
+                "app-budget-consumed": 12,
+                "txn-results": [{
+                    "app-budget-consumed": 12,
+                    "txn-result": {
+                        "pool-error": "",
+                        "txn": {"txn": {"type": "appl"}},
+                        "logs": ["aGVsbG8="],
+                        "inner-txns": [{"pool-error": "", "txn": {"txn": {"type": "pay"}}}]
+                    },
+                    "exec-trace": {
+                        "approval-program-hash": "AAAA",
+                        "approval-program-trace": [
+                            {"pc": 1},
+                            {
+                                "pc": 4,
+                                "stack-additions": [{"type": 2, "uint": 1}],
+                                "stack-pop-count": 1,
+                                "scratch-changes": [
+                                    {"slot": 0, "new-value": {"type": 1, "bytes": "AQ=="}}
+                                ],
+                                "state-changes": [{
+                                    "operation": "w",
+                                    "app-state-type": "g",
+                                    "key": "Y291bnRlcg==",
+                                    "new-value": {"type": 2, "uint": 2}
+                                }],
+                                "spawned-inners": [0]
+                            }
+                        ],
+                        "inner-trace": [{}]
+                    },
+                    "unnamed-resources-accessed": {
+                        "accounts": ["AAAA"],
+                        "boxes": [{"app": 5, "name": "Ym94"}],
+                        "extra-box-refs": 1,
+                        "app-locals": [{"account": "AAAA", "app": 5}],
+                        "asset-holdings": [{"account": "AAAA", "asset": 6}]
+                    }
+                }]
+            }],
+            "initial-states": {
+                "app-initial-states": [{
+                    "id": 5,
+                    "app-globals": {"kvs": [{"key": "a2V5", "value": {"type": 2, "uint": 1}}]},
+                    "app-locals": [{"account": "AAAA", "kvs": []}]
+                }]
+            }
+        });
+        let response: SimulateTransaction200Response =
+            serde_json::from_value(json.clone()).unwrap();
+        let group = &response.txn_groups[0];
+        let result = &group.txn_results[0];
+        let trace = result.exec_trace.as_ref().unwrap();
+        let unit = &trace.approval_program_trace.as_ref().unwrap()[1];
+        let resources = result.unnamed_resources_accessed.as_ref().unwrap();
+        let initial_states = response.initial_states.as_ref().unwrap();
+
+        assert_eq!(group.app_budget_added, Some(700));
+        assert_eq!(result.app_budget_consumed, Some(12));
+        assert_eq!(result.txn_result.inner_txns.as_ref().unwrap().len(), 1);
+        assert_eq!(unit.pc, 4);
+        assert_eq!(unit.stack_pop_count, Some(1));
+        assert_eq!(unit.spawned_inners, Some(vec![0]));
+        assert_eq!(
+            unit.scratch_changes.as_ref().unwrap()[0].new_value.bytes,
+            Some("AQ==".to_string())
+        );
+        assert_eq!(
+            unit.state_changes.as_ref().unwrap()[0].new_value,
+            Some(AvmValue {
+                value_type: 2,
+                bytes: None,
+                uint: Some(2),
+            })
+        );
+        assert_eq!(trace.inner_trace, Some(vec![Default::default()]));
+        assert_eq!(resources.extra_box_refs, Some(1));
+        assert_eq!(
+            resources.boxes,
+            Some(vec![BoxReference {
+                app: 5,
+                name: "Ym94".to_string(),
+            }])
+        );
+        assert_eq!(resources.asset_holdings.as_ref().unwrap()[0].asset, 6);
+        assert_eq!(resources.app_locals.as_ref().unwrap()[0].app, 5);
+        assert_eq!(
+            initial_states.app_initial_states.as_ref().unwrap()[0]
+                .app_globals
+                .as_ref()
+                .unwrap()
+                .kvs[0]
+                .value
+                .uint,
+            Some(1)
+        );
+        assert_eq!(serde_json::to_value(&response).unwrap(), json);
+    }
+
+    #[test]
+    fn test_decode_non_finite_floats() {
+        let mut buf = Vec::new();
+        rmp_encode::write_map_len(&mut buf, 2).unwrap();
+        rmp_encode::write_str(&mut buf, "nan").unwrap();
+        rmp_encode::write_f64(&mut buf, f64::NAN).unwrap();
+        rmp_encode::write_str(&mut buf, "inf").unwrap();
+        rmp_encode::write_f32(&mut buf, f32::INFINITY).unwrap();
+
+        let json = decode_msgpack_to_json(ModelType::SimulateTransaction200Response, &buf).unwrap();
+        assert_eq!(json, r#"{"inf":null,"nan":null}"#);
+    }
+
+    fn bin(byte: u8, len: usize) -> rmpv::Value {
+        rmpv::Value::Binary(vec![byte; len])
+    }
+
+    /// A msgpack string of bytes which needn't be valid UTF-8.
+    fn byte_string(bytes: &[u8]) -> rmpv::Value {
+        let mut buf = Vec::new();
+        rmp_encode::write_str_len(&mut buf, bytes.len() as u32).unwrap();
+        buf.extend_from_slice(bytes);
+        rmpv::decode::read_value(&mut &buf[..]).unwrap()
+    }
+
+    fn signed_transaction() -> crate::SignedTransaction {
+        crate::SignedTransaction {
+            transaction: crate::test_utils::TransactionMother::simple_payment()
+                .build()
+                .unwrap(),
+            signature: Some([1; crate::ALGORAND_SIGNATURE_BYTE_LENGTH]),
+            auth_address: None,
+            multisignature: None,
+            logic_signature: None,
+            unknown_fields: Default::default(),
+        }
+    }
+
+    fn signed_transaction_value() -> rmpv::Value {
+        let encoded = crate::AlgorandMsgpack::encode(&signed_transaction()).unwrap();
+        rmpv::decode::read_value(&mut &encoded[..]).unwrap()
+    }
+
+    /// The canonical msgpack of each model type, with keys in sorted order.
+    fn lossless_fixture(model_type: ModelType) -> rmpv::Value {
+        let stxn = signed_transaction_value();
+        match model_type {
+            ModelType::SimulateRequest => rmpv_map(vec![
+                ("allow-empty-signatures", true.into()),
+                ("round", 100.into()),
+                (
+                    "txn-groups",
+                    rmpv::Value::Array(vec![rmpv_map(vec![(
+                        "txns",
+                        rmpv::Value::Array(vec![stxn]),
+                    )])]),
+                ),
+            ]),
+            ModelType::SimulateTransaction200Response => rmpv_map(vec![
+                ("last-round", 53.into()),
+                (
+                    "txn-groups",
+                    rmpv::Value::Array(vec![rmpv_map(vec![
+                        (
+                            "txn-results",
+                            rmpv::Value::Array(vec![rmpv_map(vec![
+                                (
+                                    "exec-trace",
+                                    rmpv_map(vec![
+                                        ("approval-program-hash", bin(2, 32)),
+                                        (
+                                            "approval-program-trace",
+                                            rmpv::Value::Array(vec![rmpv_map(vec![
+                                                ("pc", 1.into()),
+                                                (
+                                                    "stack-additions",
+                                                    rmpv::Value::Array(vec![rmpv_map(vec![
+                                                        ("bytes", bin(3, 4)),
+                                                        ("type", 1.into()),
+                                                    ])]),
+                                                ),
+                                            ])]),
+                                        ),
+                                    ]),
+                                ),
+                                (
+                                    "txn-result",
+                                    rmpv_map(vec![
+                                        (
+                                            "global-state-delta",
+                                            rmpv::Value::Array(vec![rmpv_map(vec![
+                                                ("key", "a2V5".into()),
+                                                (
+                                                    "value",
+                                                    rmpv_map(vec![
+                                                        ("action", 1.into()),
+                                                        ("bytes", "dmFsdWU=".into()),
+                                                    ]),
+                                                ),
+                                            ])]),
+                                        ),
+                                        ("logs", rmpv::Value::Array(vec![bin(4, 3)])),
+                                        ("pool-error", "".into()),
+                                        ("txn", stxn),
+                                    ]),
+                                ),
+                            ])]),
+                        ),
+                        (
+                            "unnamed-resources-accessed",
+                            rmpv_map(vec![(
+                                "boxes",
+                                rmpv::Value::Array(vec![rmpv_map(vec![
+                                    ("app", 1.into()),
+                                    ("name", bin(5, 2)),
+                                ])]),
+                            )]),
+                        ),
+                    ])]),
+                ),
+                ("version", 2.into()),
+            ]),
+            ModelType::Account => rmpv_map(vec![
+                ("algo", 1000.into()),
+                (
+                    "apar",
+                    rmpv::Value::Map(vec![(
+                        10.into(),
+                        rmpv_map(vec![
+                            ("am", bin(2, 32)),
+                            ("an", byte_string(b"\xffname")),
+                            ("t", 1.into()),
+                        ]),
+                    )]),
+                ),
+                (
+                    "appp",
+                    rmpv::Value::Map(vec![(
+                        5.into(),
+                        rmpv_map(vec![
+                            ("approv", bin(3, 4)),
+                            (
+                                "gs",
+                                rmpv::Value::Map(vec![(
+                                    byte_string(&[0xff, 0]),
+                                    rmpv_map(vec![("tb", byte_string(&[0xfe])), ("tt", 1.into())]),
+                                )]),
+                            ),
+                        ]),
+                    )]),
+                ),
+                (
+                    "asset",
+                    rmpv::Value::Map(vec![(
+                        10.into(),
+                        rmpv_map(vec![("a", 5.into()), ("f", true.into())]),
+                    )]),
+                ),
+                ("onl", 1.into()),
+                ("spend", bin(4, 32)),
+                ("vote", bin(5, 32)),
+            ]),
+            ModelType::AccountAssetInformation => rmpv_map(vec![
+                (
+                    "asset-holding",
+                    rmpv_map(vec![("a", 5.into()), ("f", true.into())]),
+                ),
+                (
+                    "asset-params",
+                    rmpv_map(vec![
+                        ("c", bin(2, 32)),
+                        ("t", 100.into()),
+                        ("un", byte_string(b"UNIT")),
+                    ]),
+                ),
+                ("round", 10.into()),
+            ]),
+            ModelType::AccountApplicationInformation => rmpv_map(vec![
+                (
+                    "app-local-state",
+                    rmpv_map(vec![
+                        ("hsch", rmpv_map(vec![("nui", 1.into())])),
+                        (
+                            "tkv",
+                            rmpv::Value::Map(vec![(
+                                byte_string(b"key"),
+                                rmpv_map(vec![("tt", 2.into()), ("ui", 7.into())]),
+                            )]),
+                        ),
+                    ]),
+                ),
+                (
+                    "app-params",
+                    rmpv_map(vec![("approv", bin(2, 4)), ("clearp", bin(3, 4))]),
+                ),
+                ("round", 10.into()),
+            ]),
+            ModelType::Block => {
+                let rmpv::Value::Map(mut txn_in_block) = stxn else {
+                    unreachable!("signed transactions are maps");
+                };
+                txn_in_block.insert(
+                    0,
+                    (
+                        "dt".into(),
+                        rmpv_map(vec![
+                            (
+                                "gd",
+                                rmpv::Value::Map(vec![(
+                                    byte_string(&[0xff]),
+                                    rmpv_map(vec![("at", 1.into()), ("bs", byte_string(&[0xfe]))]),
+                                )]),
+                            ),
+                            ("lg", rmpv::Value::Array(vec![byte_string(b"log")])),
+                        ]),
+                    ),
+                );
+                txn_in_block.insert(1, ("hgi".into(), true.into()));
+                rmpv_map(vec![
+                    (
+                        "block",
+                        rmpv_map(vec![
+                            ("gh", bin(2, 32)),
+                            ("prev", bin(3, 32)),
+                            ("rnd", 5.into()),
+                            ("seed", bin(4, 32)),
+                            (
+                                "spt",
+                                rmpv::Value::Map(vec![(
+                                    0.into(),
+                                    rmpv_map(vec![("n", 256.into()), ("v", bin(5, 32))]),
+                                )]),
+                            ),
+                            ("txn", bin(6, 32)),
+                            (
+                                "txns",
+                                rmpv::Value::Array(vec![rmpv::Value::Map(txn_in_block)]),
+                            ),
+                        ]),
+                    ),
+                    (
+                        "cert",
+                        rmpv_map(vec![
+                            (
+                                "prop",
+                                rmpv_map(vec![("dig", bin(7, 32)), ("oprop", bin(8, 32))]),
+                            ),
+                            ("rnd", 5.into()),
+                            (
+                                "vote",
+                                rmpv::Value::Array(vec![rmpv_map(vec![
+                                    ("cred", rmpv_map(vec![("pf", bin(9, 80))])),
+                                    (
+                                        "sig",
+                                        rmpv_map(vec![("p", bin(10, 32)), ("s", bin(11, 64))]),
+                                    ),
+                                    ("snd", bin(12, 32)),
+                                ])]),
+                            ),
+                        ]),
+                    ),
+                ])
+            }
+            ModelType::PendingTransactionResponse => rmpv_map(vec![
+                ("confirmed-round", 5.into()),
+                ("logs", rmpv::Value::Array(vec![bin(2, 3)])),
+                ("pool-error", "".into()),
+                ("txn", stxn),
+            ]),
+            ModelType::LedgerStateDelta => rmpv_map(vec![
+                (
+                    "Accts",
+                    rmpv_map(vec![(
+                        "Accts",
+                        rmpv::Value::Array(vec![rmpv_map(vec![
+                            (
+                                "AccountData",
+                                rmpv_map(vec![(
+                                    "AccountBaseData",
+                                    rmpv_map(vec![
+                                        ("AuthAddr", bin(2, 32)),
+                                        ("MicroAlgos", 1000.into()),
+                                    ]),
+                                )]),
+                            ),
+                            ("Addr", bin(3, 32)),
+                        ])]),
+                    )]),
+                ),
+                ("Hdr", rmpv_map(vec![("gh", bin(4, 32)), ("rnd", 5.into())])),
+                (
+                    "KvMods",
+                    rmpv::Value::Map(vec![(
+                        byte_string(&[0xff]),
+                        rmpv_map(vec![("Data", bin(5, 4))]),
+                    )]),
+                ),
+                (
+                    "Txids",
+                    rmpv::Value::Map(vec![(
+                        bin(6, 32),
+                        rmpv_map(vec![("Intra", 0.into()), ("LastValid", 1000.into())]),
+                    )]),
+                ),
+                (
+                    "Txleases",
+                    rmpv::Value::Map(vec![
+                        (
+                            rmpv_map(vec![("Lease", bin(8, 32)), ("Sender", bin(3, 32))]),
+                            1000.into(),
+                        ),
+                        (
+                            rmpv_map(vec![("Lease", bin(9, 32)), ("Sender", bin(3, 32))]),
+                            1001.into(),
+                        ),
+                    ]),
+                ),
+            ]),
+            ModelType::TransactionParams => rmpv_map(vec![
+                ("consensus-version", "future".into()),
+                ("fee", 0.into()),
+                ("genesis-hash", bin(2, 32)),
+                ("genesis-id", "testnet-v1.0".into()),
+                ("last-round", 5.into()),
+                ("min-fee", 1000.into()),
+            ]),
+            ModelType::Box => rmpv_map(vec![
+                ("name", bin(2, 4)),
+                ("round", 5.into()),
+                ("value", bin(3, 8)),
+            ]),
+            ModelType::StateProof => rmpv_map(vec![
+                (
+                    "Message",
+                    rmpv_map(vec![
+                        ("BlockHeadersCommitment", bin(2, 32)),
+                        ("FirstAttestedRound", 1.into()),
+                        ("LastAttestedRound", 256.into()),
+                        ("LnProvenWeight", 10.into()),
+                        ("VotersCommitment", bin(3, 32)),
+                    ]),
+                ),
+                ("StateProof", bin(4, 64)),
+            ]),
+        }
+    }
+
+    #[test]
+    fn test_lossless_round_trip() {
+        for model_type in ModelType::all() {
+            let msgpack = encode_rmpv(lossless_fixture(model_type));
+            for bytes_format in [BytesFormat::Base64, BytesFormat::Hex, BytesFormat::Array] {
+                let json =
+                    decode_msgpack_to_json_lossless(model_type, &msgpack, bytes_format).unwrap();
+                let encoded =
+                    encode_json_to_msgpack_lossless(model_type, &json, bytes_format).unwrap();
+                assert_eq!(encoded, msgpack, "{} as {:?}", model_type, bytes_format);
+            }
+        }
+
+        // The signed transactions of a simulate request are embedded as in its usual JSON
+        let msgpack = encode_rmpv(lossless_fixture(ModelType::SimulateRequest));
+        assert_eq!(
+            decode_msgpack_to_json_lossless(
+                ModelType::SimulateRequest,
+                &msgpack,
+                BytesFormat::Base64
+            )
+            .unwrap(),
+            decode_msgpack_to_json(ModelType::SimulateRequest, &msgpack).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_lossless_bytes_formats() {
+        let msgpack = encode_rmpv(rmpv_map(vec![
+            ("name", rmpv::Value::Binary(vec![0xab, 0xcd])),
+            ("round", 5.into()),
+        ]));
+        for (bytes_format, expected) in [
+            (BytesFormat::Base64, r#"{"name":"q80=","round":5}"#),
+            (BytesFormat::Hex, r#"{"name":"abcd","round":5}"#),
+            (BytesFormat::Array, r#"{"name":[171,205],"round":5}"#),
+        ] {
+            let json =
+                decode_msgpack_to_json_lossless(ModelType::Box, &msgpack, bytes_format).unwrap();
+            assert_eq!(json, expected);
+        }
+
+        let msgpack = encode_rmpv(rmpv_map(vec![(
+            "app-local-state",
+            rmpv_map(vec![(
+                "tkv",
+                rmpv::Value::Map(vec![(
+                    byte_string(&[0xff]),
+                    rmpv_map(vec![("ui", 1.into())]),
+                )]),
+            )]),
+        )]));
+        let json = decode_msgpack_to_json_lossless(
+            ModelType::AccountApplicationInformation,
+            &msgpack,
+            BytesFormat::Array,
+        )
+        .unwrap();
+        // JSON keys must be strings, so binary keys are base64 encoded with the array format
+        assert_eq!(json, r#"{"app-local-state":{"tkv":{"/w==":{"ui":1}}}}"#);
+    }
+
+    #[test]
+    fn test_lossless_schema_errors() {
+        // Only fields in the schema are binary, so an array of small numbers stays an array
+        let msgpack = encode_json_to_msgpack_lossless(
+            ModelType::Block,
+            r#"{"block":{"txns":[{"txn":{"apfa":[1,2,3],"snd":[1,2,3]}}]}}"#,
+            BytesFormat::Array,
+        )
+        .unwrap();
+        assert_eq!(
+            msgpack,
+            encode_rmpv(rmpv_map(vec![(
+                "block",
+                rmpv_map(vec![(
+                    "txns",
+                    rmpv::Value::Array(vec![rmpv_map(vec![(
+                        "txn",
+                        rmpv_map(vec![
+                            (
+                                "apfa",
+                                rmpv::Value::Array(vec![1.into(), 2.into(), 3.into()])
+                            ),
+                            ("snd", rmpv::Value::Binary(vec![1, 2, 3])),
+                        ]),
+                    )])]),
+                )]),
+            )]))
+        );
+
+        let error = decode_msgpack_to_json_lossless(
+            ModelType::Block,
+            &encode_rmpv(rmpv_map(vec![(
+                "block",
+                rmpv_map(vec![("unknown", bin(1, 4))]),
+            )])),
+            BytesFormat::Base64,
+        )
+        .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Value doesn't match the model schema: unexpected binary value in unknown"
+        );
+
+        let error = decode_msgpack_to_json_lossless(
+            ModelType::Box,
+            &encode_rmpv(rmpv_map(vec![("round", byte_string(&[0xff]))])),
+            BytesFormat::Base64,
+        )
+        .unwrap_err();
+        assert!(matches!(error, AlgoKitMsgPackError::SchemaError(_)));
+
+        let mut trailing = encode_rmpv(lossless_fixture(ModelType::Box));
+        trailing.push(0xc0);
+        let error = decode_msgpack_to_json_lossless(ModelType::Box, &trailing, BytesFormat::Base64)
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            AlgoKitMsgPackError::MsgpackDecodingError(_)
+        ));
+
+        let error = encode_json_to_msgpack_lossless(
+            ModelType::Box,
+            r#"{"round":18446744073709551616}"#,
+            BytesFormat::Base64,
+        )
+        .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Value doesn't match the model schema: unexpected out of range integer value in round"
+        );
+
+        let error = encode_json_to_msgpack_lossless(
+            ModelType::Box,
+            r#"{"name":"not hex"}"#,
+            BytesFormat::Hex,
+        )
+        .unwrap_err();
+        assert!(matches!(error, AlgoKitMsgPackError::SchemaError(_)));
+
+        // Struct keys are sorted by their encoding and must be unique
+        let lease =
+            |lease: u8| format!(r#"{{"key":{{"Lease":[{lease}],"Sender":[3]}},"value":1}}"#);
+        let msgpack = encode_json_to_msgpack_lossless(
+            ModelType::LedgerStateDelta,
+            &format!(r#"{{"Txleases":[{},{}]}}"#, lease(9), lease(8)),
+            BytesFormat::Array,
+        )
+        .unwrap();
+        let json = decode_msgpack_to_json_lossless(
+            ModelType::LedgerStateDelta,
+            &msgpack,
+            BytesFormat::Array,
+        )
+        .unwrap();
+        assert_eq!(
+            json,
+            format!(r#"{{"Txleases":[{},{}]}}"#, lease(8), lease(9))
+        );
+        let error = encode_json_to_msgpack_lossless(
+            ModelType::LedgerStateDelta,
+            &format!(r#"{{"Txleases":[{},{}]}}"#, lease(8), lease(8)),
+            BytesFormat::Array,
+        )
+        .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Value doesn't match the model schema: unexpected duplicate key value in Txleases"
+        );
+    }
+
+    fn block_with_transactions(count: u64) -> rmpv::Value {
+        let transactions = (0..count)
+            .map(|i| {
+                let rmpv::Value::Map(mut txn_in_block) = signed_transaction_value() else {
+                    unreachable!("signed transactions are maps");
+                };
+                txn_in_block.insert(0, ("ca".into(), (i + 1).into()));
+                txn_in_block.insert(1, ("hgi".into(), true.into()));
+                rmpv::Value::Map(txn_in_block)
+            })
+            .collect();
+        rmpv_map(vec![
+            (
+                "block",
+                rmpv_map(vec![
+                    ("gh", bin(2, 32)),
+                    ("rnd", 5.into()),
+                    ("txns", rmpv::Value::Array(transactions)),
+                    ("upgradeyes", true.into()),
+                ]),
+            ),
+            ("cert", rmpv_map(vec![("rnd", 5.into())])),
+        ])
+    }
+
+    fn transaction_in_block(i: u64) -> SignedTransactionInBlock {
+        SignedTransactionInBlock {
+            signed_transaction: signed_transaction(),
+            has_genesis_id: Some(true),
+            has_genesis_hash: None,
+            closing_amount: Some(i + 1),
+            asset_closing_amount: None,
+            sender_rewards: None,
+            receiver_rewards: None,
+            close_rewards: None,
+            eval_delta: None,
+            config_asset: None,
+            application_id: None,
+        }
+    }
+
+    #[test]
+    fn test_block_reader() {
+        let msgpack = encode_rmpv(block_with_transactions(3));
+        let mut block_reader = BlockReader::new(&msgpack[..]).unwrap();
+        assert_eq!(
+            Value::Object(block_reader.header().clone()),
+            json!({"gh": BASE64.encode([2; 32]), "rnd": 5})
+        );
+        assert_eq!(block_reader.remaining_transactions(), 3);
+
+        let transactions = block_reader
+            .transactions()
+            .collect::<Result<Vec<_>>>()
+            .unwrap();
+        assert_eq!(
+            transactions,
+            (0..3).map(transaction_in_block).collect::<Vec<_>>()
+        );
+
+        // The block without its transactions is the same as when decoded in full
+        let block = block_reader.finish().unwrap();
+        let mut expected: Value =
+            serde_json::from_str(&decode_msgpack_to_json(ModelType::Block, &msgpack).unwrap())
+                .unwrap();
+        expected["block"].as_object_mut().unwrap().remove("txns");
+        assert_eq!(serde_json::to_value(&block).unwrap(), expected);
+        assert_eq!(block.block.upgrade_approve, Some(true));
+    }
+
+    #[test]
+    fn test_block_reader_partial_read() {
+        let msgpack = encode_rmpv(block_with_transactions(3));
+        let mut block_reader = BlockReader::new(&msgpack[..]).unwrap();
+        let first = block_reader.transactions().next().unwrap().unwrap();
+        assert_eq!(first, transaction_in_block(0));
+
+        let block = block_reader.finish().unwrap();
+        assert_eq!(block.block.upgrade_approve, Some(true));
+        assert_eq!(block.cert.unwrap().round, 5);
+
+        // A truncated transaction ends the iteration with an error
+        let truncated = &msgpack[..msgpack.len() - 40];
+        let mut block_reader = BlockReader::new(truncated).unwrap();
+        let results: Vec<_> = block_reader.transactions().collect();
+        assert!(results.last().unwrap().is_err());
+        assert!(block_reader.finish().is_err());
+
+        let error = BlockReader::new(&encode_rmpv(rmpv_map(vec![("cert", 1.into())]))[..])
+            .unwrap()
+            .finish()
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            AlgoKitMsgPackError::MsgpackDecodingError(_)
+        ));
+    }
+
+    #[test]
+    fn test_stream_msgpack_to_json() {
+        for model_type in [ModelType::Block, ModelType::SimulateTransaction200Response] {
+            let msgpack = encode_rmpv(lossless_fixture(model_type));
+            let mut json = Vec::new();
+            stream_msgpack_to_json(&msgpack[..], &mut json).unwrap();
+            let streamed: Value = serde_json::from_slice(&json).unwrap();
+            let expected: Value =
+                serde_json::from_str(&decode_msgpack_to_json(model_type, &msgpack).unwrap())
+                    .unwrap();
+            assert_eq!(streamed, expected, "{}", model_type);
+        }
+
+        // Integer keys, floats and negative integers are rendered as when decoding to a value
+        let value = rmpv::Value::Map(vec![
+            (1.into(), rmpv::Value::F64(1.5)),
+            ("neg".into(), (-300).into()),
+            ("nan".into(), rmpv::Value::F32(f32::NAN)),
+            ("ext".into(), rmpv::Value::Ext(1, vec![1, 2, 3])),
+        ]);
+        let mut json = Vec::new();
+        stream_msgpack_to_json(&encode_rmpv(value.clone())[..], &mut json).unwrap();
+        assert_eq!(
+            serde_json::from_slice::<Value>(&json).unwrap(),
+            rmpv_to_json(&value)
+        );
+    }
+
+    #[test]
+    fn test_stream_msgpack_to_json_errors() {
+        let msgpack = encode_rmpv(lossless_fixture(ModelType::Block));
+        let error =
+            stream_msgpack_to_json(&msgpack[..msgpack.len() - 1], std::io::sink()).unwrap_err();
+        assert!(matches!(error, AlgoKitMsgPackError::IoError(_)));
+
+        let nested = vec![0x91; 2000];
+        let error = stream_msgpack_to_json(&nested[..], std::io::sink()).unwrap_err();
+        assert!(matches!(
+            error,
+            AlgoKitMsgPackError::MsgpackDecodingError(_)
+        ));
+
+        let error = stream_msgpack_to_json(&[0xc1][..], std::io::sink()).unwrap_err();
+        assert!(matches!(
+            error,
+            AlgoKitMsgPackError::MsgpackDecodingError(_)
+        ));
     }
 }
@@ -0,0 +1,648 @@
+use crate::address::Address;
+use crate::msgpack::{AlgoKitMsgPackError, ModelHandler, ModelRegistry, ModelType, Result};
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use serde::{Deserialize, Serialize};
+use serde_with::base64::Base64;
+use serde_with::{serde_as, skip_serializing_none, Bytes};
+use std::collections::BTreeMap;
+
+use super::algod::{
+    AccountParticipation, ApplicationLocalState, ApplicationStateSchema, AssetHolding, ModelBox,
+    TealKeyValue, TealValue,
+};
+
+// -----------------------------
+// Account data structures
+// -----------------------------
+
+// algod returns accounts in msgpack using the ledger encoding of the account data, which doesn't
+// include the address of the account, the round, the min balance or the pending rewards. Rather
+// than making these up, accounts are decoded to their own models, which only have what the ledger
+// encoding has, and reuse the generated models where those are complete.
+
+/// The account data algod returns for an account in msgpack.
+///
+/// Unlike the [`Account`](super::Account) model, it doesn't include the address of the account, the
+/// round, the min balance or the pending rewards.
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct AccountData {
+    /// The MicroAlgos in the account, without the pending rewards.
+    #[serde(rename = "amount")]
+    pub amount: u64,
+    #[serde(rename = "apps-local-state")]
+    pub apps_local_state: Option<Vec<ApplicationLocalState>>,
+    #[serde(rename = "total-apps-opted-in")]
+    pub total_apps_opted_in: u64,
+    #[serde(rename = "apps-total-schema")]
+    pub apps_total_schema: Option<ApplicationStateSchema>,
+    #[serde(rename = "apps-total-extra-pages")]
+    pub apps_total_extra_pages: Option<u64>,
+    #[serde(rename = "assets")]
+    pub assets: Option<Vec<AssetHolding>>,
+    #[serde(rename = "total-assets-opted-in")]
+    pub total_assets_opted_in: u64,
+    #[serde(rename = "created-apps")]
+    pub created_apps: Option<Vec<CreatedApplication>>,
+    #[serde(rename = "total-created-apps")]
+    pub total_created_apps: u64,
+    #[serde(rename = "created-assets")]
+    pub created_assets: Option<Vec<CreatedAsset>>,
+    #[serde(rename = "total-created-assets")]
+    pub total_created_assets: u64,
+    #[serde(rename = "total-boxes")]
+    pub total_boxes: Option<u64>,
+    #[serde(rename = "total-box-bytes")]
+    pub total_box_bytes: Option<u64>,
+    #[serde(rename = "participation")]
+    pub participation: Option<AccountParticipation>,
+    #[serde(rename = "incentive-eligible")]
+    pub incentive_eligible: Option<bool>,
+    #[serde(rename = "reward-base")]
+    pub reward_base: Option<u64>,
+    #[serde(rename = "rewards")]
+    pub rewards: u64,
+    #[serde(rename = "status")]
+    pub status: String,
+    #[serde(rename = "auth-addr")]
+    pub auth_addr: Option<String>,
+    #[serde(rename = "last-proposed")]
+    pub last_proposed: Option<u64>,
+    #[serde(rename = "last-heartbeat")]
+    pub last_heartbeat: Option<u64>,
+}
+
+/// An asset created by an account, whose creator is the account.
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct CreatedAsset {
+    #[serde(rename = "index")]
+    pub index: u64,
+    #[serde(rename = "params")]
+    pub params: CreatedAssetParams,
+}
+
+/// The [`AssetParams`](super::AssetParams) of an asset created by an account, without the creator.
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct CreatedAssetParams {
+    #[serde(rename = "clawback")]
+    pub clawback: Option<String>,
+    #[serde(rename = "decimals")]
+    pub decimals: u64,
+    #[serde(rename = "default-frozen")]
+    pub default_frozen: Option<bool>,
+    #[serde(rename = "freeze")]
+    pub freeze: Option<String>,
+    #[serde(rename = "manager")]
+    pub manager: Option<String>,
+    #[serde_as(as = "Option<Base64>")]
+    #[serde(rename = "metadata-hash")]
+    pub metadata_hash: Option<Vec<u8>>,
+    #[serde(rename = "name")]
+    pub name: Option<String>,
+    #[serde_as(as = "Option<Base64>")]
+    #[serde(rename = "name-b64")]
+    pub name_b64: Option<Vec<u8>>,
+    #[serde(rename = "reserve")]
+    pub reserve: Option<String>,
+    #[serde(rename = "total")]
+    pub total: u64,
+    #[serde(rename = "unit-name")]
+    pub unit_name: Option<String>,
+    #[serde_as(as = "Option<Base64>")]
+    #[serde(rename = "unit-name-b64")]
+    pub unit_name_b64: Option<Vec<u8>>,
+    #[serde(rename = "url")]
+    pub url: Option<String>,
+    #[serde_as(as = "Option<Base64>")]
+    #[serde(rename = "url-b64")]
+    pub url_b64: Option<Vec<u8>>,
+}
+
+/// An application created by an account, whose creator is the account.
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct CreatedApplication {
+    #[serde(rename = "id")]
+    pub id: u64,
+    #[serde(rename = "params")]
+    pub params: CreatedApplicationParams,
+}
+
+/// The [`ApplicationParams`](super::ApplicationParams) of an application created by an account,
+/// without the creator.
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct CreatedApplicationParams {
+    #[serde_as(as = "Base64")]
+    #[serde(rename = "approval-program")]
+    pub approval_program: Vec<u8>,
+    #[serde_as(as = "Base64")]
+    #[serde(rename = "clear-state-program")]
+    pub clear_state_program: Vec<u8>,
+    #[serde(rename = "extra-program-pages")]
+    pub extra_program_pages: Option<u64>,
+    #[serde(rename = "local-state-schema")]
+    pub local_state_schema: Option<ApplicationStateSchema>,
+    #[serde(rename = "global-state-schema")]
+    pub global_state_schema: Option<ApplicationStateSchema>,
+    #[serde(rename = "global-state")]
+    pub global_state: Option<Vec<TealKeyValue>>,
+    #[serde(rename = "version")]
+    pub version: Option<u64>,
+}
+
+/// The asset holding and created asset params (if either exist) algod returns in msgpack for an
+/// account and asset ID.
+///
+/// Unlike the [`AccountAssetInformation200Response`](super::AccountAssetInformation200Response)
+/// model, the holding doesn't include the asset ID and the params don't include the creator, which
+/// are the asset ID and the account requested.
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct AccountAssetData {
+    #[serde(rename = "round")]
+    pub round: u64,
+    #[serde(rename = "asset-holding")]
+    pub asset_holding: Option<AccountAssetHolding>,
+    #[serde(rename = "created-asset")]
+    pub created_asset: Option<CreatedAssetParams>,
+}
+
+/// The [`AssetHolding`](super::AssetHolding) of an account for the asset requested, without the
+/// asset ID.
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct AccountAssetHolding {
+    #[serde(rename = "amount")]
+    pub amount: u64,
+    #[serde(rename = "is-frozen")]
+    pub is_frozen: bool,
+}
+
+/// The application local state and created application params (if either exist) algod returns
+/// in msgpack for an account and application ID.
+///
+/// Unlike the
+/// [`AccountApplicationInformation200Response`](super::AccountApplicationInformation200Response)
+/// model, the local state doesn't include the application ID and the params don't include the
+/// creator, which are the application ID and the account requested.
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct AccountApplicationData {
+    #[serde(rename = "round")]
+    pub round: u64,
+    #[serde(rename = "app-local-state")]
+    pub app_local_state: Option<AccountApplicationLocalState>,
+    #[serde(rename = "created-app")]
+    pub created_app: Option<CreatedApplicationParams>,
+}
+
+/// The [`ApplicationLocalState`](super::ApplicationLocalState) of an account for the application
+/// requested, without the application ID.
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct AccountApplicationLocalState {
+    #[serde(rename = "schema")]
+    pub schema: ApplicationStateSchema,
+    #[serde(rename = "key-value")]
+    pub key_value: Option<Vec<TealKeyValue>>,
+}
+
+// --------------------------------------
+// Raw msgpack encoding of account data
+// --------------------------------------
+
+// The ledger encoding of the account data has short keys, integer-keyed maps of assets and
+// applications and binary addresses.
+
+#[serde_as]
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct RawAccountData {
+    #[serde(rename = "onl")]
+    status: u8,
+    #[serde(rename = "algo")]
+    amount: u64,
+    #[serde(rename = "ebase")]
+    reward_base: u64,
+    #[serde(rename = "ern")]
+    rewards: u64,
+    #[serde(rename = "vote")]
+    #[serde_as(as = "Option<Bytes>")]
+    vote_key: Option<Vec<u8>>,
+    #[serde(rename = "sel")]
+    #[serde_as(as = "Option<Bytes>")]
+    selection_key: Option<Vec<u8>>,
+    #[serde(rename = "stprf")]
+    #[serde_as(as = "Option<Bytes>")]
+    state_proof_key: Option<Vec<u8>>,
+    #[serde(rename = "voteFst")]
+    vote_first: u64,
+    #[serde(rename = "voteLst")]
+    vote_last: u64,
+    #[serde(rename = "voteKD")]
+    vote_key_dilution: u64,
+    #[serde(rename = "apar")]
+    asset_params: BTreeMap<u64, RawAssetParams>,
+    #[serde(rename = "asset")]
+    assets: BTreeMap<u64, RawAssetHolding>,
+    #[serde(rename = "spend")]
+    auth_address: Option<Address>,
+    #[serde(rename = "appl")]
+    app_local_states: BTreeMap<u64, RawAppLocalState>,
+    #[serde(rename = "appp")]
+    app_params: BTreeMap<u64, RawAppParams>,
+    #[serde(rename = "tsch")]
+    total_app_schema: Option<RawStateSchema>,
+    #[serde(rename = "teap")]
+    total_extra_app_pages: u64,
+    #[serde(rename = "tbx")]
+    total_boxes: u64,
+    #[serde(rename = "tbxb")]
+    total_box_bytes: u64,
+    #[serde(rename = "ie")]
+    incentive_eligible: bool,
+    #[serde(rename = "lpr")]
+    last_proposed: u64,
+    #[serde(rename = "lhb")]
+    last_heartbeat: u64,
+}
+
+#[serde_as]
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct RawAssetParams {
+    #[serde(rename = "t")]
+    total: u64,
+    #[serde(rename = "dc")]
+    decimals: u64,
+    #[serde(rename = "df")]
+    default_frozen: bool,
+    #[serde(rename = "un")]
+    #[serde_as(as = "Bytes")]
+    unit_name: Vec<u8>,
+    #[serde(rename = "an")]
+    #[serde_as(as = "Bytes")]
+    asset_name: Vec<u8>,
+    #[serde(rename = "au")]
+    #[serde_as(as = "Bytes")]
+    url: Vec<u8>,
+    #[serde(rename = "am")]
+    #[serde_as(as = "Bytes")]
+    metadata_hash: Vec<u8>,
+    #[serde(rename = "m")]
+    manager: Option<Address>,
+    #[serde(rename = "r")]
+    reserve: Option<Address>,
+    #[serde(rename = "f")]
+    freeze: Option<Address>,
+    #[serde(rename = "c")]
+    clawback: Option<Address>,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct RawAssetHolding {
+    #[serde(rename = "a")]
+    amount: u64,
+    #[serde(rename = "f")]
+    frozen: bool,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct RawStateSchema {
+    #[serde(rename = "nui")]
+    num_uint: u64,
+    #[serde(rename = "nbs")]
+    num_byte_slice: u64,
+}
+
+#[serde_as]
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct RawTealValue {
+    #[serde(rename = "tt")]
+    value_type: u64,
+    #[serde(rename = "tb")]
+    #[serde_as(as = "Bytes")]
+    bytes: Vec<u8>,
+    #[serde(rename = "ui")]
+    uint: u64,
+}
+
+#[serde_as]
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct RawAppLocalState {
+    #[serde(rename = "hsch")]
+    schema: RawStateSchema,
+    #[serde(rename = "tkv")]
+    #[serde_as(as = "BTreeMap<Bytes, _>")]
+    key_values: BTreeMap<Vec<u8>, RawTealValue>,
+}
+
+#[serde_as]
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct RawAppParams {
+    #[serde(rename = "approv")]
+    #[serde_as(as = "Bytes")]
+    approval_program: Vec<u8>,
+    #[serde(rename = "clearp")]
+    #[serde_as(as = "Bytes")]
+    clear_state_program: Vec<u8>,
+    #[serde(rename = "gs")]
+    #[serde_as(as = "BTreeMap<Bytes, _>")]
+    global_state: BTreeMap<Vec<u8>, RawTealValue>,
+    #[serde(rename = "lsch")]
+    local_state_schema: Option<RawStateSchema>,
+    #[serde(rename = "gsch")]
+    global_state_schema: Option<RawStateSchema>,
+    #[serde(rename = "epp")]
+    extra_program_pages: u64,
+    #[serde(rename = "v")]
+    version: u64,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct RawAccountAssetModel {
+    #[serde(rename = "round")]
+    round: u64,
+    #[serde(rename = "asset-params")]
+    asset_params: Option<RawAssetParams>,
+    #[serde(rename = "asset-holding")]
+    asset_holding: Option<RawAssetHolding>,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct RawAccountApplicationModel {
+    #[serde(rename = "round")]
+    round: u64,
+    #[serde(rename = "app-local-state")]
+    app_local_state: Option<RawAppLocalState>,
+    #[serde(rename = "app-params")]
+    app_params: Option<RawAppParams>,
+}
+
+fn non_zero(value: u64) -> Option<u64> {
+    (value != 0).then_some(value)
+}
+
+fn non_empty<T>(items: Vec<T>) -> Option<Vec<T>> {
+    (!items.is_empty()).then_some(items)
+}
+
+fn bytes_opt(bytes: &[u8]) -> Option<Vec<u8>> {
+    (!bytes.is_empty()).then(|| bytes.to_vec())
+}
+
+fn utf8_opt(bytes: &[u8]) -> Option<String> {
+    std::str::from_utf8(bytes)
+        .ok()
+        .filter(|s| !s.is_empty())
+        .map(String::from)
+}
+
+impl From<&RawStateSchema> for ApplicationStateSchema {
+    fn from(schema: &RawStateSchema) -> Self {
+        ApplicationStateSchema {
+            num_uint: schema.num_uint,
+            num_byte_slice: schema.num_byte_slice,
+        }
+    }
+}
+
+fn teal_key_values(store: &BTreeMap<Vec<u8>, RawTealValue>) -> Option<Vec<TealKeyValue>> {
+    non_empty(
+        store
+            .iter()
+            .map(|(key, value)| TealKeyValue {
+                key: BASE64.encode(key),
+                value: TealValue {
+                    r#type: value.value_type,
+                    bytes: BASE64.encode(&value.bytes),
+                    uint: value.uint,
+                },
+            })
+            .collect(),
+    )
+}
+
+impl RawAssetParams {
+    fn to_model(&self) -> CreatedAssetParams {
+        CreatedAssetParams {
+            clawback: self.clawback.as_ref().map(Address::to_string),
+            decimals: self.decimals,
+            default_frozen: self.default_frozen.then_some(true),
+            freeze: self.freeze.as_ref().map(Address::to_string),
+            manager: self.manager.as_ref().map(Address::to_string),
+            metadata_hash: bytes_opt(&self.metadata_hash),
+            name: utf8_opt(&self.asset_name),
+            name_b64: bytes_opt(&self.asset_name),
+            reserve: self.reserve.as_ref().map(Address::to_string),
+            total: self.total,
+            unit_name: utf8_opt(&self.unit_name),
+            unit_name_b64: bytes_opt(&self.unit_name),
+            url: utf8_opt(&self.url),
+            url_b64: bytes_opt(&self.url),
+        }
+    }
+}
+
+impl RawAssetHolding {
+    fn to_model(&self) -> AccountAssetHolding {
+        AccountAssetHolding {
+            amount: self.amount,
+            is_frozen: self.frozen,
+        }
+    }
+}
+
+impl RawAppLocalState {
+    fn to_model(&self) -> AccountApplicationLocalState {
+        AccountApplicationLocalState {
+            schema: (&self.schema).into(),
+            key_value: teal_key_values(&self.key_values),
+        }
+    }
+}
+
+impl RawAppParams {
+    fn to_model(&self) -> CreatedApplicationParams {
+        CreatedApplicationParams {
+            approval_program: self.approval_program.clone(),
+            clear_state_program: self.clear_state_program.clone(),
+            extra_program_pages: non_zero(self.extra_program_pages),
+            local_state_schema: self.local_state_schema.as_ref().map(Into::into),
+            global_state_schema: self.global_state_schema.as_ref().map(Into::into),
+            global_state: teal_key_values(&self.global_state),
+            version: non_zero(self.version),
+        }
+    }
+}
+
+impl RawAccountData {
+    fn to_model(&self) -> AccountData {
+        let status = match self.status {
+            0 => "Offline",
+            1 => "Online",
+            2 => "NotParticipating",
+            _ => "Unknown",
+        };
+        let participation = self.vote_key.as_ref().map(|vote_key| AccountParticipation {
+            selection_participation_key: self.selection_key.clone().unwrap_or_default(),
+            vote_first_valid: self.vote_first,
+            vote_key_dilution: self.vote_key_dilution,
+            vote_last_valid: self.vote_last,
+            vote_participation_key: vote_key.clone(),
+            state_proof_key: self.state_proof_key.as_deref().and_then(bytes_opt),
+        });
+        AccountData {
+            amount: self.amount,
+            apps_local_state: non_empty(
+                self.app_local_states
+                    .iter()
+                    .map(|(id, state)| {
+                        let state = state.to_model();
+                        ApplicationLocalState {
+                            id: *id,
+                            schema: state.schema,
+                            key_value: state.key_value,
+                        }
+                    })
+                    .collect(),
+            ),
+            total_apps_opted_in: self.app_local_states.len() as u64,
+            apps_total_schema: self.total_app_schema.as_ref().map(Into::into),
+            apps_total_extra_pages: non_zero(self.total_extra_app_pages),
+            assets: non_empty(
+                self.assets
+                    .iter()
+                    .map(|(id, holding)| AssetHolding {
+                        amount: holding.amount,
+                        asset_id: *id,
+                        is_frozen: holding.frozen,
+                    })
+                    .collect(),
+            ),
+            total_assets_opted_in: self.assets.len() as u64,
+            created_apps: non_empty(
+                self.app_params
+                    .iter()
+                    .map(|(id, params)| CreatedApplication {
+                        id: *id,
+                        params: params.to_model(),
+                    })
+                    .collect(),
+            ),
+            total_created_apps: self.app_params.len() as u64,
+            created_assets: non_empty(
+                self.asset_params
+                    .iter()
+                    .map(|(index, params)| CreatedAsset {
+                        index: *index,
+                        params: params.to_model(),
+                    })
+                    .collect(),
+            ),
+            total_created_assets: self.asset_params.len() as u64,
+            total_boxes: non_zero(self.total_boxes),
+            total_box_bytes: non_zero(self.total_box_bytes),
+            participation,
+            incentive_eligible: self.incentive_eligible.then_some(true),
+            reward_base: non_zero(self.reward_base),
+            rewards: self.rewards,
+            status: status.to_string(),
+            auth_addr: self.auth_address.as_ref().map(Address::to_string),
+            last_proposed: non_zero(self.last_proposed),
+            last_heartbeat: non_zero(self.last_heartbeat),
+        }
+    }
+}
+
+// --------------------------------------
+// Custom decoding for accounts
+// --------------------------------------
+
+/// Decodes the raw msgpack encoding of a model with `decode` and returns the JSON of the model.
+/// Encoding isn't supported, as algod only returns these models.
+struct RawModelHandler<T: Serialize> {
+    model_type: ModelType,
+    decode: fn(&[u8]) -> Result<T>,
+}
+
+impl<T: Serialize> ModelHandler for RawModelHandler<T> {
+    fn encode_json_to_msgpack(&self, _json_str: &str) -> Result<Vec<u8>> {
+        Err(AlgoKitMsgPackError::MsgpackWriteError(format!(
+            "{} encoding is not supported",
+            self.model_type
+        )))
+    }
+
+    fn decode_msgpack_to_json(&self, msgpack_bytes: &[u8]) -> Result<String> {
+        Ok(serde_json::to_string(&(self.decode)(msgpack_bytes)?)?)
+    }
+}
+
+/// Decodes the account data of an account from the msgpack returned by algod.
+pub fn decode_account(msgpack_bytes: &[u8]) -> Result<AccountData> {
+    let raw: RawAccountData = rmp_serde::from_slice(msgpack_bytes)?;
+    Ok(raw.to_model())
+}
+
+/// Decodes the asset holding and asset params of an account from the msgpack returned by algod.
+pub fn decode_account_asset_information(msgpack_bytes: &[u8]) -> Result<AccountAssetData> {
+    let raw: RawAccountAssetModel = rmp_serde::from_slice(msgpack_bytes)?;
+    Ok(AccountAssetData {
+        round: raw.round,
+        asset_holding: raw.asset_holding.as_ref().map(RawAssetHolding::to_model),
+        created_asset: raw.asset_params.as_ref().map(RawAssetParams::to_model),
+    })
+}
+
+/// Decodes the local state and application params of an account from the msgpack returned by
+/// algod.
+pub fn decode_account_application_information(
+    msgpack_bytes: &[u8],
+) -> Result<AccountApplicationData> {
+    let raw: RawAccountApplicationModel = rmp_serde::from_slice(msgpack_bytes)?;
+    Ok(AccountApplicationData {
+        round: raw.round,
+        app_local_state: raw.app_local_state.as_ref().map(RawAppLocalState::to_model),
+        created_app: raw.app_params.as_ref().map(RawAppParams::to_model),
+    })
+}
+
+// -----------------------------
+// Registration helper
+// -----------------------------
+
+pub fn register_account_models(registry: &mut ModelRegistry) {
+    registry.registry.insert(
+        ModelType::Account,
+        Box::new(RawModelHandler {
+            model_type: ModelType::Account,
+            decode: decode_account,
+        }),
+    );
+    registry.registry.insert(
+        ModelType::AccountAssetInformation,
+        Box::new(RawModelHandler {
+            model_type: ModelType::AccountAssetInformation,
+            decode: decode_account_asset_information,
+        }),
+    );
+    registry.registry.insert(
+        ModelType::AccountApplicationInformation,
+        Box::new(RawModelHandler {
+            model_type: ModelType::AccountApplicationInformation,
+            decode: decode_account_application_information,
+        }),
+    );
+    registry.register_response::<ModelBox>(ModelType::Box);
+}
@@ -0,0 +1,32 @@
+/*
+ * Algod REST API.
+ *
+ * API endpoint for algod operations.
+ *
+ * The version of the OpenAPI document: 0.0.1
+ * Contact: contact@algorand.com
+ * Generated by: https://openapi-generator.tech
+ */
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// ApplicationStateSchema : Specifies maximums on the number of each type that may be stored.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ApplicationStateSchema {
+    /// \\[nui\\] num of uints.
+    #[serde(rename = "num-uint")]
+    pub num_uint: u64,
+    /// \\[nbs\\] num of byte slices.
+    #[serde(rename = "num-byte-slice")]
+    pub num_byte_slice: u64,
+}
+
+impl ApplicationStateSchema {
+    /// Specifies maximums on the number of each type that may be stored.
+    pub fn new(num_uint: u64, num_byte_slice: u64) -> ApplicationStateSchema {
+        ApplicationStateSchema {
+            num_uint,
+            num_byte_slice,
+        }
+    }
+}
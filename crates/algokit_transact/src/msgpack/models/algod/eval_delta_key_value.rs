@@ -0,0 +1,27 @@
+/*
+ * Algod REST API.
+ *
+ * API endpoint for algod operations.
+ *
+ * The version of the OpenAPI document: 0.0.1
+ * Contact: contact@algorand.com
+ * Generated by: https://openapi-generator.tech
+ */
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// EvalDeltaKeyValue : Key-value pairs for StateDelta.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct EvalDeltaKeyValue {
+    #[serde(rename = "key")]
+    pub key: String,
+    #[serde(rename = "value")]
+    pub value: models::EvalDelta,
+}
+
+impl EvalDeltaKeyValue {
+    /// Key-value pairs for StateDelta.
+    pub fn new(key: String, value: models::EvalDelta) -> EvalDeltaKeyValue {
+        EvalDeltaKeyValue { key, value }
+    }
+}
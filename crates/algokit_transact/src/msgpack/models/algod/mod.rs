@@ -0,0 +1,42 @@
+pub mod account;
+pub use self::account::Account;
+pub mod account_application_information200_response;
+pub use self::account_application_information200_response::AccountApplicationInformation200Response;
+pub mod account_asset_information200_response;
+pub use self::account_asset_information200_response::AccountAssetInformation200Response;
+pub mod account_participation;
+pub use self::account_participation::AccountParticipation;
+pub mod account_state_delta;
+pub use self::account_state_delta::AccountStateDelta;
+pub mod application;
+pub use self::application::Application;
+pub mod application_local_state;
+pub use self::application_local_state::ApplicationLocalState;
+pub mod application_params;
+pub use self::application_params::ApplicationParams;
+pub mod application_state_schema;
+pub use self::application_state_schema::ApplicationStateSchema;
+pub mod asset;
+pub use self::asset::Asset;
+pub mod asset_holding;
+pub use self::asset_holding::AssetHolding;
+pub mod asset_params;
+pub use self::asset_params::AssetParams;
+pub mod model_box;
+pub use self::model_box::ModelBox;
+pub mod eval_delta;
+pub use self::eval_delta::EvalDelta;
+pub mod eval_delta_key_value;
+pub use self::eval_delta_key_value::EvalDeltaKeyValue;
+pub mod pending_transaction_response;
+pub use self::pending_transaction_response::PendingTransactionResponse;
+pub mod state_proof;
+pub use self::state_proof::StateProof;
+pub mod state_proof_message;
+pub use self::state_proof_message::StateProofMessage;
+pub mod teal_key_value;
+pub use self::teal_key_value::TealKeyValue;
+pub mod teal_value;
+pub use self::teal_value::TealValue;
+pub mod transaction_params200_response;
+pub use self::transaction_params200_response::TransactionParams200Response;
@@ -0,0 +1,37 @@
+/*
+ * Algod REST API.
+ *
+ * API endpoint for algod operations.
+ *
+ * The version of the OpenAPI document: 0.0.1
+ * Contact: contact@algorand.com
+ * Generated by: https://openapi-generator.tech
+ */
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+use serde_with::serde_as;
+
+/// ModelBox : Box name and its content.
+#[serde_as]
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ModelBox {
+    /// The round for which this information is relevant
+    #[serde(rename = "round")]
+    pub round: u64,
+    /// \\[name\\] box name, base64 encoded
+    #[serde_as(as = "serde_with::base64::Base64")]
+    #[serde(rename = "name")]
+    pub name: Vec<u8>,
+    /// \\[value\\] box value, base64 encoded.
+    #[serde_as(as = "serde_with::base64::Base64")]
+    #[serde(rename = "value")]
+    pub value: Vec<u8>,
+}
+
+impl ModelBox {
+    /// Box name and its content.
+    pub fn new(round: u64, name: Vec<u8>, value: Vec<u8>) -> ModelBox {
+        ModelBox { round, name, value }
+    }
+}
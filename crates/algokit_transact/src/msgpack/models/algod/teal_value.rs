@@ -0,0 +1,36 @@
+/*
+ * Algod REST API.
+ *
+ * API endpoint for algod operations.
+ *
+ * The version of the OpenAPI document: 0.0.1
+ * Contact: contact@algorand.com
+ * Generated by: https://openapi-generator.tech
+ */
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// TealValue : Represents a TEAL value.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct TealValue {
+    /// \\[tt\\] value type. Value `1` refers to **bytes**, value `2` refers to **uint**
+    #[serde(rename = "type")]
+    pub r#type: u64,
+    /// \\[tb\\] bytes value.
+    #[serde(rename = "bytes")]
+    pub bytes: String,
+    /// \\[ui\\] uint value.
+    #[serde(rename = "uint")]
+    pub uint: u64,
+}
+
+impl TealValue {
+    /// Represents a TEAL value.
+    pub fn new(r#type: u64, bytes: String, uint: u64) -> TealValue {
+        TealValue {
+            r#type,
+            bytes,
+            uint,
+        }
+    }
+}
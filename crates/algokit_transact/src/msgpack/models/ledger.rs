@@ -0,0 +1,626 @@
+use crate::msgpack::{ModelRegistry, ModelType};
+use crate::SignedTransaction;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use std::collections::BTreeMap;
+
+use super::algod::StateProof;
+use super::transaction::signed_transaction_json;
+
+// -----------------------------
+// Block structures
+// -----------------------------
+
+/// A block and, in msgpack, its certificate.
+///
+/// Binary values, such as hashes and addresses, are base64 encoded.
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Block {
+    #[serde(rename = "block")]
+    pub block: BlockHeader,
+    #[serde(rename = "cert")]
+    pub cert: Option<Certificate>,
+}
+
+/// The header of a block, along with its transactions.
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct BlockHeader {
+    #[serde(rename = "rnd", default)]
+    pub round: u64,
+    #[serde(rename = "prev")]
+    pub previous_block_hash: Option<String>,
+    #[serde(rename = "seed")]
+    pub seed: Option<String>,
+    /// The SHA-512/256 commitment to the transactions of the block.
+    #[serde(rename = "txn")]
+    pub transactions_root: Option<String>,
+    #[serde(rename = "txn256")]
+    pub transactions_root_sha256: Option<String>,
+    #[serde(rename = "txn512")]
+    pub transactions_root_sha512: Option<String>,
+    #[serde(rename = "ts")]
+    pub timestamp: Option<i64>,
+    #[serde(rename = "gen")]
+    pub genesis_id: Option<String>,
+    #[serde(rename = "gh")]
+    pub genesis_hash: Option<String>,
+    #[serde(rename = "prp")]
+    pub proposer: Option<String>,
+    #[serde(rename = "fc")]
+    pub fees_collected: Option<u64>,
+    #[serde(rename = "bi")]
+    pub bonus: Option<u64>,
+    #[serde(rename = "pp")]
+    pub proposer_payout: Option<u64>,
+    #[serde(rename = "fees")]
+    pub fee_sink: Option<String>,
+    #[serde(rename = "rwd")]
+    pub rewards_pool: Option<String>,
+    #[serde(rename = "earn")]
+    pub rewards_level: Option<u64>,
+    #[serde(rename = "rate")]
+    pub rewards_rate: Option<u64>,
+    #[serde(rename = "frac")]
+    pub rewards_residue: Option<u64>,
+    #[serde(rename = "rwcalr")]
+    pub rewards_recalculation_round: Option<u64>,
+    #[serde(rename = "proto")]
+    pub current_protocol: Option<String>,
+    #[serde(rename = "nextproto")]
+    pub next_protocol: Option<String>,
+    #[serde(rename = "nextyes")]
+    pub next_protocol_approvals: Option<u64>,
+    #[serde(rename = "nextbefore")]
+    pub next_protocol_vote_before: Option<u64>,
+    #[serde(rename = "nextswitch")]
+    pub next_protocol_switch_on: Option<u64>,
+    #[serde(rename = "upgradeprop")]
+    pub upgrade_propose: Option<String>,
+    #[serde(rename = "upgradedelay")]
+    pub upgrade_delay: Option<u64>,
+    #[serde(rename = "upgradeyes")]
+    pub upgrade_approve: Option<bool>,
+    #[serde(rename = "tc")]
+    pub txn_counter: Option<u64>,
+    /// The state proof tracking data, keyed by the state proof type.
+    #[serde(rename = "spt")]
+    pub state_proof_tracking: Option<BTreeMap<String, StateProofTrackingData>>,
+    #[serde(rename = "partupdrmv")]
+    pub expired_participation_accounts: Option<Vec<String>>,
+    #[serde(rename = "partupdabs")]
+    pub absent_participation_accounts: Option<Vec<String>>,
+    #[serde(rename = "txns")]
+    pub transactions: Option<Vec<SignedTransactionInBlock>>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct StateProofTrackingData {
+    #[serde(rename = "v")]
+    pub voters_commitment: Option<String>,
+    #[serde(rename = "t")]
+    pub online_total_weight: Option<u64>,
+    #[serde(rename = "n")]
+    pub next_round: Option<u64>,
+}
+
+/// A signed transaction in a block, along with the results of applying it.
+///
+/// Inner transactions have the same form, without the genesis flags.
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SignedTransactionInBlock {
+    /// The signed transaction, whose genesis ID and hash are omitted when `has_genesis_id` and
+    /// `has_genesis_hash` are set, as they're those of the block.
+    #[serde(flatten, with = "signed_transaction_json")]
+    pub signed_transaction: SignedTransaction,
+    #[serde(rename = "hgi")]
+    pub has_genesis_id: Option<bool>,
+    #[serde(rename = "hgh")]
+    pub has_genesis_hash: Option<bool>,
+    #[serde(rename = "ca")]
+    pub closing_amount: Option<u64>,
+    #[serde(rename = "aca")]
+    pub asset_closing_amount: Option<u64>,
+    #[serde(rename = "rs")]
+    pub sender_rewards: Option<u64>,
+    #[serde(rename = "rr")]
+    pub receiver_rewards: Option<u64>,
+    #[serde(rename = "rc")]
+    pub close_rewards: Option<u64>,
+    #[serde(rename = "dt")]
+    pub eval_delta: Option<BlockEvalDelta>,
+    #[serde(rename = "caid")]
+    pub config_asset: Option<u64>,
+    #[serde(rename = "apid")]
+    pub application_id: Option<u64>,
+}
+
+/// The changes to application state and the logs and inner transactions of an application call.
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct BlockEvalDelta {
+    #[serde(rename = "gd")]
+    pub global_delta: Option<BTreeMap<String, BlockValueDelta>>,
+    /// The local state changes, keyed by the index of the account in the shared accounts.
+    #[serde(rename = "ld")]
+    pub local_deltas: Option<BTreeMap<String, BTreeMap<String, BlockValueDelta>>>,
+    #[serde(rename = "sa")]
+    pub shared_accounts: Option<Vec<String>>,
+    #[serde(rename = "lg")]
+    pub logs: Option<Vec<String>>,
+    #[serde(rename = "itx")]
+    pub inner_txns: Option<Vec<SignedTransactionInBlock>>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct BlockValueDelta {
+    /// The delta action, 1 to set bytes, 2 to set a uint and 3 to delete the key.
+    #[serde(rename = "at")]
+    pub action: u64,
+    #[serde(rename = "bs")]
+    pub bytes: Option<String>,
+    #[serde(rename = "ui")]
+    pub uint: Option<u64>,
+}
+
+/// The agreement certificate of a block, i.e. the votes which confirmed it.
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct Certificate {
+    #[serde(rename = "rnd", default)]
+    pub round: u64,
+    #[serde(rename = "per")]
+    pub period: Option<u64>,
+    #[serde(rename = "step")]
+    pub step: Option<u64>,
+    #[serde(rename = "prop", default)]
+    pub proposal: ProposalValue,
+    #[serde(rename = "vote")]
+    pub votes: Option<Vec<VoteAuthenticator>>,
+    #[serde(rename = "eqv")]
+    pub equivocation_votes: Option<Vec<EquivocationVoteAuthenticator>>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct ProposalValue {
+    #[serde(rename = "oper")]
+    pub original_period: Option<u64>,
+    #[serde(rename = "oprop")]
+    pub original_proposer: Option<String>,
+    #[serde(rename = "dig")]
+    pub block_digest: Option<String>,
+    #[serde(rename = "encdig")]
+    pub encoding_digest: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct VoteAuthenticator {
+    #[serde(rename = "snd")]
+    pub sender: String,
+    #[serde(rename = "cred", default)]
+    pub credential: Credential,
+    #[serde(rename = "sig", default)]
+    pub signature: OneTimeSignature,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct EquivocationVoteAuthenticator {
+    #[serde(rename = "snd")]
+    pub sender: String,
+    #[serde(rename = "cred", default)]
+    pub credential: Credential,
+    /// The two conflicting proposals which were voted for.
+    #[serde(rename = "props")]
+    pub proposals: Option<Vec<ProposalValue>>,
+    #[serde(rename = "sigs")]
+    pub signatures: Option<Vec<OneTimeSignature>>,
+}
+
+/// The VRF proof of a vote's sortition.
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct Credential {
+    #[serde(rename = "pf")]
+    pub proof: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct OneTimeSignature {
+    #[serde(rename = "s")]
+    pub signature: Option<String>,
+    #[serde(rename = "p")]
+    pub public_key: Option<String>,
+    #[serde(rename = "ps")]
+    pub public_key_signature: Option<String>,
+    #[serde(rename = "p2")]
+    pub public_key_2: Option<String>,
+    #[serde(rename = "p1s")]
+    pub public_key_1_signature: Option<String>,
+    #[serde(rename = "p2s")]
+    pub public_key_2_signature: Option<String>,
+}
+
+// -----------------------------
+// Ledger state delta structures
+// -----------------------------
+
+// Most of the ledger state delta has no codec tags, so is keyed by the Go field names, while the
+// block header and the account's asset and application data use their ledger encoding.
+
+/// The changes to the ledger made by a round or transaction group.
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct LedgerStateDelta {
+    #[serde(rename = "Accts")]
+    pub accounts: Option<AccountDeltas>,
+    /// The changes to boxes, keyed by the box key (the application ID and box name).
+    #[serde(rename = "KvMods")]
+    pub kv_mods: Option<BTreeMap<String, KvValueDelta>>,
+    /// The transactions which were added, keyed by their base64 encoded ID.
+    #[serde(rename = "Txids")]
+    pub txids: Option<BTreeMap<String, IncludedTransactions>>,
+    /// The leases which were acquired and the round they expire.
+    #[serde(rename = "Txleases", default, deserialize_with = "struct_key_entries")]
+    pub txleases: Option<Vec<TxleaseEntry>>,
+    /// The assets and applications which were created or deleted, keyed by their ID.
+    #[serde(rename = "Creatables")]
+    pub creatables: Option<BTreeMap<String, ModifiedCreatable>>,
+    #[serde(rename = "Hdr")]
+    pub header: Option<BlockHeader>,
+    #[serde(rename = "StateProofNext")]
+    pub state_proof_next: Option<u64>,
+    #[serde(rename = "PrevTimestamp")]
+    pub prev_timestamp: Option<i64>,
+    #[serde(rename = "Totals")]
+    pub totals: Option<AccountTotals>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct AccountDeltas {
+    #[serde(rename = "Accts")]
+    pub accounts: Option<Vec<BalanceRecord>>,
+    #[serde(rename = "AppResources")]
+    pub app_resources: Option<Vec<AppResourceRecord>>,
+    #[serde(rename = "AssetResources")]
+    pub asset_resources: Option<Vec<AssetResourceRecord>>,
+}
+
+/// The new state of an account.
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct BalanceRecord {
+    #[serde(rename = "Addr")]
+    pub address: String,
+    #[serde(rename = "AccountData", default)]
+    pub account_data: LedgerAccountData,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct LedgerAccountData {
+    #[serde(rename = "AccountBaseData", default)]
+    pub account_base_data: AccountBaseData,
+    #[serde(rename = "VotingData")]
+    pub voting_data: Option<VotingData>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct AccountBaseData {
+    /// The participation status, 0 for offline, 1 for online and 2 for not participating.
+    #[serde(rename = "Status")]
+    pub status: Option<u64>,
+    #[serde(rename = "MicroAlgos")]
+    pub micro_algos: Option<u64>,
+    #[serde(rename = "RewardsBase")]
+    pub rewards_base: Option<u64>,
+    #[serde(rename = "RewardedMicroAlgos")]
+    pub rewarded_micro_algos: Option<u64>,
+    #[serde(rename = "AuthAddr")]
+    pub auth_addr: Option<String>,
+    #[serde(rename = "IncentiveEligible")]
+    pub incentive_eligible: Option<bool>,
+    #[serde(rename = "TotalAppSchema")]
+    pub total_app_schema: Option<LedgerStateSchema>,
+    #[serde(rename = "TotalExtraAppPages")]
+    pub total_extra_app_pages: Option<u64>,
+    #[serde(rename = "TotalAppParams")]
+    pub total_app_params: Option<u64>,
+    #[serde(rename = "TotalAppLocalStates")]
+    pub total_app_local_states: Option<u64>,
+    #[serde(rename = "TotalAssetParams")]
+    pub total_asset_params: Option<u64>,
+    #[serde(rename = "TotalAssets")]
+    pub total_assets: Option<u64>,
+    #[serde(rename = "TotalBoxes")]
+    pub total_boxes: Option<u64>,
+    #[serde(rename = "TotalBoxBytes")]
+    pub total_box_bytes: Option<u64>,
+    #[serde(rename = "LastProposed")]
+    pub last_proposed: Option<u64>,
+    #[serde(rename = "LastHeartbeat")]
+    pub last_heartbeat: Option<u64>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct VotingData {
+    #[serde(rename = "VoteID")]
+    pub vote_id: Option<String>,
+    #[serde(rename = "SelectionID")]
+    pub selection_id: Option<String>,
+    #[serde(rename = "StateProofID")]
+    pub state_proof_id: Option<String>,
+    #[serde(rename = "VoteFirstValid")]
+    pub vote_first_valid: Option<u64>,
+    #[serde(rename = "VoteLastValid")]
+    pub vote_last_valid: Option<u64>,
+    #[serde(rename = "VoteKeyDilution")]
+    pub vote_key_dilution: Option<u64>,
+}
+
+/// The new params or local state of an application for an account.
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct AppResourceRecord {
+    #[serde(rename = "Aidx")]
+    pub app_id: u64,
+    #[serde(rename = "Addr")]
+    pub address: String,
+    #[serde(rename = "Params", default)]
+    pub params: AppParamsDelta,
+    #[serde(rename = "State", default)]
+    pub state: AppLocalStateDelta,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct AppParamsDelta {
+    #[serde(rename = "Params")]
+    pub params: Option<LedgerAppParams>,
+    #[serde(rename = "Deleted")]
+    pub deleted: Option<bool>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct AppLocalStateDelta {
+    #[serde(rename = "LocalState")]
+    pub local_state: Option<LedgerAppLocalState>,
+    #[serde(rename = "Deleted")]
+    pub deleted: Option<bool>,
+}
+
+/// The new params or holding of an asset for an account.
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct AssetResourceRecord {
+    #[serde(rename = "Aidx")]
+    pub asset_id: u64,
+    #[serde(rename = "Addr")]
+    pub address: String,
+    #[serde(rename = "Params", default)]
+    pub params: AssetParamsDelta,
+    #[serde(rename = "Holding", default)]
+    pub holding: AssetHoldingDelta,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct AssetParamsDelta {
+    #[serde(rename = "Params")]
+    pub params: Option<LedgerAssetParams>,
+    #[serde(rename = "Deleted")]
+    pub deleted: Option<bool>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct AssetHoldingDelta {
+    #[serde(rename = "Holding")]
+    pub holding: Option<LedgerAssetHolding>,
+    #[serde(rename = "Deleted")]
+    pub deleted: Option<bool>,
+}
+
+/// Application params in their ledger encoding.
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct LedgerAppParams {
+    #[serde(rename = "approv")]
+    pub approval_program: Option<String>,
+    #[serde(rename = "clearp")]
+    pub clear_state_program: Option<String>,
+    #[serde(rename = "gs")]
+    pub global_state: Option<BTreeMap<String, LedgerTealValue>>,
+    #[serde(rename = "lsch")]
+    pub local_state_schema: Option<LedgerStateSchema>,
+    #[serde(rename = "gsch")]
+    pub global_state_schema: Option<LedgerStateSchema>,
+    #[serde(rename = "epp")]
+    pub extra_program_pages: Option<u64>,
+    #[serde(rename = "v")]
+    pub version: Option<u64>,
+}
+
+/// Application local state in its ledger encoding.
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct LedgerAppLocalState {
+    #[serde(rename = "hsch")]
+    pub schema: Option<LedgerStateSchema>,
+    #[serde(rename = "tkv")]
+    pub key_value: Option<BTreeMap<String, LedgerTealValue>>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct LedgerStateSchema {
+    #[serde(rename = "nui")]
+    pub num_uint: Option<u64>,
+    #[serde(rename = "nbs")]
+    pub num_byte_slice: Option<u64>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct LedgerTealValue {
+    /// The value type, 1 for bytes and 2 for uint.
+    #[serde(rename = "tt")]
+    pub value_type: Option<u64>,
+    #[serde(rename = "tb")]
+    pub bytes: Option<String>,
+    #[serde(rename = "ui")]
+    pub uint: Option<u64>,
+}
+
+/// Asset params in their ledger encoding.
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct LedgerAssetParams {
+    #[serde(rename = "t")]
+    pub total: Option<u64>,
+    #[serde(rename = "dc")]
+    pub decimals: Option<u64>,
+    #[serde(rename = "df")]
+    pub default_frozen: Option<bool>,
+    #[serde(rename = "un")]
+    pub unit_name: Option<String>,
+    #[serde(rename = "an")]
+    pub asset_name: Option<String>,
+    #[serde(rename = "au")]
+    pub url: Option<String>,
+    #[serde(rename = "am")]
+    pub metadata_hash: Option<String>,
+    #[serde(rename = "m")]
+    pub manager: Option<String>,
+    #[serde(rename = "r")]
+    pub reserve: Option<String>,
+    #[serde(rename = "f")]
+    pub freeze: Option<String>,
+    #[serde(rename = "c")]
+    pub clawback: Option<String>,
+}
+
+/// An asset holding in its ledger encoding.
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct LedgerAssetHolding {
+    #[serde(rename = "a")]
+    pub amount: Option<u64>,
+    #[serde(rename = "f")]
+    pub frozen: Option<bool>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct KvValueDelta {
+    #[serde(rename = "Data")]
+    pub data: Option<String>,
+    #[serde(rename = "OldData")]
+    pub old_data: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct IncludedTransactions {
+    #[serde(rename = "LastValid", default)]
+    pub last_valid: u64,
+    /// The index of the transaction in the block.
+    #[serde(rename = "Intra", default)]
+    pub intra: u64,
+}
+
+/// Deserializes the entries of a map with struct keys, which is an empty object when the map is
+/// empty, as there is then no key to tell it apart from other maps.
+fn struct_key_entries<'de, D, T>(deserializer: D) -> Result<Option<Vec<T>>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Entries<T> {
+        Entries(Vec<T>),
+        Empty(BTreeMap<String, u64>),
+    }
+    match Option::<Entries<T>>::deserialize(deserializer)? {
+        Some(Entries::Entries(entries)) => Ok(Some(entries)),
+        Some(Entries::Empty(map)) if map.is_empty() => Ok(Some(Vec::new())),
+        Some(Entries::Empty(_)) => Err(serde::de::Error::custom("expected map entries")),
+        None => Ok(None),
+    }
+}
+
+/// A lease and the round it expires.
+///
+/// The leases are keyed by the sender and lease in msgpack, so are rendered as an array of these.
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct TxleaseEntry {
+    #[serde(rename = "key", default)]
+    pub lease: Txlease,
+    #[serde(rename = "value", default)]
+    pub expires: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct Txlease {
+    #[serde(rename = "Sender", default)]
+    pub sender: String,
+    #[serde(rename = "Lease", default)]
+    pub lease: String,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct ModifiedCreatable {
+    /// The type of the creatable, 0 for an asset and 1 for an application.
+    #[serde(rename = "Ctype")]
+    pub creatable_type: Option<u64>,
+    #[serde(rename = "Created")]
+    pub created: Option<bool>,
+    #[serde(rename = "Creator")]
+    pub creator: Option<String>,
+    #[serde(rename = "Ndeltas")]
+    pub ndeltas: Option<u64>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct AccountTotals {
+    #[serde(rename = "online")]
+    pub online: Option<AlgoCount>,
+    #[serde(rename = "offline")]
+    pub offline: Option<AlgoCount>,
+    #[serde(rename = "notpart")]
+    pub not_participating: Option<AlgoCount>,
+    #[serde(rename = "rwdlvl")]
+    pub rewards_level: Option<u64>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct AlgoCount {
+    #[serde(rename = "mon")]
+    pub money: Option<u64>,
+    #[serde(rename = "rwd")]
+    pub reward_units: Option<u64>,
+}
+
+// -----------------------------
+// Registration helper
+// -----------------------------
+
+pub fn register_ledger_models(registry: &mut ModelRegistry) {
+    registry.register_response::<Block>(ModelType::Block);
+    registry.register_response::<LedgerStateDelta>(ModelType::LedgerStateDelta);
+    registry.register_response::<StateProof>(ModelType::StateProof);
+}
@@ -1,6 +1,17 @@
+pub mod account;
+// Generated from `api/specs/algod.oas3.json` with the `api/oas_templates/rust_msgpack` templates
+// by `bun run generate:algod_api:rs` in `api`, so shouldn't be edited by hand.
+#[allow(unused_imports, clippy::derivable_impls, clippy::too_many_arguments)]
+pub mod algod;
+pub mod ledger;
 pub mod simulate;
+pub mod transaction;
 
+pub use account::*;
+pub use algod::*;
+pub use ledger::*;
 pub use simulate::*;
+pub use transaction::*;
 
 use crate::ModelRegistry;
 
@@ -8,4 +19,8 @@ use crate::ModelRegistry;
 pub fn register_all_models(registry: &mut ModelRegistry) {
     // Register simulation models
     simulate::register_simulation_models(registry);
+    // Register algod response models
+    account::register_account_models(registry);
+    ledger::register_ledger_models(registry);
+    transaction::register_transaction_models(registry);
 }
@@ -1,6 +1,8 @@
 use crate::msgpack::{
-    encode_value_to_msgpack, AlgoKitMsgPackError, ModelHandler, ModelRegistry, ModelType, Result,
+    encode_value_to_msgpack, rmpv_to_json, AlgoKitMsgPackError, ModelHandler, ModelRegistry,
+    ModelType, PendingTransactionResponse, Result,
 };
+use crate::{AlgorandMsgpack, SignedTransaction};
 use base64::engine::general_purpose::STANDARD as BASE64;
 use base64::Engine;
 use serde::{Deserialize, Serialize};
@@ -38,6 +40,16 @@ impl SimulateRequest {
             ..Default::default()
         }
     }
+
+    /// Creates a request to simulate groups of signed transactions.
+    pub fn from_signed_transactions(txn_groups: Vec<Vec<SignedTransaction>>) -> Result<Self> {
+        Ok(Self::new(
+            txn_groups
+                .iter()
+                .map(|txns| SimulateRequestTransactionGroup::from_signed_transactions(txns))
+                .collect::<Result<_>>()?,
+        ))
+    }
 }
 
 #[skip_serializing_none]
@@ -69,22 +81,261 @@ impl SimulateRequestTransactionGroup {
     pub fn new(txns: Vec<String>) -> Self {
         Self { txns }
     }
+
+    /// Creates a group from signed transactions, which are base64 encoded.
+    pub fn from_signed_transactions(txns: &[SignedTransaction]) -> Result<Self> {
+        Ok(Self::new(
+            txns.iter()
+                .map(|txn| Ok(BASE64.encode(txn.encode()?)))
+                .collect::<Result<_>>()?,
+        ))
+    }
+}
+
+/// How the signed transactions of a decoded simulate request are rendered in JSON.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum SimulateRequestTransactionFormat {
+    /// The base64 encoded msgpack of the signed transaction, as accepted when encoding a simulate
+    /// request.
+    #[default]
+    Base64,
+
+    /// The decoded signed transaction, with binary values base64 encoded.
+    Json,
+}
+
+/// Decodes a msgpack simulate request to JSON.
+///
+/// # Parameters
+/// * `msgpack_bytes` - The msgpack encoded simulate request
+/// * `format` - How the signed transactions of the request are rendered
+///
+/// # Returns
+/// The JSON of the simulate request, or an error if it isn't a valid simulate request or, when
+/// rendering them as JSON, one of its signed transactions can't be decoded.
+pub fn decode_simulate_request_to_json(
+    msgpack_bytes: &[u8],
+    format: SimulateRequestTransactionFormat,
+) -> Result<String> {
+    // The request is read in place, so the signed transactions keep their original encoding
+    let reader = &mut &msgpack_bytes[..];
+    let len = rmp::decode::read_map_len(reader)
+        .map_err(|_| decoding_error("expected the simulate request to be a map"))?;
+
+    let mut json = serde_json::Map::with_capacity(len as usize);
+    for _ in 0..len {
+        let key = read_key(reader, "expected the simulate request keys to be strings")?;
+        let value = match key.as_str() {
+            "txn-groups" => decode_txn_groups(reader, format)?,
+            _ => rmpv_to_json(&read_value(reader)?),
+        };
+        json.insert(key, value);
+    }
+    Ok(serde_json::to_string(&json)?)
+}
+
+fn decoding_error(message: &str) -> AlgoKitMsgPackError {
+    rmp_serde::decode::Error::Syntax(message.to_string()).into()
+}
+
+fn read_value(reader: &mut &[u8]) -> Result<rmpv::Value> {
+    rmpv::decode::read_value(reader).map_err(|e| AlgoKitMsgPackError::IoError(e.to_string()))
+}
+
+fn read_key(reader: &mut &[u8], message: &str) -> Result<String> {
+    match read_value(reader)? {
+        rmpv::Value::String(key) => key.into_str().ok_or_else(|| decoding_error(message)),
+        _ => Err(decoding_error(message)),
+    }
+}
+
+fn decode_txn_groups(
+    reader: &mut &[u8],
+    format: SimulateRequestTransactionFormat,
+) -> Result<serde_json::Value> {
+    let len = rmp::decode::read_array_len(reader)
+        .map_err(|_| decoding_error("expected txn-groups to be an array"))?;
+    let mut json_groups = Vec::new();
+    for _ in 0..len {
+        let fields = rmp::decode::read_map_len(reader)
+            .map_err(|_| decoding_error("expected each txn group to be a map"))?;
+        let mut json_group = serde_json::Map::new();
+        for _ in 0..fields {
+            let key = read_key(reader, "expected the txn group keys to be strings")?;
+            let value = match key.as_str() {
+                "txns" => {
+                    let len = rmp::decode::read_array_len(reader)
+                        .map_err(|_| decoding_error("expected txns to be an array"))?;
+                    let mut txns = Vec::new();
+                    for _ in 0..len {
+                        txns.push(decode_signed_transaction(reader, format)?);
+                    }
+                    serde_json::Value::Array(txns)
+                }
+                _ => rmpv_to_json(&read_value(reader)?),
+            };
+            json_group.insert(key, value);
+        }
+        json_groups.push(serde_json::Value::Object(json_group));
+    }
+    Ok(serde_json::Value::Array(json_groups))
+}
+
+fn decode_signed_transaction(
+    reader: &mut &[u8],
+    format: SimulateRequestTransactionFormat,
+) -> Result<serde_json::Value> {
+    let start = *reader;
+    let value = read_value(reader)?;
+    let bytes = &start[..start.len() - reader.len()];
+    match format {
+        SimulateRequestTransactionFormat::Base64 => {
+            Ok(serde_json::Value::String(BASE64.encode(bytes)))
+        }
+        SimulateRequestTransactionFormat::Json => {
+            SignedTransaction::decode(bytes)?;
+            Ok(rmpv_to_json(&value))
+        }
+    }
 }
 
 // ---------------------
 // Simulation responses
 // ---------------------
 
+/// The resources which were accessed without being referenced by the transaction or group.
+#[skip_serializing_none]
 #[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
 pub struct SimulateUnnamedResourcesAccessed {
-    #[serde(flatten)]
-    pub other: serde_json::Value,
+    #[serde(rename = "accounts")]
+    pub accounts: Option<Vec<String>>,
+    #[serde(rename = "assets")]
+    pub assets: Option<Vec<u64>>,
+    #[serde(rename = "apps")]
+    pub apps: Option<Vec<u64>>,
+    #[serde(rename = "boxes")]
+    pub boxes: Option<Vec<BoxReference>>,
+    /// The number of extra box references used to increase the IO budget.
+    #[serde(rename = "extra-box-refs")]
+    pub extra_box_refs: Option<u64>,
+    #[serde(rename = "asset-holdings")]
+    pub asset_holdings: Option<Vec<AssetHoldingReference>>,
+    #[serde(rename = "app-locals")]
+    pub app_locals: Option<Vec<ApplicationLocalReference>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct BoxReference {
+    #[serde(rename = "app")]
+    pub app: u64,
+    #[serde(rename = "name")]
+    pub name: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct AssetHoldingReference {
+    #[serde(rename = "account")]
+    pub account: String,
+    #[serde(rename = "asset")]
+    pub asset: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct ApplicationLocalReference {
+    #[serde(rename = "account")]
+    pub account: String,
+    #[serde(rename = "app")]
+    pub app: u64,
+}
+
+/// The execution trace of the programs run by a transaction and its inner transactions.
+#[skip_serializing_none]
 #[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
 pub struct SimulationTransactionExecTrace {
-    #[serde(flatten)]
-    pub other: serde_json::Value,
+    #[serde(rename = "approval-program-trace")]
+    pub approval_program_trace: Option<Vec<SimulationOpcodeTraceUnit>>,
+    #[serde(rename = "approval-program-hash")]
+    pub approval_program_hash: Option<String>,
+    #[serde(rename = "clear-state-program-trace")]
+    pub clear_state_program_trace: Option<Vec<SimulationOpcodeTraceUnit>>,
+    #[serde(rename = "clear-state-program-hash")]
+    pub clear_state_program_hash: Option<String>,
+    #[serde(rename = "clear-state-rollback")]
+    pub clear_state_rollback: Option<bool>,
+    #[serde(rename = "clear-state-rollback-error")]
+    pub clear_state_rollback_error: Option<String>,
+    #[serde(rename = "logic-sig-trace")]
+    pub logic_sig_trace: Option<Vec<SimulationOpcodeTraceUnit>>,
+    #[serde(rename = "logic-sig-hash")]
+    pub logic_sig_hash: Option<String>,
+    #[serde(rename = "inner-trace")]
+    pub inner_trace: Option<Vec<SimulationTransactionExecTrace>>,
+}
+
+/// The effects of evaluating a single opcode.
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct SimulationOpcodeTraceUnit {
+    #[serde(rename = "pc", default)]
+    pub pc: u64,
+    #[serde(rename = "scratch-changes")]
+    pub scratch_changes: Option<Vec<ScratchChange>>,
+    #[serde(rename = "state-changes")]
+    pub state_changes: Option<Vec<ApplicationStateOperation>>,
+    /// The indexes of the traces of the inner transactions spawned by this opcode.
+    #[serde(rename = "spawned-inners")]
+    pub spawned_inners: Option<Vec<u64>>,
+    #[serde(rename = "stack-pop-count")]
+    pub stack_pop_count: Option<u64>,
+    #[serde(rename = "stack-additions")]
+    pub stack_additions: Option<Vec<AvmValue>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct ScratchChange {
+    #[serde(rename = "slot", default)]
+    pub slot: u64,
+    #[serde(rename = "new-value")]
+    pub new_value: AvmValue,
+}
+
+/// A write or delete of global, local or box state.
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct ApplicationStateOperation {
+    /// `w` for a write or `d` for a delete.
+    #[serde(rename = "operation")]
+    pub operation: String,
+    /// `g` for global state, `l` for local state or `b` for boxes.
+    #[serde(rename = "app-state-type")]
+    pub app_state_type: String,
+    #[serde(rename = "key", default)]
+    pub key: String,
+    #[serde(rename = "new-value")]
+    pub new_value: Option<AvmValue>,
+    #[serde(rename = "account")]
+    pub account: Option<String>,
+}
+
+/// A value on the stack or in state, with bytes base64 encoded.
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct AvmValue {
+    /// The value type, 1 for bytes and 2 for uint64.
+    #[serde(rename = "type")]
+    pub value_type: u64,
+    #[serde(rename = "bytes")]
+    pub bytes: Option<String>,
+    #[serde(rename = "uint")]
+    pub uint: Option<u64>,
+}
+
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct AvmKeyValue {
+    #[serde(rename = "key", default)]
+    pub key: String,
+    #[serde(rename = "value")]
+    pub value: AvmValue,
 }
 
 #[skip_serializing_none]
@@ -104,17 +355,42 @@ pub struct SimulationEvalOverrides {
     pub fix_signers: Option<bool>,
 }
 
+/// The initial states of the applications accessed during simulation.
+#[skip_serializing_none]
 #[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
 pub struct SimulateInitialStates {
-    #[serde(flatten)]
-    pub other: serde_json::Value,
+    #[serde(rename = "app-initial-states")]
+    pub app_initial_states: Option<Vec<ApplicationInitialStates>>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct ApplicationInitialStates {
+    #[serde(rename = "id")]
+    pub id: u64,
+    #[serde(rename = "app-locals")]
+    pub app_locals: Option<Vec<ApplicationKVStorage>>,
+    #[serde(rename = "app-globals")]
+    pub app_globals: Option<ApplicationKVStorage>,
+    #[serde(rename = "app-boxes")]
+    pub app_boxes: Option<ApplicationKVStorage>,
 }
 
 #[skip_serializing_none]
 #[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct ApplicationKVStorage {
+    #[serde(rename = "kvs", default)]
+    pub kvs: Vec<AvmKeyValue>,
+    /// The account of the local state.
+    #[serde(rename = "account")]
+    pub account: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct SimulateTransactionResult {
     #[serde(rename = "txn-result")]
-    pub txn_result: serde_json::Value,
+    pub txn_result: PendingTransactionResponse,
     #[serde(rename = "app-budget-consumed")]
     pub app_budget_consumed: Option<i64>,
     #[serde(rename = "logic-sig-budget-consumed")]
@@ -128,7 +404,7 @@ pub struct SimulateTransactionResult {
 }
 
 #[skip_serializing_none]
-#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct SimulateTransactionGroupResult {
     #[serde(rename = "txn-results")]
     pub txn_results: Vec<SimulateTransactionResult>,
@@ -145,7 +421,7 @@ pub struct SimulateTransactionGroupResult {
 }
 
 #[skip_serializing_none]
-#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct SimulateTransaction200Response {
     pub version: i64,
     #[serde(rename = "last-round")]
@@ -194,10 +470,8 @@ impl ModelHandler for SimulateRequestHandler {
         Ok(buf)
     }
 
-    fn decode_msgpack_to_json(&self, _msgpack_bytes: &[u8]) -> Result<String> {
-        Err(AlgoKitMsgPackError::MsgpackWriteError(
-            "Simulate request decoding is not supported".into(),
-        ))
+    fn decode_msgpack_to_json(&self, msgpack_bytes: &[u8]) -> Result<String> {
+        decode_simulate_request_to_json(msgpack_bytes, SimulateRequestTransactionFormat::Base64)
     }
 }
 
@@ -238,39 +512,6 @@ impl SimulateRequestHandler {
         }
         Ok(())
     }
-
-    fn rmpv_to_json(value: &rmpv::Value) -> serde_json::Value {
-        use rmpv::Value as V;
-        match value {
-            V::Nil => serde_json::Value::Null,
-            V::Boolean(b) => serde_json::Value::Bool(*b),
-            V::Integer(i) => {
-                if let Some(n) = i.as_i64() {
-                    serde_json::Value::Number(n.into())
-                } else if let Some(n) = i.as_u64() {
-                    serde_json::Value::Number(serde_json::Number::from(n))
-                } else {
-                    serde_json::Value::String(i.to_string())
-                }
-            }
-            V::F32(f) => serde_json::Number::from_f64(*f as f64).unwrap().into(),
-            V::F64(f) => serde_json::Number::from_f64(*f).unwrap().into(),
-            V::String(s) => serde_json::Value::String(s.as_str().unwrap_or_default().into()),
-            V::Binary(b) | V::Ext(_, b) => serde_json::Value::String(BASE64.encode(b)),
-            V::Array(arr) => serde_json::Value::Array(arr.iter().map(Self::rmpv_to_json).collect()),
-            V::Map(map) => {
-                let mut m = serde_json::Map::with_capacity(map.len());
-                for (k, v) in map {
-                    let key = match k {
-                        V::String(s) => s.as_str().unwrap_or_default().to_string(),
-                        _ => k.to_string(),
-                    };
-                    m.insert(key, Self::rmpv_to_json(v));
-                }
-                serde_json::Value::Object(m)
-            }
-        }
-    }
 }
 
 struct SimulateResponseHandler;
@@ -286,7 +527,7 @@ impl ModelHandler for SimulateResponseHandler {
         let mut cursor = Cursor::new(msgpack_bytes);
         let root: rmpv::Value = rmpv::decode::read_value(&mut cursor)
             .map_err(|e| AlgoKitMsgPackError::IoError(e.to_string()))?;
-        let json_val = SimulateRequestHandler::rmpv_to_json(&root);
+        let json_val = rmpv_to_json(&root);
         Ok(serde_json::to_string(&json_val)?)
     }
 }
@@ -0,0 +1,54 @@
+use crate::msgpack::{ModelRegistry, ModelType};
+
+use super::algod::{PendingTransactionResponse, TransactionParams200Response};
+
+// -----------------------------
+// Signed transaction fields
+// -----------------------------
+
+/// (De)serializes a [`SignedTransaction`](crate::SignedTransaction) as the JSON converted from its
+/// msgpack, with binary values base64 encoded, as for the untyped fields of the models.
+///
+/// The generated models use it for the fields marked with `x-algokit-signed-txn` in the spec.
+pub(crate) mod signed_transaction_json {
+    use crate::msgpack::rmpv_to_json;
+    use crate::msgpack::schema::{Converter, SIGNED_TRANSACTION};
+    use crate::msgpack::BytesFormat;
+    use crate::{AlgorandMsgpack, SignedTransaction};
+    use serde::de::Error as _;
+    use serde::ser::Error as _;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<S: Serializer>(
+        txn: &SignedTransaction,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let bytes = txn.encode().map_err(S::Error::custom)?;
+        let value = rmpv::decode::read_value(&mut &bytes[..]).map_err(S::Error::custom)?;
+        rmpv_to_json(&value).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<SignedTransaction, D::Error> {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        let converter = Converter {
+            bytes_format: BytesFormat::Base64,
+            text_byte_strings: true,
+        };
+        let mut bytes = Vec::new();
+        converter
+            .write_msgpack(&mut bytes, &value, None, SIGNED_TRANSACTION, "txn")
+            .map_err(D::Error::custom)?;
+        SignedTransaction::decode_preserving_unknown_fields(&bytes).map_err(D::Error::custom)
+    }
+}
+
+// -----------------------------
+// Registration helper
+// -----------------------------
+
+pub fn register_transaction_models(registry: &mut ModelRegistry) {
+    registry.register_response::<PendingTransactionResponse>(ModelType::PendingTransactionResponse);
+    registry.register_response::<TransactionParams200Response>(ModelType::TransactionParams);
+}
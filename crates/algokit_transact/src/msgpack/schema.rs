@@ -0,0 +1,726 @@
+//! Lossless conversion between msgpack and JSON, driven by the schema of each model type.
+//!
+//! JSON has no binary type, no integer map keys and only UTF-8 strings, so converting msgpack to
+//! JSON without knowing the model means guessing on the way back, e.g. whether an array of small
+//! numbers was binary data. The schema of each [`ModelType`] records which fields hold binary data,
+//! strings of arbitrary bytes, embedded msgpack or maps with non-string keys, which lets the JSON
+//! be converted back to the same msgpack. Maps keyed by objects are represented as an array of
+//! `{"key": ..., "value": ...}` entries.
+//!
+//! The conversion is of the raw msgpack structure, with its own keys, rather than of the typed
+//! models, so a value which doesn't fit the schema (e.g. binary data in a field the schema doesn't
+//! know about) is an error rather than being silently changed.
+
+use super::{struct_key_entry, AlgoKitMsgPackError, ModelType, Result};
+use crate::utils::write_msgpack_value;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use rmp::encode as rmp_encode;
+use serde_json::Value;
+use std::collections::BTreeMap;
+use FieldSchema::{Array, ByteString, Bytes, Embedded, Map};
+
+/// How binary values are represented in JSON.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum BytesFormat {
+    /// A standard base64 string.
+    #[default]
+    Base64,
+
+    /// A lowercase hex string.
+    Hex,
+
+    /// An array of the byte values.
+    ///
+    /// JSON object keys must be strings, so binary map keys are base64 encoded instead.
+    Array,
+}
+
+/// The type of a field, where it isn't implied by the JSON value.
+#[derive(Debug)]
+pub(crate) enum FieldSchema {
+    /// A msgpack binary value.
+    Bytes,
+
+    /// A msgpack string which may hold arbitrary bytes rather than UTF-8 text.
+    ByteString,
+
+    /// A msgpack value embedded in the model, represented in JSON as the bytes of its encoding.
+    Embedded,
+
+    /// An array of values of the given type.
+    Array(&'static FieldSchema),
+
+    /// A map with keys of the given type and values of the given type, represented as a JSON
+    /// object, or as an array of `{"key": ..., "value": ...}` entries for [`MapKey::Fields`].
+    Map(MapKey, &'static FieldSchema),
+
+    /// An object whose fields have the given types, instead of those of the enclosing object.
+    Fields(Fields),
+}
+
+/// The type of the keys of a [`FieldSchema::Map`].
+#[derive(Debug)]
+pub(crate) enum MapKey {
+    Bytes,
+    ByteString,
+    Integer,
+    /// An object whose fields have the given types, such as the sender and lease of a lease.
+    Fields(Fields),
+}
+
+/// The types of the fields of an object, which also apply to the fields of any nested objects
+/// unless replaced by [`FieldSchema::Fields`].
+pub(crate) type Fields = &'static [(&'static str, FieldSchema)];
+
+const NO_FIELDS: FieldSchema = FieldSchema::Fields(&[]);
+
+const ONE_TIME_SIGNATURE: Fields = &[
+    ("p", Bytes),
+    ("p1s", Bytes),
+    ("p2", Bytes),
+    ("p2s", Bytes),
+    ("ps", Bytes),
+    ("s", Bytes),
+];
+
+const MERKLE_PROOF: Fields = &[("pth", Array(&Bytes))];
+
+const STATE_PROOF: Fields = &[
+    ("P", FieldSchema::Fields(MERKLE_PROOF)),
+    ("S", FieldSchema::Fields(MERKLE_PROOF)),
+    ("c", Bytes),
+    (
+        "r",
+        Map(
+            MapKey::Integer,
+            &FieldSchema::Fields(&[
+                ("p", FieldSchema::Fields(&[("cmt", Bytes)])),
+                (
+                    "s",
+                    FieldSchema::Fields(&[(
+                        "s",
+                        FieldSchema::Fields(&[
+                            ("prf", FieldSchema::Fields(MERKLE_PROOF)),
+                            ("sig", Bytes),
+                            ("vkey", FieldSchema::Fields(&[("k", Bytes)])),
+                        ]),
+                    )]),
+                ),
+            ]),
+        ),
+    ),
+];
+
+const ASSET_PARAMS: Fields = &[
+    ("am", Bytes),
+    ("an", ByteString),
+    ("au", ByteString),
+    ("c", Bytes),
+    ("f", Bytes),
+    ("m", Bytes),
+    ("r", Bytes),
+    ("un", ByteString),
+];
+
+const TEAL_VALUE: FieldSchema = FieldSchema::Fields(&[("tb", ByteString)]);
+
+const VALUE_DELTA: FieldSchema = FieldSchema::Fields(&[("bs", ByteString)]);
+
+const APP_LOCAL_STATE: Fields = &[("tkv", Map(MapKey::ByteString, &TEAL_VALUE))];
+
+const APP_PARAMS: Fields = &[
+    ("approv", Bytes),
+    ("clearp", Bytes),
+    ("gs", Map(MapKey::ByteString, &TEAL_VALUE)),
+];
+
+/// A signed transaction, along with the apply data of a transaction in a block.
+pub(crate) const SIGNED_TRANSACTION: Fields = &[
+    ("aclose", Bytes),
+    ("apaa", Array(&Bytes)),
+    ("apap", Bytes),
+    ("apar", FieldSchema::Fields(ASSET_PARAMS)),
+    ("apat", Array(&Bytes)),
+    ("apsu", Bytes),
+    ("arcv", Bytes),
+    ("arg", Array(&Bytes)),
+    ("asnd", Bytes),
+    ("close", Bytes),
+    ("fadd", Bytes),
+    ("gd", Map(MapKey::ByteString, &VALUE_DELTA)),
+    ("gh", Bytes),
+    ("grp", Bytes),
+    (
+        "hb",
+        FieldSchema::Fields(&[
+            ("a", Bytes),
+            ("prf", FieldSchema::Fields(ONE_TIME_SIGNATURE)),
+            ("sd", Bytes),
+            ("vid", Bytes),
+        ]),
+    ),
+    ("l", Bytes),
+    (
+        "ld",
+        Map(MapKey::Integer, &Map(MapKey::ByteString, &VALUE_DELTA)),
+    ),
+    ("lg", Array(&ByteString)),
+    ("lx", Bytes),
+    ("n", Bytes),
+    ("note", Bytes),
+    ("pk", Bytes),
+    ("rcv", Bytes),
+    ("rekey", Bytes),
+    ("s", Bytes),
+    ("sa", Array(&Bytes)),
+    ("selkey", Bytes),
+    ("sgnr", Bytes),
+    ("sig", Bytes),
+    ("snd", Bytes),
+    ("sp", FieldSchema::Fields(STATE_PROOF)),
+    ("spmsg", FieldSchema::Fields(&[("b", Bytes), ("v", Bytes)])),
+    ("sprfkey", Bytes),
+    ("votekey", Bytes),
+];
+
+const BLOCK_HEADER: Fields = &[
+    ("fees", Bytes),
+    ("gh", Bytes),
+    ("partupdabs", Array(&Bytes)),
+    ("partupdrmv", Array(&Bytes)),
+    ("prev", Bytes),
+    ("prp", Bytes),
+    ("rwd", Bytes),
+    ("seed", Bytes),
+    (
+        "spt",
+        Map(MapKey::Integer, &FieldSchema::Fields(&[("v", Bytes)])),
+    ),
+    ("txn", Bytes),
+    ("txn256", Bytes),
+    ("txn512", Bytes),
+    ("txns", FieldSchema::Fields(SIGNED_TRANSACTION)),
+];
+
+const CERTIFICATE: Fields = &[
+    ("dig", Bytes),
+    ("encdig", Bytes),
+    ("oprop", Bytes),
+    ("pf", Bytes),
+    ("sig", FieldSchema::Fields(ONE_TIME_SIGNATURE)),
+    ("sigs", FieldSchema::Fields(ONE_TIME_SIGNATURE)),
+    ("snd", Bytes),
+];
+
+const PENDING_TRANSACTION: Fields = &[
+    ("logs", Array(&Bytes)),
+    ("txn", FieldSchema::Fields(SIGNED_TRANSACTION)),
+];
+
+const SIMULATE_RESPONSE: Fields = &[
+    ("approval-program-hash", Bytes),
+    ("bytes", Bytes),
+    ("clear-state-program-hash", Bytes),
+    // The state deltas of a transaction result are already base64 encoded by algod
+    ("global-state-delta", NO_FIELDS),
+    ("key", Bytes),
+    ("local-state-delta", NO_FIELDS),
+    ("logic-sig-hash", Bytes),
+    ("logs", Array(&Bytes)),
+    ("name", Bytes),
+    ("txn", FieldSchema::Fields(SIGNED_TRANSACTION)),
+];
+
+// The ledger state delta has no codec tags, so is keyed by the Go field names
+const LEDGER_STATE_DELTA: Fields = &[
+    ("Addr", Bytes),
+    ("AuthAddr", Bytes),
+    (
+        "Creatables",
+        Map(MapKey::Integer, &FieldSchema::Fields(&[("Creator", Bytes)])),
+    ),
+    ("Hdr", FieldSchema::Fields(BLOCK_HEADER)),
+    ("Holding", NO_FIELDS),
+    (
+        "KvMods",
+        Map(
+            MapKey::ByteString,
+            &FieldSchema::Fields(&[("Data", Bytes), ("OldData", Bytes)]),
+        ),
+    ),
+    ("SelectionID", Bytes),
+    ("StateProofID", Bytes),
+    ("Txids", Map(MapKey::Bytes, &NO_FIELDS)),
+    (
+        "Txleases",
+        Map(
+            MapKey::Fields(&[("Lease", Bytes), ("Sender", Bytes)]),
+            &NO_FIELDS,
+        ),
+    ),
+    ("VoteID", Bytes),
+    ("am", Bytes),
+    ("an", ByteString),
+    ("approv", Bytes),
+    ("au", ByteString),
+    ("c", Bytes),
+    ("clearp", Bytes),
+    ("f", Bytes),
+    ("gs", Map(MapKey::ByteString, &TEAL_VALUE)),
+    ("m", Bytes),
+    ("r", Bytes),
+    ("tkv", Map(MapKey::ByteString, &TEAL_VALUE)),
+    ("un", ByteString),
+];
+
+impl ModelType {
+    /// The types of the fields of the raw msgpack encoding of the model.
+    pub(crate) fn schema(&self) -> Fields {
+        match self {
+            ModelType::SimulateRequest => &[("txns", Array(&Embedded))],
+            ModelType::SimulateTransaction200Response => SIMULATE_RESPONSE,
+            ModelType::Account => &[
+                (
+                    "apar",
+                    Map(MapKey::Integer, &FieldSchema::Fields(ASSET_PARAMS)),
+                ),
+                (
+                    "appl",
+                    Map(MapKey::Integer, &FieldSchema::Fields(APP_LOCAL_STATE)),
+                ),
+                (
+                    "appp",
+                    Map(MapKey::Integer, &FieldSchema::Fields(APP_PARAMS)),
+                ),
+                ("asset", Map(MapKey::Integer, &NO_FIELDS)),
+                ("sel", Bytes),
+                ("spend", Bytes),
+                ("stprf", Bytes),
+                ("vote", Bytes),
+            ],
+            ModelType::AccountAssetInformation => &[
+                ("asset-holding", NO_FIELDS),
+                ("asset-params", FieldSchema::Fields(ASSET_PARAMS)),
+            ],
+            ModelType::AccountApplicationInformation => &[
+                ("app-local-state", FieldSchema::Fields(APP_LOCAL_STATE)),
+                ("app-params", FieldSchema::Fields(APP_PARAMS)),
+            ],
+            ModelType::Block => &[
+                ("block", FieldSchema::Fields(BLOCK_HEADER)),
+                ("cert", FieldSchema::Fields(CERTIFICATE)),
+            ],
+            ModelType::PendingTransactionResponse => PENDING_TRANSACTION,
+            ModelType::LedgerStateDelta => LEDGER_STATE_DELTA,
+            ModelType::TransactionParams => &[("genesis-hash", Bytes)],
+            ModelType::Box => &[("name", Bytes), ("value", Bytes)],
+            ModelType::StateProof => &[
+                ("BlockHeadersCommitment", Bytes),
+                ("StateProof", Bytes),
+                ("VotersCommitment", Bytes),
+            ],
+        }
+    }
+}
+
+/// Decodes msgpack to JSON using the schema of the model type, so that encoding the JSON with
+/// [`encode_json_to_msgpack_lossless`] returns the same msgpack.
+///
+/// Unlike [`super::decode_msgpack_to_json`], the JSON has the raw structure and keys of the
+/// msgpack rather than those of the model, e.g. the ledger encoding of an account.
+///
+/// # Parameters
+/// * `model_type` - The model type of the msgpack
+/// * `msgpack_bytes` - The canonical msgpack encoding of the model
+/// * `bytes_format` - How binary values are represented in the JSON
+///
+/// # Returns
+/// The JSON, or a `SchemaError` if the msgpack has a value which doesn't fit the schema and so
+/// couldn't be converted back, such as binary data in a field which isn't known to be binary or a
+/// 32-bit float.
+pub fn decode_msgpack_to_json_lossless(
+    model_type: ModelType,
+    msgpack_bytes: &[u8],
+    bytes_format: BytesFormat,
+) -> Result<String> {
+    let mut reader = msgpack_bytes;
+    let value = rmpv::decode::read_value(&mut reader)
+        .map_err(|e| AlgoKitMsgPackError::IoError(e.to_string()))?;
+    if !reader.is_empty() {
+        return Err(rmp_serde::decode::Error::Syntax(format!(
+            "{} trailing bytes after the msgpack value",
+            reader.len()
+        ))
+        .into());
+    }
+
+    let converter = Converter {
+        bytes_format,
+        text_byte_strings: false,
+    };
+    let json = converter.to_json(&value, None, model_type.schema(), model_type.as_str())?;
+    Ok(serde_json::to_string(&json)?)
+}
+
+/// Encodes JSON produced by [`decode_msgpack_to_json_lossless`] to msgpack using the schema of the
+/// model type.
+///
+/// Object keys are written in sorted order, so canonical msgpack (as produced by algod) round trips
+/// byte for byte.
+///
+/// # Parameters
+/// * `model_type` - The model type of the JSON
+/// * `json_str` - The JSON, with the raw structure and keys of the msgpack
+/// * `bytes_format` - How binary values are represented in the JSON
+///
+/// # Returns
+/// The msgpack, or a `SchemaError` if the JSON has a value which doesn't fit the schema, such as a
+/// binary field which isn't in the bytes format or an integer which doesn't fit in 64 bits.
+pub fn encode_json_to_msgpack_lossless(
+    model_type: ModelType,
+    json_str: &str,
+    bytes_format: BytesFormat,
+) -> Result<Vec<u8>> {
+    let value: Value = serde_json::from_str(json_str)?;
+    let converter = Converter {
+        bytes_format,
+        text_byte_strings: false,
+    };
+    let mut buf = Vec::new();
+    converter.write_msgpack(
+        &mut buf,
+        &value,
+        None,
+        model_type.schema(),
+        model_type.as_str(),
+    )?;
+    Ok(buf)
+}
+
+/// Converts between msgpack and JSON using the schema of a model.
+pub(crate) struct Converter {
+    pub(crate) bytes_format: BytesFormat,
+
+    /// Whether strings of arbitrary bytes are text in the JSON rather than in the bytes format,
+    /// as in the JSON converted from msgpack by [`super::rmpv_to_json`].
+    pub(crate) text_byte_strings: bool,
+}
+
+impl Converter {
+    /// Converts a msgpack value to JSON, where `schema` is the type of the value if the schema
+    /// has one and `fields` are the types of the fields of the enclosing object.
+    fn to_json(
+        &self,
+        value: &rmpv::Value,
+        schema: Option<&'static FieldSchema>,
+        fields: Fields,
+        name: &str,
+    ) -> Result<Value> {
+        use rmpv::Value as V;
+        match (schema, value) {
+            (_, V::Nil) => Ok(Value::Null),
+            (Some(FieldSchema::Fields(fields)), _) => self.to_json(value, None, fields, name),
+            (Some(Bytes), V::Binary(bytes)) => Ok(self.bytes_to_json(bytes)),
+            (Some(ByteString), V::String(s)) => Ok(self.bytes_to_json(s.as_bytes())),
+            (Some(Embedded), _) => {
+                let mut buf = Vec::new();
+                write_msgpack_value(&mut buf, value)?;
+                Ok(self.bytes_to_json(&buf))
+            }
+            (Some(Array(item)), V::Array(items)) => Ok(Value::Array(
+                items
+                    .iter()
+                    .map(|value| self.to_json(value, Some(item), fields, name))
+                    .collect::<Result<_>>()?,
+            )),
+            (Some(Map(MapKey::Fields(key_fields), item)), V::Map(entries)) => Ok(Value::Array(
+                entries
+                    .iter()
+                    .map(|(key, value)| {
+                        Ok(struct_key_entry(
+                            self.to_json(key, None, key_fields, name)?,
+                            self.to_json(value, Some(item), fields, name)?,
+                        ))
+                    })
+                    .collect::<Result<_>>()?,
+            )),
+            (Some(Map(key_type, item)), V::Map(entries)) => {
+                let mut map = serde_json::Map::with_capacity(entries.len());
+                for (key, value) in entries {
+                    let key = match (key_type, key) {
+                        (MapKey::Bytes, V::Binary(bytes)) => self.key_to_json(bytes),
+                        (MapKey::ByteString, V::String(s)) => self.key_to_json(s.as_bytes()),
+                        (MapKey::Integer, V::Integer(i)) => i.to_string(),
+                        _ => return Err(unexpected_value(value_kind(key), name)),
+                    };
+                    map.insert(key, self.to_json(value, Some(item), fields, name)?);
+                }
+                Ok(Value::Object(map))
+            }
+            (None, V::Boolean(b)) => Ok(Value::Bool(*b)),
+            (None, V::Integer(i)) => Ok(match i.as_u64() {
+                Some(n) => n.into(),
+                None => i.as_i64().expect("integer is either u64 or i64").into(),
+            }),
+            (None, V::F64(f)) => serde_json::Number::from_f64(*f)
+                .map(Value::Number)
+                .ok_or_else(|| unexpected_value("non-finite float", name)),
+            (None, V::String(s)) => s
+                .as_str()
+                .map(|s| Value::String(s.to_string()))
+                .ok_or_else(|| unexpected_value("non-UTF-8 string", name)),
+            (None, V::Array(items)) => Ok(Value::Array(
+                items
+                    .iter()
+                    .map(|value| self.to_json(value, None, fields, name))
+                    .collect::<Result<_>>()?,
+            )),
+            (None, V::Map(entries)) => {
+                let mut map = serde_json::Map::with_capacity(entries.len());
+                for (key, value) in entries {
+                    let key = key
+                        .as_str()
+                        .ok_or_else(|| unexpected_value(value_kind(key), name))?;
+                    let schema = field_schema(fields, key);
+                    map.insert(key.to_string(), self.to_json(value, schema, fields, key)?);
+                }
+                Ok(Value::Object(map))
+            }
+            _ => Err(unexpected_value(value_kind(value), name)),
+        }
+    }
+
+    /// Writes a JSON value as msgpack, where `schema` is the type of the value if the schema has
+    /// one and `fields` are the types of the fields of the enclosing object.
+    pub(crate) fn write_msgpack(
+        &self,
+        buf: &mut Vec<u8>,
+        value: &Value,
+        schema: Option<&'static FieldSchema>,
+        fields: Fields,
+        name: &str,
+    ) -> Result<()> {
+        match (schema, value) {
+            (_, Value::Null) => rmp_encode::write_nil(buf)?,
+            (Some(FieldSchema::Fields(fields)), _) => {
+                self.write_msgpack(buf, value, None, fields, name)?
+            }
+            (Some(Bytes), _) => rmp_encode::write_bin(buf, &self.json_to_bytes(value, name)?)?,
+            (Some(ByteString), Value::String(s)) if self.text_byte_strings => {
+                rmp_encode::write_str(buf, s)?
+            }
+            (Some(ByteString), _) => {
+                let bytes = self.json_to_bytes(value, name)?;
+                rmp_encode::write_str_len(buf, bytes.len() as u32)?;
+                buf.extend_from_slice(&bytes);
+            }
+            (Some(Embedded), _) => {
+                let bytes = self.json_to_bytes(value, name)?;
+                let mut reader = &bytes[..];
+                if rmpv::decode::read_value(&mut reader).is_err() || !reader.is_empty() {
+                    return Err(unexpected_value("non-msgpack", name));
+                }
+                buf.extend_from_slice(&bytes);
+            }
+            (Some(Array(item)), Value::Array(items)) => {
+                rmp_encode::write_array_len(buf, items.len() as u32)?;
+                for value in items {
+                    self.write_msgpack(buf, value, Some(item), fields, name)?;
+                }
+            }
+            (Some(Map(MapKey::Fields(key_fields), item)), Value::Array(entries)) => {
+                // Canonical msgpack sorts keys which aren't strings or integers by their encoding
+                let mut sorted = BTreeMap::new();
+                for entry in entries {
+                    let (Some(key), Some(value)) = (entry.get("key"), entry.get("value")) else {
+                        return Err(unexpected_value(json_kind(entry), name));
+                    };
+                    if entry.as_object().map(serde_json::Map::len) != Some(2) {
+                        return Err(unexpected_value("object", name));
+                    }
+                    let mut key_buf = Vec::new();
+                    self.write_msgpack(&mut key_buf, key, None, key_fields, name)?;
+                    if sorted.insert(key_buf, value).is_some() {
+                        return Err(unexpected_value("duplicate key", name));
+                    }
+                }
+                rmp_encode::write_map_len(buf, sorted.len() as u32)?;
+                for (key, value) in sorted {
+                    buf.extend_from_slice(&key);
+                    self.write_msgpack(buf, value, Some(item), fields, name)?;
+                }
+            }
+            (Some(Map(key_type, item)), Value::Object(entries)) => {
+                // Canonical msgpack sorts keys by their encoding rather than their JSON form
+                let mut sorted = BTreeMap::new();
+                for (key, value) in entries {
+                    let key = match key_type {
+                        MapKey::ByteString if self.text_byte_strings => {
+                            Some(SortKey::Bytes(key.as_bytes().to_vec()))
+                        }
+                        MapKey::Bytes | MapKey::ByteString => self.key_to_bytes(key),
+                        MapKey::Integer => parse_integer_key(key),
+                        MapKey::Fields(_) => None,
+                    }
+                    .ok_or_else(|| unexpected_value("key", name))?;
+                    sorted.insert(key, value);
+                }
+                rmp_encode::write_map_len(buf, sorted.len() as u32)?;
+                for (key, value) in sorted {
+                    match (key_type, key) {
+                        (MapKey::Bytes, SortKey::Bytes(bytes)) => {
+                            rmp_encode::write_bin(buf, &bytes)?
+                        }
+                        (_, SortKey::Bytes(bytes)) => {
+                            rmp_encode::write_str_len(buf, bytes.len() as u32)?;
+                            buf.extend_from_slice(&bytes);
+                        }
+                        (_, SortKey::Integer(i)) => match u64::try_from(i) {
+                            Ok(n) => rmp_encode::write_uint(buf, n).map(|_| ())?,
+                            Err(_) => rmp_encode::write_sint(buf, i as i64).map(|_| ())?,
+                        },
+                    }
+                    self.write_msgpack(buf, value, Some(item), fields, name)?;
+                }
+            }
+            (None, Value::Bool(b)) => rmp_encode::write_bool(buf, *b)?,
+            (None, Value::Number(n)) => {
+                if let Some(u) = n.as_u64() {
+                    rmp_encode::write_uint(buf, u)?;
+                } else if let Some(i) = n.as_i64() {
+                    rmp_encode::write_sint(buf, i)?;
+                } else {
+                    let f = n.as_f64().unwrap_or_default();
+                    // serde_json parses integers beyond 64 bits as floats, losing precision
+                    if f.fract() == 0.0 && f.abs() >= u64::MAX as f64 {
+                        return Err(unexpected_value("out of range integer", name));
+                    }
+                    rmp_encode::write_f64(buf, f)?;
+                }
+            }
+            (None, Value::String(s)) => rmp_encode::write_str(buf, s)?,
+            (None, Value::Array(items)) => {
+                rmp_encode::write_array_len(buf, items.len() as u32)?;
+                for value in items {
+                    self.write_msgpack(buf, value, None, fields, name)?;
+                }
+            }
+            (None, Value::Object(entries)) => {
+                rmp_encode::write_map_len(buf, entries.len() as u32)?;
+                for (key, value) in entries {
+                    rmp_encode::write_str(buf, key)?;
+                    self.write_msgpack(buf, value, field_schema(fields, key), fields, key)?;
+                }
+            }
+            (Some(_), _) => return Err(unexpected_value(json_kind(value), name)),
+        }
+        Ok(())
+    }
+
+    fn bytes_to_json(&self, bytes: &[u8]) -> Value {
+        match self.bytes_format {
+            BytesFormat::Base64 => Value::String(BASE64.encode(bytes)),
+            BytesFormat::Hex => Value::String(to_hex(bytes)),
+            BytesFormat::Array => Value::Array(bytes.iter().map(|&b| b.into()).collect()),
+        }
+    }
+
+    fn json_to_bytes(&self, value: &Value, name: &str) -> Result<Vec<u8>> {
+        let bytes = match (self.bytes_format, value) {
+            (BytesFormat::Base64, Value::String(s)) => BASE64.decode(s).ok(),
+            (BytesFormat::Hex, Value::String(s)) => from_hex(s),
+            (BytesFormat::Array, Value::Array(items)) => items
+                .iter()
+                .map(|item| item.as_u64().and_then(|b| u8::try_from(b).ok()))
+                .collect(),
+            _ => None,
+        };
+        bytes.ok_or_else(|| unexpected_value(json_kind(value), name))
+    }
+
+    fn key_to_json(&self, bytes: &[u8]) -> String {
+        match self.bytes_format {
+            BytesFormat::Hex => to_hex(bytes),
+            BytesFormat::Base64 | BytesFormat::Array => BASE64.encode(bytes),
+        }
+    }
+
+    fn key_to_bytes(&self, key: &str) -> Option<SortKey> {
+        match self.bytes_format {
+            BytesFormat::Hex => from_hex(key),
+            BytesFormat::Base64 | BytesFormat::Array => BASE64.decode(key).ok(),
+        }
+        .map(SortKey::Bytes)
+    }
+}
+
+/// A map key in the order of its canonical encoding.
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
+enum SortKey {
+    Integer(i128),
+    Bytes(Vec<u8>),
+}
+
+fn parse_integer_key(key: &str) -> Option<SortKey> {
+    let i: i128 = key.parse().ok()?;
+    (i64::MIN as i128..=u64::MAX as i128)
+        .contains(&i)
+        .then_some(SortKey::Integer(i))
+}
+
+fn field_schema(fields: Fields, key: &str) -> Option<&'static FieldSchema> {
+    fields
+        .iter()
+        .find(|(name, _)| *name == key)
+        .map(|(_, schema)| schema)
+}
+
+fn unexpected_value(kind: &str, name: &str) -> AlgoKitMsgPackError {
+    AlgoKitMsgPackError::SchemaError(format!("unexpected {} value in {}", kind, name))
+}
+
+fn value_kind(value: &rmpv::Value) -> &'static str {
+    match value {
+        rmpv::Value::Nil => "nil",
+        rmpv::Value::Boolean(_) => "boolean",
+        rmpv::Value::Integer(_) => "integer",
+        rmpv::Value::F32(_) => "32-bit float",
+        rmpv::Value::F64(_) => "float",
+        rmpv::Value::String(_) => "string",
+        rmpv::Value::Binary(_) => "binary",
+        rmpv::Value::Array(_) => "array",
+        rmpv::Value::Map(_) => "map",
+        rmpv::Value::Ext(_, _) => "extension",
+    }
+}
+
+fn json_kind(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    const DIGITS: &[u8; 16] = b"0123456789abcdef";
+    bytes
+        .iter()
+        .flat_map(|b| [DIGITS[(b >> 4) as usize], DIGITS[(b & 0xf) as usize]])
+        .map(char::from)
+        .collect()
+}
+
+fn from_hex(s: &str) -> Option<Vec<u8>> {
+    if s.len() % 2 != 0 || !s.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return None;
+    }
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).ok())
+        .collect()
+}
@@ -0,0 +1,381 @@
+//! Streaming decoding of msgpack, for blocks and responses too large to hold in memory at once.
+//!
+//! Reading a single byte at a time from an unbuffered reader (e.g. a file or socket) is slow, so
+//! readers and writers should be wrapped in a `BufReader` or `BufWriter`.
+
+use super::{
+    is_struct_key, json_key, rmpv_to_json, struct_key_entry, AlgoKitMsgPackError, Block, Result,
+    SignedTransactionInBlock,
+};
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use rmp::Marker;
+use serde_json::Value;
+use std::io::{Read, Write};
+
+/// The maximum nesting of arrays and maps, so malicious input can't overflow the stack.
+const MAX_DEPTH: usize = 256;
+
+/// Converts msgpack from a reader to JSON written to a writer, holding no more than a single
+/// string or binary value of the msgpack in memory.
+///
+/// The JSON is the same as that of [`super::decode_msgpack_to_json`] for models kept in their raw
+/// encoding, such as [`super::ModelType::Block`], with binary values base64 encoded. Object keys
+/// are written in the order of the msgpack, which is sorted for the canonical msgpack returned by
+/// algod.
+///
+/// # Parameters
+/// * `reader` - The msgpack to convert
+/// * `writer` - Where to write the JSON
+///
+/// # Returns
+/// `Ok(())` once the JSON is written, or an error if the msgpack is invalid or reading or writing
+/// fails.
+pub fn stream_msgpack_to_json<R: Read, W: Write>(mut reader: R, mut writer: W) -> Result<()> {
+    write_json(&mut reader, &mut writer, 0)?;
+    writer.flush()?;
+    Ok(())
+}
+
+/// Reads a msgpack block, as returned by algod, one transaction at a time.
+///
+/// Only the block header and a single transaction are held in memory. The header fields which
+/// precede the transactions in the msgpack are available from [`BlockReader::header`] as soon as
+/// the reader is created, and the remaining fields and the certificate from
+/// [`BlockReader::finish`].
+///
+/// ```
+/// # use algokit_transact::msgpack::BlockReader;
+/// # fn example(msgpack: &[u8]) -> Result<(), algokit_transact::AlgoKitMsgPackError> {
+/// let mut block_reader = BlockReader::new(msgpack)?;
+/// for transaction in block_reader.transactions() {
+///     let transaction = transaction?;
+///     println!("{:?}", transaction.signed_transaction.transaction);
+/// }
+/// let block = block_reader.finish()?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct BlockReader<R: Read> {
+    reader: R,
+    header: serde_json::Map<String, Value>,
+    cert: Option<Value>,
+    has_block: bool,
+    failed: bool,
+    remaining_root_fields: u32,
+    remaining_block_fields: u32,
+    remaining_transactions: u32,
+}
+
+impl<R: Read> BlockReader<R> {
+    /// Creates a reader of the msgpack block, reading it up to its transactions.
+    pub fn new(mut reader: R) -> Result<Self> {
+        let remaining_root_fields = read_map_len(&mut reader)?;
+        let mut block_reader = BlockReader {
+            reader,
+            header: serde_json::Map::new(),
+            cert: None,
+            has_block: false,
+            failed: false,
+            remaining_root_fields,
+            remaining_block_fields: 0,
+            remaining_transactions: 0,
+        };
+        block_reader.read_to_transactions()?;
+        Ok(block_reader)
+    }
+
+    /// The fields of the block header which have been read, excluding the transactions.
+    pub fn header(&self) -> &serde_json::Map<String, Value> {
+        &self.header
+    }
+
+    /// The number of transactions of the block which haven't been read.
+    pub fn remaining_transactions(&self) -> u32 {
+        self.remaining_transactions
+    }
+
+    /// Returns an iterator which reads the transactions of the block.
+    pub fn transactions(&mut self) -> BlockTransactions<'_, R> {
+        BlockTransactions { block_reader: self }
+    }
+
+    /// Reads the rest of the block, skipping any transactions which haven't been read.
+    ///
+    /// # Returns
+    /// The block, without its transactions, or an error if the msgpack isn't a valid block.
+    pub fn finish(mut self) -> Result<Block> {
+        if self.failed {
+            return Err(rmp_serde::decode::Error::Syntax(
+                "msgpack block couldn't be read past a transaction".to_string(),
+            )
+            .into());
+        }
+        loop {
+            while self.remaining_transactions > 0 {
+                self.remaining_transactions -= 1;
+                write_json(&mut self.reader, &mut std::io::sink(), 0)?;
+            }
+            self.read_to_transactions()?;
+            if self.remaining_block_fields == 0 && self.remaining_root_fields == 0 {
+                break;
+            }
+        }
+
+        if !self.has_block {
+            return Err(rmp_serde::decode::Error::Syntax(
+                "msgpack block has no block field".to_string(),
+            )
+            .into());
+        }
+        // Deserialized rather than constructed, so the block is checked against its model
+        let block = serde_json::json!({"block": self.header, "cert": self.cert});
+        Ok(serde_json::from_value(block)?)
+    }
+
+    /// Reads fields of the block until the start of its transactions or the end of the block.
+    fn read_to_transactions(&mut self) -> Result<()> {
+        let reader = &mut self.reader;
+        loop {
+            if self.remaining_block_fields > 0 {
+                self.remaining_block_fields -= 1;
+                let key = read_key(reader)?;
+                if key == "txns" {
+                    self.remaining_transactions = read_array_len(reader)?;
+                    return Ok(());
+                }
+                self.header.insert(key, read_value(reader)?);
+            } else if self.remaining_root_fields > 0 {
+                self.remaining_root_fields -= 1;
+                match read_key(reader)?.as_str() {
+                    "block" => {
+                        self.has_block = true;
+                        self.remaining_block_fields = read_map_len(reader)?;
+                    }
+                    "cert" => self.cert = Some(read_value(reader)?),
+                    _ => write_json(reader, &mut std::io::sink(), 0)?,
+                }
+            } else {
+                return Ok(());
+            }
+        }
+    }
+}
+
+/// An iterator over the transactions of a block, created by [`BlockReader::transactions`].
+pub struct BlockTransactions<'a, R: Read> {
+    block_reader: &'a mut BlockReader<R>,
+}
+
+impl<R: Read> Iterator for BlockTransactions<'_, R> {
+    type Item = Result<SignedTransactionInBlock>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let block_reader = &mut *self.block_reader;
+        if block_reader.remaining_transactions == 0 {
+            return None;
+        }
+
+        block_reader.remaining_transactions -= 1;
+        let transaction = match read_value(&mut block_reader.reader) {
+            Ok(transaction) => transaction,
+            Err(error) => {
+                // The position in the msgpack is unknown, so nothing more can be read
+                block_reader.remaining_transactions = 0;
+                block_reader.failed = true;
+                return Some(Err(error));
+            }
+        };
+        Some(serde_json::from_value(transaction).map_err(Into::into))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.block_reader.remaining_transactions as usize;
+        (0, Some(remaining))
+    }
+}
+
+fn read_value<R: Read>(reader: &mut R) -> Result<Value> {
+    Ok(rmpv_to_json(&read_raw_value(reader)?))
+}
+
+fn read_key<R: Read>(reader: &mut R) -> Result<String> {
+    Ok(json_key(&read_raw_value(reader)?))
+}
+
+fn read_raw_value<R: Read>(reader: &mut R) -> Result<rmpv::Value> {
+    rmpv::decode::read_value(reader).map_err(|e| AlgoKitMsgPackError::IoError(e.to_string()))
+}
+
+fn read_map_len<R: Read>(reader: &mut R) -> Result<u32> {
+    match read_marker(reader)? {
+        Marker::FixMap(len) => Ok(len.into()),
+        Marker::Map16 => Ok(u16::from_be_bytes(read_bytes(reader)?).into()),
+        Marker::Map32 => Ok(u32::from_be_bytes(read_bytes(reader)?)),
+        marker => Err(unexpected_marker(marker, "a map")),
+    }
+}
+
+fn read_array_len<R: Read>(reader: &mut R) -> Result<u32> {
+    match read_marker(reader)? {
+        Marker::FixArray(len) => Ok(len.into()),
+        Marker::Array16 => Ok(u16::from_be_bytes(read_bytes(reader)?).into()),
+        Marker::Array32 => Ok(u32::from_be_bytes(read_bytes(reader)?)),
+        marker => Err(unexpected_marker(marker, "an array")),
+    }
+}
+
+/// Reads a msgpack value and writes it as JSON, rendered as by `rmpv_to_json`.
+fn write_json<R: Read, W: Write>(reader: &mut R, writer: &mut W, depth: usize) -> Result<()> {
+    if depth > MAX_DEPTH {
+        return Err(rmp_serde::decode::Error::DepthLimitExceeded.into());
+    }
+
+    let marker = read_marker(reader)?;
+    match marker {
+        Marker::Null => writer.write_all(b"null")?,
+        Marker::True => writer.write_all(b"true")?,
+        Marker::False => writer.write_all(b"false")?,
+        Marker::FixPos(n) => serde_json::to_writer(&mut *writer, &n)?,
+        Marker::FixNeg(n) => serde_json::to_writer(&mut *writer, &n)?,
+        Marker::U8 => serde_json::to_writer(&mut *writer, &u8::from_be_bytes(read_bytes(reader)?))?,
+        Marker::U16 => {
+            serde_json::to_writer(&mut *writer, &u16::from_be_bytes(read_bytes(reader)?))?
+        }
+        Marker::U32 => {
+            serde_json::to_writer(&mut *writer, &u32::from_be_bytes(read_bytes(reader)?))?
+        }
+        Marker::U64 => {
+            serde_json::to_writer(&mut *writer, &u64::from_be_bytes(read_bytes(reader)?))?
+        }
+        Marker::I8 => serde_json::to_writer(&mut *writer, &i8::from_be_bytes(read_bytes(reader)?))?,
+        Marker::I16 => {
+            serde_json::to_writer(&mut *writer, &i16::from_be_bytes(read_bytes(reader)?))?
+        }
+        Marker::I32 => {
+            serde_json::to_writer(&mut *writer, &i32::from_be_bytes(read_bytes(reader)?))?
+        }
+        Marker::I64 => {
+            serde_json::to_writer(&mut *writer, &i64::from_be_bytes(read_bytes(reader)?))?
+        }
+        // JSON has no representation of NaN or infinite numbers, so these are written as null
+        Marker::F32 => {
+            let f = f32::from_be_bytes(read_bytes(reader)?) as f64;
+            serde_json::to_writer(&mut *writer, &serde_json::Number::from_f64(f))?
+        }
+        Marker::F64 => {
+            let f = f64::from_be_bytes(read_bytes(reader)?);
+            serde_json::to_writer(&mut *writer, &serde_json::Number::from_f64(f))?
+        }
+        Marker::FixStr(_) | Marker::Str8 | Marker::Str16 | Marker::Str32 => {
+            let len = read_len(reader, marker)?;
+            let bytes = read_vec(reader, len)?;
+            let s = std::str::from_utf8(&bytes).unwrap_or_default();
+            serde_json::to_writer(&mut *writer, s)?
+        }
+        Marker::Bin8 | Marker::Bin16 | Marker::Bin32 => {
+            let len = read_len(reader, marker)?;
+            let bytes = read_vec(reader, len)?;
+            serde_json::to_writer(&mut *writer, &BASE64.encode(bytes))?
+        }
+        Marker::FixExt1
+        | Marker::FixExt2
+        | Marker::FixExt4
+        | Marker::FixExt8
+        | Marker::FixExt16
+        | Marker::Ext8
+        | Marker::Ext16
+        | Marker::Ext32 => {
+            let len = read_len(reader, marker)?;
+            let [_ext_type] = read_bytes(reader)?;
+            let bytes = read_vec(reader, len)?;
+            serde_json::to_writer(&mut *writer, &BASE64.encode(bytes))?
+        }
+        Marker::FixArray(_) | Marker::Array16 | Marker::Array32 => {
+            let len = read_len(reader, marker)?;
+            writer.write_all(b"[")?;
+            for i in 0..len {
+                if i > 0 {
+                    writer.write_all(b",")?;
+                }
+                write_json(reader, writer, depth + 1)?;
+            }
+            writer.write_all(b"]")?;
+        }
+        Marker::FixMap(_) | Marker::Map16 | Marker::Map32 => {
+            let len = read_len(reader, marker)?;
+            let mut struct_keys = false;
+            for i in 0..len {
+                let key = read_raw_value(reader)?;
+                if i == 0 {
+                    struct_keys = is_struct_key(&key);
+                    writer.write_all(if struct_keys { b"[" } else { b"{" })?;
+                } else {
+                    writer.write_all(b",")?;
+                }
+                if struct_keys {
+                    // Maps with struct keys are rendered as an array of key and value entries
+                    let value = read_raw_value(reader)?;
+                    let entry = struct_key_entry(rmpv_to_json(&key), rmpv_to_json(&value));
+                    serde_json::to_writer(&mut *writer, &entry)?;
+                } else {
+                    serde_json::to_writer(&mut *writer, &json_key(&key))?;
+                    writer.write_all(b":")?;
+                    write_json(reader, writer, depth + 1)?;
+                }
+            }
+            match (len, struct_keys) {
+                (0, _) => writer.write_all(b"{}")?,
+                (_, true) => writer.write_all(b"]")?,
+                (_, false) => writer.write_all(b"}")?,
+            }
+        }
+        Marker::Reserved => return Err(unexpected_marker(marker, "a value")),
+    }
+    Ok(())
+}
+
+/// Reads the length of a string, binary, extension, array or map from after its marker.
+fn read_len<R: Read>(reader: &mut R, marker: Marker) -> Result<u32> {
+    Ok(match marker {
+        Marker::FixStr(len) | Marker::FixArray(len) | Marker::FixMap(len) => len.into(),
+        Marker::FixExt1 => 1,
+        Marker::FixExt2 => 2,
+        Marker::FixExt4 => 4,
+        Marker::FixExt8 => 8,
+        Marker::FixExt16 => 16,
+        Marker::Str8 | Marker::Bin8 | Marker::Ext8 => u8::from_be_bytes(read_bytes(reader)?).into(),
+        Marker::Str16 | Marker::Bin16 | Marker::Ext16 | Marker::Array16 | Marker::Map16 => {
+            u16::from_be_bytes(read_bytes(reader)?).into()
+        }
+        Marker::Str32 | Marker::Bin32 | Marker::Ext32 | Marker::Array32 | Marker::Map32 => {
+            u32::from_be_bytes(read_bytes(reader)?)
+        }
+        marker => return Err(unexpected_marker(marker, "a length")),
+    })
+}
+
+fn read_marker<R: Read>(reader: &mut R) -> Result<Marker> {
+    let [byte] = read_bytes(reader)?;
+    Ok(Marker::from_u8(byte))
+}
+
+fn read_bytes<R: Read, const N: usize>(reader: &mut R) -> Result<[u8; N]> {
+    let mut bytes = [0; N];
+    reader.read_exact(&mut bytes)?;
+    Ok(bytes)
+}
+
+/// Reads `len` bytes without allocating them up front, as the length may be corrupt.
+fn read_vec<R: Read>(reader: &mut R, len: u32) -> Result<Vec<u8>> {
+    let mut bytes = Vec::new();
+    reader.take(len.into()).read_to_end(&mut bytes)?;
+    if bytes.len() != len as usize {
+        return Err(std::io::Error::from(std::io::ErrorKind::UnexpectedEof).into());
+    }
+    Ok(bytes)
+}
+
+fn unexpected_marker(marker: Marker, expected: &str) -> AlgoKitMsgPackError {
+    rmp_serde::decode::Error::Syntax(format!("expected {} but found {:?}", expected, marker)).into()
+}
@@ -0,0 +1,228 @@
+//! Multisignature support for Algorand transactions.
+//!
+//! This module provides the [`MultisigSignature`] type which is attached to a signed
+//! transaction when the sender is a multisig account, along with helpers to apply and merge
+//! the signatures of the individual participants.
+
+use crate::address::Address;
+use crate::constants::{ALGORAND_SIGNATURE_BYTE_LENGTH, MULTISIG_VERSION};
+use crate::error::AlgoKitTransactError;
+use crate::transactions::UnknownFields;
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, Bytes};
+
+/// A signature of a single participant of a multisig account.
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct MultisigSubsignature {
+    /// The address of the participant.
+    #[serde(rename = "pk")]
+    pub address: Address,
+
+    /// The participant's Ed25519 signature, if they have signed.
+    #[serde(rename = "s")]
+    #[serde_as(as = "Option<Bytes>")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub signature: Option<[u8; ALGORAND_SIGNATURE_BYTE_LENGTH]>,
+}
+
+/// The signature of a multisig account.
+///
+/// Contains the multisig account parameters and the (possibly partial) set of participant
+/// signatures. The transaction is authorized once at least `threshold` participants have signed.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct MultisigSignature {
+    /// The multisig version.
+    #[serde(rename = "v")]
+    pub version: u8,
+
+    /// The number of participant signatures required to authorize a transaction.
+    #[serde(rename = "thr")]
+    pub threshold: u8,
+
+    /// The participant signatures, in the order the participants were specified when the
+    /// multisig account was created.
+    #[serde(rename = "subsig")]
+    pub subsignatures: Vec<MultisigSubsignature>,
+
+    /// Fields of the multisig signature which are not recognised by this crate.
+    #[serde(flatten)]
+    #[serde(skip_serializing_if = "UnknownFields::is_empty")]
+    #[serde(default)]
+    pub unknown_fields: UnknownFields,
+}
+
+impl MultisigSignature {
+    /// Creates an unsigned multisig signature for the supplied multisig account parameters.
+    ///
+    /// # Parameters
+    /// * `version` - The multisig version, which must currently be 1
+    /// * `threshold` - The number of participant signatures required
+    /// * `participants` - The addresses of the participants, in order
+    ///
+    /// # Returns
+    /// The multisig signature or an error if the parameters are invalid.
+    pub fn from_participants(
+        version: u8,
+        threshold: u8,
+        participants: Vec<Address>,
+    ) -> Result<Self, AlgoKitTransactError> {
+        validate_multisig_params(version, threshold, participants.len())?;
+
+        Ok(Self {
+            version,
+            threshold,
+            subsignatures: participants
+                .into_iter()
+                .map(|address| MultisigSubsignature {
+                    address,
+                    signature: None,
+                })
+                .collect(),
+            unknown_fields: UnknownFields::new(),
+        })
+    }
+
+    /// Returns the addresses of the participants, in order.
+    pub fn participants(&self) -> Vec<Address> {
+        self.subsignatures
+            .iter()
+            .map(|subsig| subsig.address.clone())
+            .collect()
+    }
+
+    /// Returns the address of the multisig account.
+    ///
+    /// # Returns
+    /// The multisig address or an error if the multisig parameters are invalid.
+    pub fn address(&self) -> Result<Address, AlgoKitTransactError> {
+        Address::from_multisig(self.version, self.threshold, &self.participants())
+    }
+
+    /// Returns true if at least `threshold` participants have signed.
+    pub fn is_fully_signed(&self) -> bool {
+        self.subsignatures
+            .iter()
+            .filter(|subsig| subsig.signature.is_some())
+            .count()
+            >= self.threshold as usize
+    }
+
+    /// Applies a participant's signature.
+    ///
+    /// If the participant appears more than once in the multisig account, the signature is
+    /// applied to every occurrence.
+    ///
+    /// # Parameters
+    /// * `participant` - The address of the participant which signed
+    /// * `signature` - The participant's Ed25519 signature of the transaction
+    ///
+    /// # Returns
+    /// The multisig signature with the signature applied or an error if the address is not a
+    /// participant of the multisig account.
+    pub fn apply_subsignature(
+        &self,
+        participant: &Address,
+        signature: [u8; ALGORAND_SIGNATURE_BYTE_LENGTH],
+    ) -> Result<Self, AlgoKitTransactError> {
+        let mut found = false;
+        let subsignatures = self
+            .subsignatures
+            .iter()
+            .map(|subsig| {
+                if &subsig.address == participant {
+                    found = true;
+                    MultisigSubsignature {
+                        address: subsig.address.clone(),
+                        signature: Some(signature),
+                    }
+                } else {
+                    subsig.clone()
+                }
+            })
+            .collect();
+
+        if !found {
+            return Err(AlgoKitTransactError::InputError(format!(
+                "Address {} is not a participant of the multisig account",
+                participant
+            )));
+        }
+
+        Ok(Self {
+            subsignatures,
+            ..self.clone()
+        })
+    }
+
+    /// Merges the participant signatures of another multisig signature into this one.
+    ///
+    /// # Parameters
+    /// * `other` - A multisig signature for the same multisig account
+    ///
+    /// # Returns
+    /// The merged multisig signature or an error if the multisig accounts differ or both
+    /// contain a different signature from the same participant.
+    pub fn merge(&self, other: &Self) -> Result<Self, AlgoKitTransactError> {
+        if self.version != other.version
+            || self.threshold != other.threshold
+            || self.participants() != other.participants()
+        {
+            return Err(AlgoKitTransactError::InputError(
+                "Multisig signatures are for different multisig accounts".to_string(),
+            ));
+        }
+
+        let subsignatures = self
+            .subsignatures
+            .iter()
+            .zip(&other.subsignatures)
+            .map(|(subsig, other_subsig)| {
+                let signature = match (subsig.signature, other_subsig.signature) {
+                    (Some(a), Some(b)) if a != b => {
+                        return Err(AlgoKitTransactError::InputError(format!(
+                            "Multisig signatures contain conflicting signatures for participant {}",
+                            subsig.address
+                        )));
+                    }
+                    (a, b) => a.or(b),
+                };
+                Ok(MultisigSubsignature {
+                    address: subsig.address.clone(),
+                    signature,
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self {
+            subsignatures,
+            ..self.clone()
+        })
+    }
+}
+
+pub(crate) fn validate_multisig_params(
+    version: u8,
+    threshold: u8,
+    num_participants: usize,
+) -> Result<(), AlgoKitTransactError> {
+    if version != MULTISIG_VERSION {
+        return Err(AlgoKitTransactError::InputError(format!(
+            "Unsupported multisig version {}",
+            version
+        )));
+    }
+    if num_participants == 0 || num_participants > u8::MAX as usize {
+        return Err(AlgoKitTransactError::InputError(format!(
+            "Multisig accounts must have between 1 and {} participants",
+            u8::MAX
+        )));
+    }
+    if threshold == 0 || threshold as usize > num_participants {
+        return Err(AlgoKitTransactError::InputError(
+            "Multisig threshold must be between 1 and the number of participants".to_string(),
+        ));
+    }
+    Ok(())
+}
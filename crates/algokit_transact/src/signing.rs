@@ -0,0 +1,207 @@
+//! Ed25519 signing and signature verification for Algorand transactions.
+//!
+//! This module is only available when the `signing` feature is enabled. It provides the
+//! [`TransactionSigner`] trait, which allows keys held outside of the process (e.g. in an HSM
+//! or KMS) to sign transactions, along with an implementation for in-memory Ed25519 keys.
+
+use crate::address::Address;
+use crate::constants::ALGORAND_SIGNATURE_BYTE_LENGTH;
+use crate::error::AlgoKitTransactError;
+use crate::logic_signature::LogicSignature;
+use crate::multisig::MultisigSignature;
+use crate::traits::AlgorandMsgpack;
+use crate::transactions::{SignedTransaction, Transaction};
+use ed25519_dalek::{Signature, Signer, SigningKey, VerifyingKey};
+
+/// A signer which can produce Ed25519 signatures for an Algorand account.
+pub trait TransactionSigner {
+    /// Returns the address of the account whose key produces the signatures.
+    fn address(&self) -> Address;
+
+    /// Signs the supplied bytes.
+    ///
+    /// # Parameters
+    /// * `bytes` - The bytes to sign, including any domain separation prefix
+    ///
+    /// # Returns
+    /// The 64-byte Ed25519 signature or an error if signing fails.
+    fn sign_bytes(
+        &self,
+        bytes: &[u8],
+    ) -> Result<[u8; ALGORAND_SIGNATURE_BYTE_LENGTH], AlgoKitTransactError>;
+}
+
+impl TransactionSigner for SigningKey {
+    fn address(&self) -> Address {
+        Address::from_pubkey(&self.verifying_key().to_bytes())
+    }
+
+    fn sign_bytes(
+        &self,
+        bytes: &[u8],
+    ) -> Result<[u8; ALGORAND_SIGNATURE_BYTE_LENGTH], AlgoKitTransactError> {
+        Ok(self.sign(bytes).to_bytes())
+    }
+}
+
+impl Transaction {
+    /// Signs the transaction.
+    ///
+    /// If the signer is not the sender of the transaction (i.e. the sender account has been
+    /// rekeyed to the signer), the auth address of the signed transaction is set to the signer.
+    ///
+    /// # Parameters
+    /// * `signer` - The signer of the transaction
+    ///
+    /// # Returns
+    /// The signed transaction or an error if encoding or signing fails.
+    pub fn sign<S: TransactionSigner + ?Sized>(
+        &self,
+        signer: &S,
+    ) -> Result<SignedTransaction, AlgoKitTransactError> {
+        let signature = signer.sign_bytes(&self.encode()?)?;
+        let signer_address = signer.address();
+
+        Ok(SignedTransaction {
+            transaction: self.clone(),
+            signature: Some(signature),
+            auth_address: (signer_address != self.header().sender).then_some(signer_address),
+            multisignature: None,
+            logic_signature: None,
+            unknown_fields: Default::default(),
+        })
+    }
+}
+
+impl SignedTransaction {
+    /// Verifies the signature of the transaction.
+    ///
+    /// The signature is checked against the auth address if set, otherwise the sender.
+    ///
+    /// - Single signatures are verified against the authorizing account.
+    /// - Multisig signatures must be for the authorizing account and contain at least
+    ///   `threshold` valid participant signatures.
+    /// - Delegated logic signatures must have a valid signature of the program by the
+    ///   authorizing account. Escrow logic signatures must be for a program whose address is
+    ///   the authorizing account.
+    ///
+    /// The program of a logic signature is not evaluated, so a successful verification only
+    /// means the program is allowed to authorize the transaction.
+    ///
+    /// # Returns
+    /// `Ok(())` if the signature is valid or an error describing why it is not.
+    pub fn verify(&self) -> Result<(), AlgoKitTransactError> {
+        let authorizer = self
+            .auth_address
+            .as_ref()
+            .unwrap_or(&self.transaction.header().sender);
+
+        match (&self.signature, &self.multisignature, &self.logic_signature) {
+            (Some(signature), None, None) => {
+                verify_signature(authorizer, &self.transaction.encode()?, signature)
+            }
+            (None, Some(multisig), None) => {
+                verify_multisig(authorizer, &self.transaction.encode()?, multisig)
+            }
+            (None, None, Some(logic_signature)) => {
+                verify_logic_signature(authorizer, logic_signature)
+            }
+            (None, None, None) => Err(AlgoKitTransactError::InvalidSignature(
+                "Transaction is not signed".to_string(),
+            )),
+            _ => Err(AlgoKitTransactError::InvalidSignature(
+                "Only one of a signature, multisig signature or logic signature may be set"
+                    .to_string(),
+            )),
+        }
+    }
+}
+
+fn verify_signature(
+    signer: &Address,
+    message: &[u8],
+    signature: &[u8; ALGORAND_SIGNATURE_BYTE_LENGTH],
+) -> Result<(), AlgoKitTransactError> {
+    let verifying_key = VerifyingKey::from_bytes(&signer.pub_key).map_err(|_| {
+        AlgoKitTransactError::InvalidSignature(format!(
+            "Address {} is not a valid Ed25519 public key",
+            signer
+        ))
+    })?;
+
+    verifying_key
+        .verify_strict(message, &Signature::from_bytes(signature))
+        .map_err(|_| {
+            AlgoKitTransactError::InvalidSignature(format!(
+                "Signature is not valid for address {}",
+                signer
+            ))
+        })
+}
+
+fn verify_multisig(
+    authorizer: &Address,
+    message: &[u8],
+    multisig: &MultisigSignature,
+) -> Result<(), AlgoKitTransactError> {
+    if &multisig.address()? != authorizer {
+        return Err(AlgoKitTransactError::InvalidSignature(format!(
+            "Multisig signature is not for address {}",
+            authorizer
+        )));
+    }
+
+    let mut valid_signatures = 0;
+    for subsig in &multisig.subsignatures {
+        if let Some(signature) = &subsig.signature {
+            verify_signature(&subsig.address, message, signature)?;
+            valid_signatures += 1;
+        }
+    }
+
+    if valid_signatures < multisig.threshold as usize {
+        return Err(AlgoKitTransactError::InvalidSignature(format!(
+            "Multisig signature has {} of the {} required signatures",
+            valid_signatures, multisig.threshold
+        )));
+    }
+
+    Ok(())
+}
+
+fn verify_logic_signature(
+    authorizer: &Address,
+    logic_signature: &LogicSignature,
+) -> Result<(), AlgoKitTransactError> {
+    match (
+        &logic_signature.signature,
+        &logic_signature.multisignature,
+        &logic_signature.logic_multisignature,
+    ) {
+        (Some(signature), None, None) => {
+            verify_signature(authorizer, &logic_signature.bytes_to_sign(), signature)
+        }
+        (None, Some(multisig), None) => {
+            verify_multisig(authorizer, &logic_signature.bytes_to_sign(), multisig)
+        }
+        (None, None, Some(multisig)) => verify_multisig(
+            authorizer,
+            &logic_signature.multisig_bytes_to_sign(authorizer),
+            multisig,
+        ),
+        (None, None, None) => {
+            if &logic_signature.address() != authorizer {
+                return Err(AlgoKitTransactError::InvalidSignature(format!(
+                    "Logic signature program is not for address {}",
+                    authorizer
+                )));
+            }
+            Ok(())
+        }
+        _ => Err(AlgoKitTransactError::InvalidSignature(
+            "Logic signature may only have one of a signature, multisig signature or logic multisig \
+             signature"
+                .to_string(),
+        )),
+    }
+}
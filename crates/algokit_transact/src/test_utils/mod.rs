@@ -1,11 +1,19 @@
 use crate::{
-    transactions::{AssetTransferTransactionBuilder, PaymentTransactionBuilder},
-    Address, AlgorandMsgpack, Byte32, SignedTransaction, Transaction, TransactionHeaderBuilder,
-    TransactionId, ALGORAND_PUBLIC_KEY_BYTE_LENGTH, HASH_BYTES_LENGTH,
+    transactions::{
+        ApplicationCallTransactionBuilder, AssetConfigTransactionBuilder,
+        AssetFreezeTransactionBuilder, AssetTransferTransactionBuilder,
+        KeyRegistrationTransactionBuilder, PaymentTransactionBuilder,
+    },
+    Address, AlgorandMsgpack, AssetParams, BoxReference, Byte32, FalconVerifier, HashFactory,
+    HeartbeatParams, HeartbeatProof, HeartbeatTransactionFields, MerkleArrayProof, MerkleSignature,
+    MerkleSignatureVerifier, OnApplicationComplete, Participant, Reveal, SignedTransaction,
+    SigslotCommit, StateProof, StateProofMessage, StateProofTransactionFields, StateSchema,
+    Transaction, TransactionHeader, TransactionHeaderBuilder, TransactionId,
+    ALGORAND_PUBLIC_KEY_BYTE_LENGTH, HASH_BYTES_LENGTH,
 };
 use base64::{prelude::BASE64_STANDARD, Engine};
 use convert_case::{Case, Casing};
-use ed25519_dalek::{Signer, SigningKey};
+use ed25519_dalek::SigningKey;
 use serde::Serialize;
 use serde_json::to_writer_pretty;
 use std::{fs::File, str::FromStr};
@@ -94,6 +102,229 @@ impl TransactionMother {
             .receiver(AddressMother::neil())
             .to_owned()
     }
+
+    pub fn asset_create() -> AssetConfigTransactionBuilder {
+        AssetConfigTransactionBuilder::default()
+            .header(TransactionHeaderMother::simple_testnet().build().unwrap())
+            .params(AssetParams {
+                total: Some(1_000_000_000),
+                decimals: Some(6),
+                default_frozen: None,
+                unit_name: Some(b"TEST".to_vec()),
+                asset_name: Some(b"Test Asset".to_vec()),
+                url: Some(b"https://example.com".to_vec()),
+                metadata_hash: None,
+                manager: Some(AddressMother::address()),
+                reserve: Some(AddressMother::address()),
+                freeze: Some(AddressMother::neil()),
+                clawback: Some(AddressMother::neil()),
+                unknown_fields: Default::default(),
+            })
+            .to_owned()
+    }
+
+    pub fn asset_reconfigure() -> AssetConfigTransactionBuilder {
+        AssetConfigTransactionBuilder::default()
+            .header(TransactionHeaderMother::simple_testnet().build().unwrap())
+            .asset_id(107686045)
+            .params(AssetParams {
+                manager: Some(AddressMother::neil()),
+                reserve: Some(AddressMother::address()),
+                ..Default::default()
+            })
+            .to_owned()
+    }
+
+    pub fn asset_destroy() -> AssetConfigTransactionBuilder {
+        AssetConfigTransactionBuilder::default()
+            .header(TransactionHeaderMother::simple_testnet().build().unwrap())
+            .asset_id(107686045)
+            .to_owned()
+    }
+
+    pub fn asset_freeze() -> AssetFreezeTransactionBuilder {
+        AssetFreezeTransactionBuilder::default()
+            .header(TransactionHeaderMother::simple_testnet().build().unwrap())
+            .asset_id(107686045)
+            .freeze_target(AddressMother::neil())
+            .frozen(true)
+            .to_owned()
+    }
+
+    pub fn application_create() -> ApplicationCallTransactionBuilder {
+        ApplicationCallTransactionBuilder::default()
+            .header(TransactionHeaderMother::simple_testnet().build().unwrap())
+            .app_id(0)
+            .approval_program(BASE64_STANDARD.decode("CoEBQw==").unwrap())
+            .clear_state_program(BASE64_STANDARD.decode("CoEB").unwrap())
+            .global_state_schema(StateSchema {
+                num_uints: 1,
+                num_byte_slices: 2,
+                unknown_fields: Default::default(),
+            })
+            .local_state_schema(StateSchema {
+                num_uints: 3,
+                num_byte_slices: 0,
+                unknown_fields: Default::default(),
+            })
+            .extra_program_pages(1)
+            .to_owned()
+    }
+
+    pub fn application_call() -> ApplicationCallTransactionBuilder {
+        ApplicationCallTransactionBuilder::default()
+            .header(TransactionHeaderMother::simple_testnet().build().unwrap())
+            .app_id(718348254)
+            .on_complete(OnApplicationComplete::NoOp)
+            .args(vec![
+                BASE64_STANDARD.decode("Aq7OEQ==").unwrap(),
+                b"hello".to_vec(),
+            ])
+            .account_references(vec![AddressMother::neil()])
+            .app_references(vec![718348253])
+            .asset_references(vec![107686045])
+            .box_references(vec![
+                BoxReference {
+                    app_id: 0,
+                    name: b"box".to_vec(),
+                    unknown_fields: Default::default(),
+                },
+                BoxReference {
+                    app_id: 718348253,
+                    name: b"other".to_vec(),
+                    unknown_fields: Default::default(),
+                },
+            ])
+            .to_owned()
+    }
+
+    pub fn online_key_registration() -> KeyRegistrationTransactionBuilder {
+        KeyRegistrationTransactionBuilder::default()
+            .header(TransactionHeaderMother::simple_testnet().build().unwrap())
+            .vote_key(
+                BASE64_STANDARD
+                    .decode("m0Fsoh4lcScMCokAOKir6Ypxw/7d/TVOypxn0RoQB50=")
+                    .unwrap()
+                    .try_into()
+                    .unwrap(),
+            )
+            .selection_key(
+                BASE64_STANDARD
+                    .decode("CWvM27jAXjxllleVmdvk29qkEZkWfirhbBeRnKH7ieA=")
+                    .unwrap()
+                    .try_into()
+                    .unwrap(),
+            )
+            .state_proof_key(
+                BASE64_STANDARD
+                    .decode("MYC5kg7vhuUNcfahzPUxXZfK7r/GnwtwnRlNSWkkBNcMAL3C8F5Fl1k3lkZoDr/3YDx0PCogNVTtr6csHAl7ow==")
+                    .unwrap()
+                    .try_into()
+                    .unwrap(),
+            )
+            .vote_first(50659540)
+            .vote_last(53659540)
+            .vote_key_dilution(1733)
+            .to_owned()
+    }
+
+    pub fn offline_key_registration() -> KeyRegistrationTransactionBuilder {
+        KeyRegistrationTransactionBuilder::default()
+            .header(TransactionHeaderMother::simple_testnet().build().unwrap())
+            .to_owned()
+    }
+
+    pub fn non_participation_key_registration() -> KeyRegistrationTransactionBuilder {
+        KeyRegistrationTransactionBuilder::default()
+            .header(TransactionHeaderMother::simple_testnet().build().unwrap())
+            .non_participation(true)
+            .to_owned()
+    }
+
+    pub fn state_proof() -> Transaction {
+        let reveal = |position: u8| Reveal {
+            sigslot: SigslotCommit {
+                signature: MerkleSignature {
+                    signature: vec![position; 16],
+                    vector_commitment_index: 1026,
+                    proof: MerkleArrayProof {
+                        path: vec![vec![position; 32], vec![position + 1; 32]],
+                        hash_factory: HashFactory { hash_type: 1 },
+                        tree_depth: 2,
+                    },
+                    verifying_key: FalconVerifier {
+                        public_key: vec![position; 1793],
+                    },
+                },
+                lower_sig_weight: position as u64 * 1_000_000,
+            },
+            participant: Participant {
+                verifier: MerkleSignatureVerifier {
+                    commitment: [position; 64],
+                    key_lifetime: 256,
+                },
+                weight: 1_000_000,
+            },
+        };
+
+        Transaction::StateProof(StateProofTransactionFields {
+            // State proof transactions are free and don't include a genesis ID
+            header: TransactionHeader {
+                fee: None,
+                genesis_id: None,
+                ..TransactionHeaderMother::testnet()
+                    .sender(AddressMother::state_proof_sender())
+                    .first_valid(50659540)
+                    .last_valid(50660540)
+                    .build()
+                    .unwrap()
+            },
+            state_proof_type: 0,
+            state_proof: StateProof {
+                sig_commit: vec![1; 32],
+                signed_weight: 2_000_000,
+                sig_proofs: MerkleArrayProof {
+                    path: vec![vec![2; 32]],
+                    hash_factory: HashFactory { hash_type: 1 },
+                    tree_depth: 4,
+                },
+                part_proofs: MerkleArrayProof {
+                    path: vec![vec![3; 32]],
+                    hash_factory: HashFactory { hash_type: 1 },
+                    tree_depth: 4,
+                },
+                merkle_signature_salt_version: 0,
+                reveals: [(3, reveal(3)), (12, reveal(12))].into_iter().collect(),
+                positions_to_reveal: vec![12, 3],
+            },
+            message: StateProofMessage {
+                block_headers_commitment: vec![4; 32],
+                voters_commitment: vec![5; 32],
+                ln_proven_weight: 2_000_000,
+                first_attested_round: 50658049,
+                last_attested_round: 50658304,
+            },
+        })
+    }
+
+    pub fn heartbeat() -> Transaction {
+        Transaction::Heartbeat(HeartbeatTransactionFields {
+            header: TransactionHeaderMother::simple_testnet().build().unwrap(),
+            params: HeartbeatParams {
+                address: AddressMother::neil(),
+                proof: HeartbeatProof {
+                    signature: [1; 64],
+                    public_key: [2; 32],
+                    public_key_2: [3; 32],
+                    public_key_1_signature: [4; 64],
+                    public_key_2_signature: [5; 64],
+                },
+                seed: [6; 32],
+                vote_id: [7; 32],
+                key_dilution: 1733,
+            },
+        })
+    }
 }
 
 pub struct AddressMother {}
@@ -108,6 +339,12 @@ impl AddressMother {
             .unwrap()
     }
 
+    pub fn state_proof_sender() -> Address {
+        "XM6FEYVJ2XDU2IBH4OT6VZGW75YM63CM4TC6AV6BD3JZXFJUIICYTVB5EU"
+            .parse::<Address>()
+            .unwrap()
+    }
+
     pub fn neil() -> Address {
         "JB3K6HTAXODO4THESLNYTSG6GQUFNEVIQG7A6ZYVDACR6WA3ZF52TKU5NA"
             .parse::<Address>()
@@ -115,6 +352,17 @@ impl AddressMother {
     }
 }
 
+pub struct AppSpecMother {}
+impl AppSpecMother {
+    pub fn arc56_json() -> &'static str {
+        include_str!("app_specs/arc56.json")
+    }
+
+    pub fn arc32_json() -> &'static str {
+        include_str!("app_specs/arc32.json")
+    }
+}
+
 pub struct TransactionGroupMother {}
 impl TransactionGroupMother {
     pub fn testnet_payment_group() -> Vec<Transaction> {
@@ -199,11 +447,9 @@ impl TransactionTestData {
         let id: String = transaction.id().unwrap();
         let id_raw: [u8; HASH_BYTES_LENGTH] = transaction.id_raw().unwrap();
         let unsigned_bytes = transaction.encode().unwrap();
-        let signature = signing_key.sign(&unsigned_bytes);
         let signed_txn = SignedTransaction {
-            transaction: transaction.clone(),
-            signature: Some(signature.to_bytes()),
             auth_address: None,
+            ..transaction.sign(&signing_key).unwrap()
         };
         let signed_bytes = signed_txn.encode().unwrap();
 
@@ -212,8 +458,11 @@ impl TransactionTestData {
                 .unwrap();
         let signer_signed_txn = SignedTransaction {
             transaction: transaction.clone(),
-            signature: Some(signature.to_bytes()),
+            signature: signed_txn.signature,
             auth_address: Some(rekeyed_sender_auth_address.clone()),
+            multisignature: None,
+            logic_signature: None,
+            unknown_fields: Default::default(),
         };
         let rekeyed_sender_signed_bytes = signer_signed_txn.encode().unwrap();
 
@@ -261,6 +510,44 @@ impl TestDataMother {
         TransactionTestData::new(transaction, signing_private_key)
     }
 
+    pub fn application_call() -> TransactionTestData {
+        let signing_private_key: Byte32 = [
+            2, 205, 103, 33, 67, 14, 82, 196, 115, 196, 206, 254, 50, 110, 63, 182, 149, 229, 184,
+            216, 93, 11, 13, 99, 69, 213, 218, 165, 134, 118, 47, 44,
+        ];
+        let transaction = TransactionMother::application_call().build().unwrap();
+        TransactionTestData::new(transaction, signing_private_key)
+    }
+
+    pub fn asset_create() -> TransactionTestData {
+        let signing_private_key: Byte32 = [
+            2, 205, 103, 33, 67, 14, 82, 196, 115, 196, 206, 254, 50, 110, 63, 182, 149, 229, 184,
+            216, 93, 11, 13, 99, 69, 213, 218, 165, 134, 118, 47, 44,
+        ];
+        let transaction = TransactionMother::asset_create().build().unwrap();
+        TransactionTestData::new(transaction, signing_private_key)
+    }
+
+    pub fn asset_freeze() -> TransactionTestData {
+        let signing_private_key: Byte32 = [
+            2, 205, 103, 33, 67, 14, 82, 196, 115, 196, 206, 254, 50, 110, 63, 182, 149, 229, 184,
+            216, 93, 11, 13, 99, 69, 213, 218, 165, 134, 118, 47, 44,
+        ];
+        let transaction = TransactionMother::asset_freeze().build().unwrap();
+        TransactionTestData::new(transaction, signing_private_key)
+    }
+
+    pub fn online_key_registration() -> TransactionTestData {
+        let signing_private_key: Byte32 = [
+            2, 205, 103, 33, 67, 14, 82, 196, 115, 196, 206, 254, 50, 110, 63, 182, 149, 229, 184,
+            216, 93, 11, 13, 99, 69, 213, 218, 165, 134, 118, 47, 44,
+        ];
+        let transaction = TransactionMother::online_key_registration()
+            .build()
+            .unwrap();
+        TransactionTestData::new(transaction, signing_private_key)
+    }
+
     pub fn export<F, T>(path: &std::path::Path, transform: Option<F>)
     where
         F: Fn(&TransactionTestData) -> T,
@@ -273,6 +560,10 @@ impl TestDataMother {
         let test_data = normalise_json(serde_json::json!({
             "simple_payment": Self::simple_payment().as_json(&transform),
             "opt_in_asset_transfer": Self::opt_in_asset_transfer().as_json(&transform),
+            "application_call": Self::application_call().as_json(&transform),
+            "asset_create": Self::asset_create().as_json(&transform),
+            "asset_freeze": Self::asset_freeze().as_json(&transform),
+            "online_key_registration": Self::online_key_registration().as_json(&transform),
         }));
 
         let file = File::create(path).expect("Failed to create export file");
@@ -289,7 +580,7 @@ fn normalise_json(value: serde_json::Value) -> serde_json::Value {
                 .collect(),
         ),
         serde_json::Value::Array(arr) => {
-            serde_json::Value::Array(arr.into_iter().map(|v| normalise_json(v)).collect())
+            serde_json::Value::Array(arr.into_iter().map(normalise_json).collect())
         }
         other => other,
     }
@@ -1,16 +1,30 @@
 use crate::{
+    app_spec::{
+        Arc56Contract, DefaultValue, DefaultValueSource, OnCompleteAction, StorageKey, StructField,
+        StructFieldType,
+    },
     constants::{
         ALGORAND_SIGNATURE_BYTE_LENGTH, ALGORAND_SIGNATURE_ENCODING_INCR, MAX_TX_GROUP_SIZE,
     },
+    decode_msgpack_to_json, mnemonic_from_seed, seed_from_mnemonic, supported_models,
     test_utils::{
-        AddressMother, TransactionGroupMother, TransactionHeaderMother, TransactionMother,
+        AddressMother, AppSpecMother, TransactionGroupMother, TransactionHeaderMother,
+        TransactionMother,
+    },
+    transactions::{
+        ApplicationCallTransactionFields, ConsensusParams, FeeParams, GroupFeeParams,
+        TransactionFeeParams, TransactionHeader, TransactionHeaderBuilder,
     },
-    transactions::FeeParams,
-    Address, AlgorandMsgpack, EstimateTransactionSize, SignedTransaction, Transaction,
-    TransactionId, Transactions,
+    AbiMethod, AbiMethodArgType, AbiMethodArgValue, AbiReferenceType, AbiTransactionType, AbiType,
+    AbiValue, Address, AlgoKitTransactError, AlgorandMsgpack, AssetParams, BoxReference,
+    ComposerTransaction, EstimateTransactionSize, LogicSignature, MethodCall, MethodCallArg,
+    MultisigSignature, OnApplicationComplete, SignedTransaction, StateSchema, SuggestedParams,
+    Transaction, TransactionComposer, TransactionId, TransactionSigner, Transactions,
 };
 use base64::{prelude::BASE64_STANDARD, Engine};
+use ed25519_dalek::{Signer, SigningKey, Verifier};
 use pretty_assertions::assert_eq;
+use proptest::prelude::*;
 
 #[test]
 fn test_payment_transaction_encoding() {
@@ -27,6 +41,9 @@ fn test_payment_transaction_encoding() {
         transaction: payment_tx.clone(),
         signature: Some([0; ALGORAND_SIGNATURE_BYTE_LENGTH]),
         auth_address: None,
+        multisignature: None,
+        logic_signature: None,
+        unknown_fields: Default::default(),
     };
     let encoded_stx = signed_tx.encode().unwrap();
     let decoded_stx = SignedTransaction::decode(&encoded_stx).unwrap();
@@ -59,6 +76,9 @@ fn test_asset_transfer_transaction_encoding() {
         transaction: asset_transfer_tx.clone(),
         signature: Some([0; ALGORAND_SIGNATURE_BYTE_LENGTH]),
         auth_address: None,
+        multisignature: None,
+        logic_signature: None,
+        unknown_fields: Default::default(),
     };
     let encoded_stx = signed_tx.encode().unwrap();
     let decoded_stx = SignedTransaction::decode(&encoded_stx).unwrap();
@@ -74,245 +94,1667 @@ fn test_asset_transfer_transaction_encoding() {
 }
 
 #[test]
-fn test_signed_transaction_encoding() {
-    let tx_builder = TransactionMother::simple_payment();
-    let payment_tx = tx_builder.build().unwrap();
+fn test_application_call_transaction_encoding() {
+    let tx_builder = TransactionMother::application_call();
+    let application_call_tx_fields = tx_builder.build_fields().unwrap();
+    let application_call_tx = tx_builder.build().unwrap();
+
+    let encoded = application_call_tx.encode().unwrap();
+    let decoded = Transaction::decode(&encoded).unwrap();
+    assert_eq!(decoded, application_call_tx);
+    assert_eq!(
+        decoded,
+        Transaction::ApplicationCall(application_call_tx_fields)
+    );
 
-    // The sender is signing the transaction
     let signed_tx = SignedTransaction {
-        transaction: payment_tx.clone(),
+        transaction: application_call_tx.clone(),
         signature: Some([0; ALGORAND_SIGNATURE_BYTE_LENGTH]),
         auth_address: None,
+        multisignature: None,
+        logic_signature: None,
+        unknown_fields: Default::default(),
     };
     let encoded_stx = signed_tx.encode().unwrap();
-    assert_eq!(encoded_stx.len(), 247);
     let decoded_stx = SignedTransaction::decode(&encoded_stx).unwrap();
     assert_eq!(decoded_stx, signed_tx);
+    assert_eq!(decoded_stx.transaction, application_call_tx);
 
-    // The sender is not signing the transaction (rekeyed sender account)
-    let auth_address = AddressMother::address();
-    let signed_tx = SignedTransaction {
-        transaction: payment_tx.clone(),
-        signature: Some([1; ALGORAND_SIGNATURE_BYTE_LENGTH]),
-        auth_address: Some(auth_address.clone()),
-    };
-    let encoded_stx: Vec<u8> = signed_tx.encode().unwrap();
-    assert_eq!(encoded_stx.len(), 286);
-    let decoded_stx = SignedTransaction::decode(&encoded_stx).unwrap();
-    assert_eq!(decoded_stx, signed_tx);
+    let raw_encoded = application_call_tx.encode_raw().unwrap();
+    assert_eq!(encoded[0], b'T');
+    assert_eq!(encoded[1], b'X');
+    assert_eq!(encoded.len(), raw_encoded.len() + 2);
+    assert_eq!(encoded[2..], raw_encoded);
+    assert_eq!(encoded.len(), 246);
 }
 
 #[test]
-fn test_zero_address() {
-    let addr = AddressMother::zero_address();
-    assert_eq!(
-        addr.to_string(),
-        "AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAY5HFKQ"
-    );
+fn test_application_create_transaction_encoding() {
+    let application_create_tx = TransactionMother::application_create().build().unwrap();
 
-    let addr_from_str = addr.to_string().parse::<Address>().unwrap();
-    assert_eq!(addr, addr_from_str);
+    let encoded = application_create_tx.encode().unwrap();
+    let decoded = Transaction::decode(&encoded).unwrap();
+    assert_eq!(decoded, application_create_tx);
+    assert_eq!(encoded.len(), 182);
 }
 
 #[test]
-fn test_address() {
-    let addr = AddressMother::address();
+fn test_application_call_msgpack_keys() {
+    let application_call_tx = TransactionMother::application_call().build().unwrap();
+
+    let value = rmpv::decode::read_value(&mut application_call_tx.encode_raw().unwrap().as_slice())
+        .unwrap();
+    let keys: Vec<&str> = value
+        .as_map()
+        .unwrap()
+        .iter()
+        .map(|(k, _)| k.as_str().unwrap())
+        .collect();
     assert_eq!(
-        addr.to_string(),
-        "RIMARGKZU46OZ77OLPDHHPUJ7YBSHRTCYMQUC64KZCCMESQAFQMYU6SL2Q"
+        keys,
+        vec![
+            "apaa", "apas", "apat", "apbx", "apfa", "apid", "fee", "fv", "gen", "gh", "lv", "snd",
+            "type"
+        ]
     );
 
-    let addr_from_str = addr.to_string().parse::<Address>().unwrap();
-    assert_eq!(addr, addr_from_str);
+    let box_references = value
+        .as_map()
+        .unwrap()
+        .iter()
+        .find(|(k, _)| k.as_str() == Some("apbx"))
+        .unwrap()
+        .1
+        .as_array()
+        .unwrap()
+        .clone();
+    // The current app is omitted, other apps are a 1-based index into the app references
+    assert_eq!(
+        box_references[0],
+        rmpv::Value::Map(vec![("n".into(), rmpv::Value::Binary(b"box".to_vec()))])
+    );
+    assert_eq!(
+        box_references[1],
+        rmpv::Value::Map(vec![
+            ("i".into(), 1.into()),
+            ("n".into(), rmpv::Value::Binary(b"other".to_vec()))
+        ])
+    );
 }
 
 #[test]
-fn test_pay_transaction_id() {
-    let expected_tx_id_raw = [
-        35, 93, 0, 170, 96, 221, 1, 74, 119, 147, 131, 116, 7, 31, 225, 40, 215, 47, 44, 120, 128,
-        245, 41, 65, 116, 255, 147, 64, 90, 80, 147, 223,
-    ];
-    let expected_tx_id = "ENOQBKTA3UAUU54TQN2AOH7BFDLS6LDYQD2SSQLU76JUAWSQSPPQ";
-
-    let tx_builder = TransactionMother::payment_with_note();
-    let payment_tx = tx_builder.build().unwrap();
-    let signed_tx = SignedTransaction {
-        transaction: payment_tx.clone(),
-        signature: Some([0; ALGORAND_SIGNATURE_BYTE_LENGTH]),
-        auth_address: None,
-    };
+fn test_application_call_box_reference_not_in_app_references() {
+    let application_call_tx = TransactionMother::application_call()
+        .box_references(vec![BoxReference {
+            app_id: 1234,
+            name: b"box".to_vec(),
+            unknown_fields: Default::default(),
+        }])
+        .build()
+        .unwrap();
 
-    assert_eq!(payment_tx.id().unwrap(), expected_tx_id);
-    assert_eq!(payment_tx.id_raw().unwrap(), expected_tx_id_raw);
-    assert_eq!(signed_tx.id().unwrap(), expected_tx_id);
-    assert_eq!(signed_tx.id_raw().unwrap(), expected_tx_id_raw);
+    let error = application_call_tx.encode().unwrap_err();
+    assert!(error
+        .to_string()
+        .contains("box reference app id 1234 is not in the app references"));
 }
 
 #[test]
-fn test_estimate_transaction_size() {
-    let tx_builder = TransactionMother::simple_payment();
-    let payment_tx = tx_builder.build().unwrap();
-    let encoding_length = payment_tx.encode_raw().unwrap().len();
-    let estimation = payment_tx.estimate_size().unwrap();
+fn test_application_call_empty_box_reference_encoding() {
+    let sender = AddressMother::neil();
+    let application_call_tx = crate::ApplicationCallTransactionBuilder::default()
+        .header(
+            TransactionHeaderBuilder::default()
+                .sender(sender.clone())
+                .fee(1000)
+                .first_valid(1)
+                .last_valid(2)
+                .build()
+                .unwrap(),
+        )
+        .app_id(1)
+        .box_references(vec![BoxReference::default()])
+        .build()
+        .unwrap();
 
-    let signed_tx = SignedTransaction {
-        transaction: payment_tx.clone(),
-        signature: Some([0; ALGORAND_SIGNATURE_BYTE_LENGTH]),
-        auth_address: None,
-    };
-    let actual_size = signed_tx.encode().unwrap().len();
+    // go-algorand omits the empty fields of a box reference, so an empty reference is `{}`
+    let mut expected = vec![0x87];
+    expected.extend_from_slice(b"\xa4apbx\x91\x80");
+    expected.extend_from_slice(b"\xa4apid\x01");
+    expected.extend_from_slice(b"\xa3fee\xcd\x03\xe8");
+    expected.extend_from_slice(b"\xa2fv\x01");
+    expected.extend_from_slice(b"\xa2lv\x02");
+    expected.extend_from_slice(b"\xa3snd\xc4\x20");
+    expected.extend_from_slice(&sender.pub_key);
+    expected.extend_from_slice(b"\xa4type\xa4appl");
 
+    assert_eq!(application_call_tx.encode_raw().unwrap(), expected);
     assert_eq!(
-        estimation,
-        encoding_length + ALGORAND_SIGNATURE_ENCODING_INCR
+        Transaction::decode(&application_call_tx.encode().unwrap()).unwrap(),
+        application_call_tx
     );
-    assert_eq!(estimation, actual_size);
 }
 
 #[test]
-fn test_min_fee() {
-    let txn: Transaction = TransactionMother::simple_payment().build().unwrap();
+fn test_application_call_box_reference_to_called_app() {
+    let app_id = 718348254;
+    let box_reference = BoxReference {
+        app_id,
+        name: b"box".to_vec(),
+        unknown_fields: Default::default(),
+    };
 
-    let updated_transaction = txn
-        .assign_fee(FeeParams {
-            fee_per_byte: 0,
-            min_fee: 1000,
-            extra_fee: None,
-            max_fee: None,
-        })
+    // Without an app reference the called app is encoded as index 0, so it decodes as 0
+    let application_call_tx = TransactionMother::application_call()
+        .box_references(vec![box_reference.clone()])
+        .build()
         .unwrap();
-    assert_eq!(updated_transaction.header().fee, Some(1000));
+    let decoded = Transaction::decode(&application_call_tx.encode().unwrap()).unwrap();
+    let Transaction::ApplicationCall(fields) = decoded else {
+        panic!("expected an application call");
+    };
+    assert_eq!(fields.box_references.unwrap()[0].app_id, 0);
+
+    // With an app reference the index of the reference is kept
+    let application_call_tx = TransactionMother::application_call()
+        .app_references(vec![app_id])
+        .box_references(vec![box_reference])
+        .build()
+        .unwrap();
+    let decoded = Transaction::decode(&application_call_tx.encode().unwrap()).unwrap();
+    assert_eq!(decoded, application_call_tx);
 }
 
 #[test]
-fn test_extra_fee() {
-    let txn: Transaction = TransactionMother::simple_payment().build().unwrap();
-
-    let updated_transaction = txn
-        .assign_fee(FeeParams {
-            fee_per_byte: 1,
-            min_fee: 1000,
-            extra_fee: Some(500),
-            max_fee: None,
-        })
+fn test_application_call_on_complete_encoding() {
+    let application_call_tx = TransactionMother::application_call()
+        .on_complete(OnApplicationComplete::DeleteApplication)
+        .build()
         .unwrap();
-    assert_eq!(updated_transaction.header().fee, Some(1500));
+
+    let encoded = application_call_tx.encode().unwrap();
+    let decoded = Transaction::decode(&encoded).unwrap();
+    assert_eq!(decoded, application_call_tx);
+
+    match decoded {
+        Transaction::ApplicationCall(fields) => {
+            assert_eq!(fields.on_complete, OnApplicationComplete::DeleteApplication)
+        }
+        _ => panic!("expected an application call transaction"),
+    }
 }
 
 #[test]
-fn test_max_fee() {
-    let txn: Transaction = TransactionMother::simple_payment().build().unwrap();
+fn test_asset_create_transaction_encoding() {
+    let tx_builder = TransactionMother::asset_create();
+    let asset_create_tx_fields = tx_builder.build_fields().unwrap();
+    let asset_create_tx = tx_builder.build().unwrap();
 
-    let result = txn.assign_fee(FeeParams {
-        fee_per_byte: 10,
-        min_fee: 500,
-        extra_fee: None,
-        max_fee: Some(1000),
-    });
+    let encoded = asset_create_tx.encode().unwrap();
+    let decoded = Transaction::decode(&encoded).unwrap();
+    assert_eq!(decoded, asset_create_tx);
+    assert_eq!(decoded, Transaction::AssetConfig(asset_create_tx_fields));
 
-    assert!(result.is_err());
-    let err: crate::AlgoKitTransactError = result.unwrap_err();
-    let msg = format!("{}", err);
-    assert!(
-        msg == "Transaction fee 2470 µALGO is greater than max fee 1000 µALGO",
-        "Unexpected error message: {}",
-        msg
-    );
+    let signed_tx = SignedTransaction {
+        transaction: asset_create_tx.clone(),
+        signature: Some([0; ALGORAND_SIGNATURE_BYTE_LENGTH]),
+        auth_address: None,
+        multisignature: None,
+        logic_signature: None,
+        unknown_fields: Default::default(),
+    };
+    let encoded_stx = signed_tx.encode().unwrap();
+    let decoded_stx = SignedTransaction::decode(&encoded_stx).unwrap();
+    assert_eq!(decoded_stx, signed_tx);
+
+    let raw_encoded = asset_create_tx.encode_raw().unwrap();
+    assert_eq!(encoded[2..], raw_encoded);
+    assert_eq!(encoded.len(), 334);
 }
 
 #[test]
-fn test_calculate_fee() {
-    let txn: Transaction = TransactionMother::simple_payment().build().unwrap();
-
-    let updated_transaction = txn
-        .assign_fee(FeeParams {
-            fee_per_byte: 5,
-            min_fee: 1000,
-            extra_fee: None,
-            max_fee: None,
-        })
-        .unwrap();
+fn test_asset_reconfigure_transaction_encoding() {
+    let asset_reconfigure_tx = TransactionMother::asset_reconfigure().build().unwrap();
 
-    assert_eq!(updated_transaction.header().fee, Some(1235));
+    let encoded = asset_reconfigure_tx.encode().unwrap();
+    let decoded = Transaction::decode(&encoded).unwrap();
+    assert_eq!(decoded, asset_reconfigure_tx);
+    assert_eq!(encoded.len(), 216);
 }
 
 #[test]
-fn test_multi_transaction_group() {
-    let expected_group: [u8; 32] = BASE64_STANDARD
-        .decode(String::from("uJA6BWzZ5g7Ve0FersqCLWsrEstt6p0+F3bNGEKH3I4="))
-        .unwrap()
-        .try_into()
-        .unwrap();
-    let txs = TransactionGroupMother::testnet_payment_group();
+fn test_asset_destroy_transaction_encoding() {
+    let asset_destroy_tx = TransactionMother::asset_destroy().build().unwrap();
 
-    let grouped_txs = txs.assign_group().unwrap();
+    let encoded = asset_destroy_tx.encode().unwrap();
+    let decoded = Transaction::decode(&encoded).unwrap();
+    assert_eq!(decoded, asset_destroy_tx);
 
-    assert_eq!(grouped_txs.len(), txs.len());
-    for grouped_tx in grouped_txs.iter() {
-        assert_eq!(grouped_tx.header().group.unwrap(), expected_group);
-    }
-    assert_eq!(
-        &grouped_txs[0].id().unwrap(),
-        "6SIXGV2TELA2M5RHZ72CVKLBSJ2OPUAKYFTUUE27O23RN6TFMGHQ"
-    );
+    // Destroying an asset with empty params must be encoded identically to omitting them
+    let asset_destroy_with_empty_params_tx = TransactionMother::asset_destroy()
+        .params(AssetParams::default())
+        .build()
+        .unwrap();
     assert_eq!(
-        &grouped_txs[1].id().unwrap(),
-        "7OY3VQXJCDSKPMGEFJMNJL2L3XIOMRM2U7DM2L54CC7QM5YBFQEA"
+        asset_destroy_with_empty_params_tx.encode().unwrap(),
+        encoded
     );
+    assert_eq!(encoded.len(), 138);
 }
 
 #[test]
-fn test_single_transaction_group() {
-    let expected_group: [u8; 32] = BASE64_STANDARD
-        .decode(String::from("LLW3AwgyXbwoMMBNfLSAGHtqoKtj/c7MjNMR0MGW6sg="))
+fn test_asset_names_with_arbitrary_bytes() {
+    let mut asset_create = TransactionMother::asset_create().build_fields().unwrap();
+    let params = asset_create.params.as_mut().unwrap();
+    params.asset_name = Some(vec![0xff, 0x41]);
+    params.unit_name = Some(vec![0xfe]);
+    let tx = Transaction::AssetConfig(asset_create);
+
+    // Names which aren't valid UTF-8 are still encoded as msgpack strings, as by algod
+    let encoded = tx.encode_raw().unwrap();
+    assert!(encoded.windows(3).any(|bytes| bytes == [0xa2, 0xff, 0x41]));
+    assert!(encoded.windows(2).any(|bytes| bytes == [0xa1, 0xfe]));
+    assert_eq!(Transaction::decode_strict(&encoded).unwrap(), tx);
+
+    let signed_tx = SignedTransaction {
+        transaction: tx.clone(),
+        signature: Some([0; ALGORAND_SIGNATURE_BYTE_LENGTH]),
+        auth_address: None,
+        multisignature: None,
+        logic_signature: None,
+        unknown_fields: Default::default(),
+    };
+    let encoded_stx = signed_tx.encode().unwrap();
+    assert_eq!(
+        SignedTransaction::decode_strict(&encoded_stx).unwrap(),
+        signed_tx
+    );
+
+    // Names encoded as binary are also accepted
+    let mut value = rmpv::decode::read_value(&mut &encoded[..]).unwrap();
+    let rmpv::Value::Map(fields) = &mut value else {
+        panic!("expected a map");
+    };
+    let (_, rmpv::Value::Map(apar)) = fields
+        .iter_mut()
+        .find(|(key, _)| key.as_str() == Some("apar"))
         .unwrap()
-        .try_into()
+    else {
+        panic!("expected the asset params to be a map");
+    };
+    let (_, asset_name) = apar
+        .iter_mut()
+        .find(|(key, _)| key.as_str() == Some("an"))
         .unwrap();
-    let txs: Vec<Transaction> = TransactionGroupMother::group_of(1);
+    *asset_name = rmpv::Value::Binary(vec![0xff, 0x41]);
+    let mut binary_encoded = Vec::new();
+    rmpv::encode::write_value(&mut binary_encoded, &value).unwrap();
+    assert_eq!(Transaction::decode(&binary_encoded).unwrap(), tx);
 
-    let grouped_txs = txs.assign_group().unwrap();
+    // Other serializers see the names as plain bytes rather than a msgpack extension
+    let serialized = rmp_serde::to_vec_named(&tx).unwrap();
+    let value = rmpv::decode::read_value(&mut &serialized[..]).unwrap();
+    assert_eq!(value["apar"]["an"], rmpv::Value::Binary(vec![0xff, 0x41]));
+    assert_eq!(Transaction::decode(&serialized).unwrap(), tx);
+    let json = serde_json::to_string(&tx).unwrap();
+    assert!(json.contains(r#""an":[255,65]"#));
+    assert_eq!(serde_json::from_str::<Transaction>(&json).unwrap(), tx);
+}
 
-    assert_eq!(grouped_txs.len(), txs.len());
-    for grouped_tx in grouped_txs.iter() {
-        assert_eq!(grouped_tx.header().group.unwrap(), expected_group);
+#[test]
+fn test_asset_config_validation() {
+    let cases = [
+        (
+            TransactionMother::asset_create()
+                .params(AssetParams {
+                    total: Some(1),
+                    decimals: Some(20),
+                    ..Default::default()
+                })
+                .build_fields(),
+            "Asset decimals must not exceed 19",
+        ),
+        (
+            TransactionMother::asset_create()
+                .params(AssetParams {
+                    total: Some(1),
+                    url: Some(vec![b'a'; 97]),
+                    ..Default::default()
+                })
+                .build_fields(),
+            "Asset url must not exceed 96 bytes",
+        ),
+        (
+            TransactionMother::asset_create()
+                .params(AssetParams {
+                    total: Some(1),
+                    unit_name: Some(vec![b'a'; 9]),
+                    ..Default::default()
+                })
+                .build_fields(),
+            "Asset unit name must not exceed 8 bytes",
+        ),
+        (
+            TransactionMother::asset_create()
+                .params(AssetParams {
+                    total: Some(1),
+                    asset_name: Some(vec![b'a'; 33]),
+                    ..Default::default()
+                })
+                .build_fields(),
+            "Asset asset name must not exceed 32 bytes",
+        ),
+        (
+            TransactionMother::asset_destroy()
+                .asset_id(0)
+                .build_fields(),
+            "Asset params are required when creating an asset",
+        ),
+        (
+            TransactionMother::asset_reconfigure()
+                .params(AssetParams {
+                    total: Some(1),
+                    ..Default::default()
+                })
+                .build_fields(),
+            "Only the manager, reserve, freeze and clawback addresses can be reconfigured",
+        ),
+    ];
+
+    for (result, expected) in cases {
+        assert_eq!(result.unwrap_err().to_string(), expected);
     }
 }
 
 #[test]
-fn test_transaction_group_too_big() {
-    let txs: Vec<Transaction> = TransactionGroupMother::group_of(MAX_TX_GROUP_SIZE + 1);
+fn test_asset_freeze_transaction_encoding() {
+    let tx_builder = TransactionMother::asset_freeze();
+    let asset_freeze_tx_fields = tx_builder.build_fields().unwrap();
+    let asset_freeze_tx = tx_builder.build().unwrap();
 
-    let result = txs.assign_group();
+    let encoded = asset_freeze_tx.encode().unwrap();
+    let decoded = Transaction::decode(&encoded).unwrap();
+    assert_eq!(decoded, asset_freeze_tx);
+    assert_eq!(decoded, Transaction::AssetFreeze(asset_freeze_tx_fields));
 
-    let error = result.unwrap_err();
-    assert!(error
-        .to_string()
-        .starts_with("Transaction group size exceeds the max limit"));
+    let signed_tx = SignedTransaction {
+        transaction: asset_freeze_tx.clone(),
+        signature: Some([0; ALGORAND_SIGNATURE_BYTE_LENGTH]),
+        auth_address: None,
+        multisignature: None,
+        logic_signature: None,
+        unknown_fields: Default::default(),
+    };
+    let encoded_stx = signed_tx.encode().unwrap();
+    let decoded_stx = SignedTransaction::decode(&encoded_stx).unwrap();
+    assert_eq!(decoded_stx, signed_tx);
+    assert_eq!(decoded_stx.transaction, asset_freeze_tx);
+
+    let raw_encoded = asset_freeze_tx.encode_raw().unwrap();
+    assert_eq!(encoded[0], b'T');
+    assert_eq!(encoded[1], b'X');
+    assert_eq!(encoded.len(), raw_encoded.len() + 2);
+    assert_eq!(encoded[2..], raw_encoded);
+    assert_eq!(encoded.len(), 183);
 }
 
 #[test]
-fn test_transaction_group_too_small() {
-    let txs: Vec<Transaction> = TransactionGroupMother::group_of(0);
+fn test_asset_unfreeze_transaction_encoding() {
+    let asset_freeze_tx = TransactionMother::asset_freeze().build().unwrap();
+    let asset_unfreeze_tx = TransactionMother::asset_freeze()
+        .frozen(false)
+        .build()
+        .unwrap();
 
-    let result = txs.assign_group();
+    let encoded = asset_unfreeze_tx.encode().unwrap();
+    let decoded = Transaction::decode(&encoded).unwrap();
+    assert_eq!(decoded, asset_unfreeze_tx);
 
-    let error = result.unwrap_err();
-    assert!(error
-        .to_string()
-        .starts_with("Transaction group size cannot be 0"));
+    // A false frozen state is omitted from the canonical encoding
+    assert_eq!(
+        encoded.len(),
+        asset_freeze_tx.encode().unwrap().len() - "afrz".len() - 2
+    );
 }
 
 #[test]
-fn test_transaction_group_already_set() {
-    let tx: Transaction = TransactionMother::simple_payment()
-        .header(
-            TransactionHeaderMother::simple_testnet()
-                .group(
-                    BASE64_STANDARD
-                        .decode(String::from("y1Hz6KZhHJI4TZLwZqXO3TFgXVQdD/1+c6BLk3wTW6Q="))
-                        .unwrap()
-                        .try_into()
-                        .unwrap(),
+fn test_online_key_registration_transaction_encoding() {
+    let tx_builder = TransactionMother::online_key_registration();
+    let key_registration_tx_fields = tx_builder.build_fields().unwrap();
+    let key_registration_tx = tx_builder.build().unwrap();
+    assert!(key_registration_tx_fields.is_online());
+
+    let encoded = key_registration_tx.encode().unwrap();
+    let decoded = Transaction::decode(&encoded).unwrap();
+    assert_eq!(decoded, key_registration_tx);
+    assert_eq!(
+        decoded,
+        Transaction::KeyRegistration(key_registration_tx_fields)
+    );
+
+    let signed_tx = SignedTransaction {
+        transaction: key_registration_tx.clone(),
+        signature: Some([0; ALGORAND_SIGNATURE_BYTE_LENGTH]),
+        auth_address: None,
+        multisignature: None,
+        logic_signature: None,
+        unknown_fields: Default::default(),
+    };
+    let encoded_stx = signed_tx.encode().unwrap();
+    let decoded_stx = SignedTransaction::decode(&encoded_stx).unwrap();
+    assert_eq!(decoded_stx, signed_tx);
+    assert_eq!(decoded_stx.transaction, key_registration_tx);
+
+    let raw_encoded = key_registration_tx.encode_raw().unwrap();
+    assert_eq!(encoded[0], b'T');
+    assert_eq!(encoded[1], b'X');
+    assert_eq!(encoded.len(), raw_encoded.len() + 2);
+    assert_eq!(encoded[2..], raw_encoded);
+    assert_eq!(encoded.len(), 323);
+}
+
+#[test]
+fn test_offline_key_registration_transaction_encoding() {
+    let tx_builder = TransactionMother::offline_key_registration();
+    let key_registration_tx_fields = tx_builder.build_fields().unwrap();
+    let key_registration_tx = tx_builder.build().unwrap();
+    assert!(key_registration_tx_fields.is_offline());
+
+    let encoded = key_registration_tx.encode().unwrap();
+    let decoded = Transaction::decode(&encoded).unwrap();
+    assert_eq!(decoded, key_registration_tx);
+    assert_eq!(
+        decoded,
+        Transaction::KeyRegistration(key_registration_tx_fields)
+    );
+    assert_eq!(encoded.len(), 130);
+}
+
+#[test]
+fn test_non_participation_key_registration_transaction_encoding() {
+    let tx_builder = TransactionMother::non_participation_key_registration();
+    let key_registration_tx_fields = tx_builder.build_fields().unwrap();
+    let key_registration_tx = tx_builder.build().unwrap();
+    assert!(key_registration_tx_fields.is_non_participating());
+
+    let encoded = key_registration_tx.encode().unwrap();
+    let decoded = Transaction::decode(&encoded).unwrap();
+    assert_eq!(decoded, key_registration_tx);
+    assert_eq!(
+        decoded,
+        Transaction::KeyRegistration(key_registration_tx_fields)
+    );
+    assert_eq!(encoded.len(), 139);
+}
+
+#[test]
+fn test_key_registration_validation() {
+    let cases = [
+        (
+            TransactionMother::offline_key_registration()
+                .vote_key([1; 32])
+                .build_fields(),
+            "Online key registration is missing: selection key, state proof key, vote first, vote last, vote key dilution",
+        ),
+        (
+            TransactionMother::online_key_registration()
+                .vote_key_dilution(0)
+                .build_fields(),
+            "Online key registration is missing: vote key dilution",
+        ),
+        (
+            TransactionMother::online_key_registration()
+                .vote_first(53659541)
+                .build_fields(),
+            "Vote first must not be greater than vote last",
+        ),
+        (
+            TransactionMother::online_key_registration()
+                .non_participation(true)
+                .build_fields(),
+            "Non-participating key registration must not set participation keys or vote parameters",
+        ),
+    ];
+
+    for (result, expected) in cases {
+        assert_eq!(result.unwrap_err().to_string(), expected);
+    }
+}
+
+fn msgpack_map(entries: Vec<(&str, rmpv::Value)>) -> rmpv::Value {
+    rmpv::Value::Map(entries.into_iter().map(|(k, v)| (k.into(), v)).collect())
+}
+
+fn msgpack_bytes(byte: u8, len: usize) -> rmpv::Value {
+    rmpv::Value::Binary(vec![byte; len])
+}
+
+#[test]
+fn test_state_proof_transaction_encoding() {
+    let state_proof_tx = TransactionMother::state_proof();
+
+    let merkle_proof = |byte: u8, tree_depth: u8| {
+        msgpack_map(vec![
+            ("hsh", msgpack_map(vec![("t", 1.into())])),
+            ("pth", rmpv::Value::Array(vec![msgpack_bytes(byte, 32)])),
+            ("td", tree_depth.into()),
+        ])
+    };
+    let reveal = |position: u8| {
+        msgpack_map(vec![
+            (
+                "p",
+                msgpack_map(vec![
+                    (
+                        "p",
+                        msgpack_map(vec![
+                            ("cmt", msgpack_bytes(position, 64)),
+                            ("lf", 256.into()),
+                        ]),
+                    ),
+                    ("w", 1_000_000.into()),
+                ]),
+            ),
+            (
+                "s",
+                msgpack_map(vec![
+                    ("l", (position as u64 * 1_000_000).into()),
+                    (
+                        "s",
+                        msgpack_map(vec![
+                            ("idx", 1026.into()),
+                            (
+                                "prf",
+                                msgpack_map(vec![
+                                    ("hsh", msgpack_map(vec![("t", 1.into())])),
+                                    (
+                                        "pth",
+                                        rmpv::Value::Array(vec![
+                                            msgpack_bytes(position, 32),
+                                            msgpack_bytes(position + 1, 32),
+                                        ]),
+                                    ),
+                                    ("td", 2.into()),
+                                ]),
+                            ),
+                            ("sig", msgpack_bytes(position, 16)),
+                            (
+                                "vkey",
+                                msgpack_map(vec![("k", msgpack_bytes(position, 1793))]),
+                            ),
+                        ]),
+                    ),
+                ]),
+            ),
+        ])
+    };
+    // The canonical encoding algod produces, with integer keys sorted numerically
+    let expected = msgpack_map(vec![
+        ("fv", 50659540.into()),
+        (
+            "gh",
+            rmpv::Value::Binary(
+                BASE64_STANDARD
+                    .decode("SGO1GKSzyE7IEPItTxCByw9x8FmnrCDexi9/cOUJOiI=")
+                    .unwrap(),
+            ),
+        ),
+        ("lv", 50660540.into()),
+        (
+            "snd",
+            rmpv::Value::Binary(AddressMother::state_proof_sender().pub_key.to_vec()),
+        ),
+        (
+            "sp",
+            msgpack_map(vec![
+                ("P", merkle_proof(3, 4)),
+                ("S", merkle_proof(2, 4)),
+                ("c", msgpack_bytes(1, 32)),
+                ("pr", rmpv::Value::Array(vec![12.into(), 3.into()])),
+                (
+                    "r",
+                    rmpv::Value::Map(vec![(3.into(), reveal(3)), (12.into(), reveal(12))]),
+                ),
+                ("w", 2_000_000.into()),
+            ]),
+        ),
+        (
+            "spmsg",
+            msgpack_map(vec![
+                ("P", 2_000_000.into()),
+                ("b", msgpack_bytes(4, 32)),
+                ("f", 50658049.into()),
+                ("l", 50658304.into()),
+                ("v", msgpack_bytes(5, 32)),
+            ]),
+        ),
+        ("type", "stpf".into()),
+    ]);
+    let mut expected_bytes = Vec::new();
+    rmpv::encode::write_value(&mut expected_bytes, &expected).unwrap();
+
+    let decoded = Transaction::decode(&expected_bytes).unwrap();
+    assert_eq!(decoded, state_proof_tx);
+    assert_eq!(decoded.encode_raw().unwrap(), expected_bytes);
+    assert_eq!(decoded.id().unwrap(), state_proof_tx.id().unwrap());
+
+    let signed_tx = SignedTransaction {
+        transaction: state_proof_tx.clone(),
+        signature: None,
+        auth_address: None,
+        multisignature: None,
+        logic_signature: None,
+        unknown_fields: Default::default(),
+    };
+    let encoded_stx = signed_tx.encode().unwrap();
+    let decoded_stx = SignedTransaction::decode(&encoded_stx).unwrap();
+    assert_eq!(decoded_stx, signed_tx);
+}
+
+#[test]
+fn test_heartbeat_transaction_encoding() {
+    let heartbeat_tx = TransactionMother::heartbeat();
+
+    let expected = msgpack_map(vec![
+        ("fee", 1000.into()),
+        ("fv", 50659540.into()),
+        ("gen", "testnet-v1.0".into()),
+        (
+            "gh",
+            rmpv::Value::Binary(
+                BASE64_STANDARD
+                    .decode("SGO1GKSzyE7IEPItTxCByw9x8FmnrCDexi9/cOUJOiI=")
+                    .unwrap(),
+            ),
+        ),
+        (
+            "hb",
+            msgpack_map(vec![
+                (
+                    "a",
+                    rmpv::Value::Binary(AddressMother::neil().pub_key.to_vec()),
+                ),
+                ("kd", 1733.into()),
+                (
+                    "prf",
+                    msgpack_map(vec![
+                        ("p", msgpack_bytes(2, 32)),
+                        ("p1s", msgpack_bytes(4, 64)),
+                        ("p2", msgpack_bytes(3, 32)),
+                        ("p2s", msgpack_bytes(5, 64)),
+                        ("s", msgpack_bytes(1, 64)),
+                    ]),
+                ),
+                ("sd", msgpack_bytes(6, 32)),
+                ("vid", msgpack_bytes(7, 32)),
+            ]),
+        ),
+        ("lv", 50660540.into()),
+        (
+            "snd",
+            rmpv::Value::Binary(AddressMother::address().pub_key.to_vec()),
+        ),
+        ("type", "hb".into()),
+    ]);
+    let mut expected_bytes = Vec::new();
+    rmpv::encode::write_value(&mut expected_bytes, &expected).unwrap();
+
+    let decoded = Transaction::decode(&expected_bytes).unwrap();
+    assert_eq!(decoded, heartbeat_tx);
+    assert_eq!(decoded.encode_raw().unwrap(), expected_bytes);
+    assert_eq!(decoded.id().unwrap(), heartbeat_tx.id().unwrap());
+
+    let signed_tx = SignedTransaction {
+        transaction: heartbeat_tx.clone(),
+        signature: None,
+        auth_address: None,
+        multisignature: None,
+        logic_signature: None,
+        unknown_fields: Default::default(),
+    };
+    let encoded_stx = signed_tx.encode().unwrap();
+    let decoded_stx = SignedTransaction::decode(&encoded_stx).unwrap();
+    assert_eq!(decoded_stx, signed_tx);
+}
+
+#[test]
+fn test_signed_transaction_encoding() {
+    let tx_builder = TransactionMother::simple_payment();
+    let payment_tx = tx_builder.build().unwrap();
+
+    // The sender is signing the transaction
+    let signed_tx = SignedTransaction {
+        transaction: payment_tx.clone(),
+        signature: Some([0; ALGORAND_SIGNATURE_BYTE_LENGTH]),
+        auth_address: None,
+        multisignature: None,
+        logic_signature: None,
+        unknown_fields: Default::default(),
+    };
+    let encoded_stx = signed_tx.encode().unwrap();
+    assert_eq!(encoded_stx.len(), 247);
+    let decoded_stx = SignedTransaction::decode(&encoded_stx).unwrap();
+    assert_eq!(decoded_stx, signed_tx);
+
+    // The sender is not signing the transaction (rekeyed sender account)
+    let auth_address = AddressMother::address();
+    let signed_tx = SignedTransaction {
+        transaction: payment_tx.clone(),
+        signature: Some([1; ALGORAND_SIGNATURE_BYTE_LENGTH]),
+        auth_address: Some(auth_address.clone()),
+        multisignature: None,
+        logic_signature: None,
+        unknown_fields: Default::default(),
+    };
+    let encoded_stx: Vec<u8> = signed_tx.encode().unwrap();
+    assert_eq!(encoded_stx.len(), 286);
+    let decoded_stx = SignedTransaction::decode(&encoded_stx).unwrap();
+    assert_eq!(decoded_stx, signed_tx);
+}
+
+#[test]
+fn test_multisig_address() {
+    let participants: Vec<Address> = [
+        "DN7MBMCL5JQ3PFUQS7TMX5AH4EEKOBJVDUF4TCV6WERATKFLQF4MQUPZTA",
+        "BFRTECKTOOE7A5LHCF3TTEOH2A7BW46IYT2SX5VP6ANKEXHZYJY77SJTVM",
+        "47YPQTIGQEO7T4Y4RWDYWEKV6RTR2UNBQXBABEEGM72ESWDQNCQ52OPASU",
+    ]
+    .iter()
+    .map(|addr| addr.parse().unwrap())
+    .collect();
+
+    let multisig_addr = Address::from_multisig(1, 2, &participants).unwrap();
+    assert_eq!(
+        multisig_addr.to_string(),
+        "RWJLJCMQAFZ2ATP2INM2GZTKNL6OULCCUBO5TQPXH3V2KR4AG7U5UA5JNM"
+    );
+
+    let multisig = MultisigSignature::from_participants(1, 2, participants.clone()).unwrap();
+    assert_eq!(multisig.address().unwrap(), multisig_addr);
+
+    assert_eq!(
+        Address::from_multisig(2, 2, &participants)
+            .unwrap_err()
+            .to_string(),
+        "Unsupported multisig version 2"
+    );
+    assert_eq!(
+        Address::from_multisig(1, 4, &participants)
+            .unwrap_err()
+            .to_string(),
+        "Multisig threshold must be between 1 and the number of participants"
+    );
+    assert_eq!(
+        Address::from_multisig(1, 1, &[]).unwrap_err().to_string(),
+        "Multisig accounts must have between 1 and 255 participants"
+    );
+}
+
+#[test]
+fn test_multisig_signed_transaction_encoding() {
+    let signing_keys: Vec<SigningKey> = (1..=3u8)
+        .map(|i| SigningKey::from_bytes(&[i; 32]))
+        .collect();
+    let participants: Vec<Address> = signing_keys
+        .iter()
+        .map(|key| Address::from_pubkey(&key.verifying_key().to_bytes()))
+        .collect();
+    let multisig = MultisigSignature::from_participants(1, 2, participants.clone()).unwrap();
+
+    let mut tx = TransactionMother::simple_payment().build().unwrap();
+    tx.header_mut().sender = multisig.address().unwrap();
+    let tx_bytes = tx.encode().unwrap();
+
+    // Each participant signs the transaction separately
+    let partially_signed: Vec<SignedTransaction> = [0, 2]
+        .iter()
+        .map(|&i| SignedTransaction {
+            transaction: tx.clone(),
+            signature: None,
+            auth_address: None,
+            multisignature: Some(
+                multisig
+                    .apply_subsignature(
+                        &participants[i],
+                        signing_keys[i].sign(&tx_bytes).to_bytes(),
+                    )
+                    .unwrap(),
+            ),
+            logic_signature: None,
+            unknown_fields: Default::default(),
+        })
+        .collect();
+    assert!(!partially_signed[0]
+        .multisignature
+        .as_ref()
+        .unwrap()
+        .is_fully_signed());
+
+    assert_eq!(
+        partially_signed[0].verify().unwrap_err().to_string(),
+        "Multisig signature has 1 of the 2 required signatures"
+    );
+
+    let signed_tx = partially_signed[0]
+        .merge_multisignatures(&partially_signed[1])
+        .unwrap();
+    let merged_multisig = signed_tx.multisignature.as_ref().unwrap();
+    assert!(merged_multisig.is_fully_signed());
+    assert!(merged_multisig.subsignatures[0].signature.is_some());
+    assert!(merged_multisig.subsignatures[1].signature.is_none());
+    assert!(merged_multisig.subsignatures[2].signature.is_some());
+
+    let encoded_stx = signed_tx.encode().unwrap();
+    let decoded_stx = SignedTransaction::decode(&encoded_stx).unwrap();
+    assert_eq!(decoded_stx, signed_tx);
+    decoded_stx.verify().unwrap();
+
+    let value = rmpv::decode::read_value(&mut encoded_stx.as_slice()).unwrap();
+    let keys: Vec<&str> = value
+        .as_map()
+        .unwrap()
+        .iter()
+        .map(|(k, _)| k.as_str().unwrap())
+        .collect();
+    assert_eq!(keys, vec!["msig", "txn"]);
+    let msig_keys: Vec<&str> = value.as_map().unwrap()[0]
+        .1
+        .as_map()
+        .unwrap()
+        .iter()
+        .map(|(k, _)| k.as_str().unwrap())
+        .collect();
+    assert_eq!(msig_keys, vec!["subsig", "thr", "v"]);
+}
+
+#[test]
+fn test_multisig_merge_errors() {
+    let participants = vec![AddressMother::address(), AddressMother::neil()];
+    let multisig = MultisigSignature::from_participants(1, 1, participants.clone()).unwrap();
+
+    let signed_a = multisig
+        .apply_subsignature(&participants[0], [1; ALGORAND_SIGNATURE_BYTE_LENGTH])
+        .unwrap();
+    let signed_b = multisig
+        .apply_subsignature(&participants[0], [2; ALGORAND_SIGNATURE_BYTE_LENGTH])
+        .unwrap();
+    assert_eq!(
+        signed_a.merge(&signed_b).unwrap_err().to_string(),
+        format!(
+            "Multisig signatures contain conflicting signatures for participant {}",
+            participants[0]
+        )
+    );
+
+    let other_multisig = MultisigSignature::from_participants(1, 2, participants.clone()).unwrap();
+    assert_eq!(
+        multisig.merge(&other_multisig).unwrap_err().to_string(),
+        "Multisig signatures are for different multisig accounts"
+    );
+
+    assert_eq!(
+        multisig
+            .apply_subsignature(
+                &AddressMother::zero_address(),
+                [1; ALGORAND_SIGNATURE_BYTE_LENGTH]
+            )
+            .unwrap_err()
+            .to_string(),
+        format!(
+            "Address {} is not a participant of the multisig account",
+            AddressMother::zero_address()
+        )
+    );
+}
+
+#[test]
+fn test_app_address() {
+    // Mainnet applications
+    for (app_id, address) in [
+        (
+            1002541853,
+            "XSKED5VKZZCSYNDWXZJI65JM2HP7HZFJWCOBIMOONKHTK5UVKENBNVDEYM",
+        ),
+        (
+            552635992,
+            "KGDZ3FJKPS333XU2CG3KGNALVQSVIQJ3KQN4LDMR5SHWACBFARIEU5KXU4",
+        ),
+        (
+            1284326447,
+            "JP3ENKDQC2BOYRMLFGKBS7RB2IVNF7VNHCFHVTRNHOENRQ6R4UN7MCNXPI",
+        ),
+    ] {
+        assert_eq!(Address::from_app_id(app_id).to_string(), address);
+    }
+}
+
+#[test]
+fn test_program_address() {
+    // `#pragma version 1; int 1`
+    let program = vec![1, 32, 1, 1, 34];
+    assert_eq!(
+        Address::from_program(&program).to_string(),
+        "6Z3C3LDVWGMX23BMSYMANACQOSINPFIRF77H7N3AWJZYV6OH6GWTJKVMXY"
+    );
+
+    let lsig = LogicSignature::new(program.clone(), None);
+    assert_eq!(lsig.address(), Address::from_program(&program));
+    assert_eq!(
+        lsig.bytes_to_sign(),
+        [b"Program".as_slice(), &program].concat()
+    );
+    assert_eq!(
+        lsig.program_data_to_sign(b"data"),
+        [
+            b"ProgData".as_slice(),
+            &lsig.address().pub_key,
+            b"data".as_slice()
+        ]
+        .concat()
+    );
+}
+
+#[test]
+fn test_escrow_logic_signature_encoding() {
+    let lsig = LogicSignature::new(vec![1, 32, 1, 1, 34], Some(vec![b"arg".to_vec()]));
+    assert!(!lsig.is_delegated());
+
+    let mut tx = TransactionMother::simple_payment().build().unwrap();
+    tx.header_mut().sender = lsig.address();
+
+    let signed_tx = SignedTransaction {
+        transaction: tx,
+        signature: None,
+        auth_address: None,
+        multisignature: None,
+        logic_signature: Some(lsig),
+        unknown_fields: Default::default(),
+    };
+    let encoded_stx = signed_tx.encode().unwrap();
+    let decoded_stx = SignedTransaction::decode(&encoded_stx).unwrap();
+    assert_eq!(decoded_stx, signed_tx);
+    decoded_stx.verify().unwrap();
+
+    let value = rmpv::decode::read_value(&mut encoded_stx.as_slice()).unwrap();
+    let keys: Vec<&str> = value
+        .as_map()
+        .unwrap()
+        .iter()
+        .map(|(k, _)| k.as_str().unwrap())
+        .collect();
+    assert_eq!(keys, vec!["lsig", "txn"]);
+    assert_eq!(
+        value.as_map().unwrap()[0].1,
+        rmpv::Value::Map(vec![
+            (
+                "arg".into(),
+                rmpv::Value::Array(vec![rmpv::Value::Binary(b"arg".to_vec())])
+            ),
+            ("l".into(), rmpv::Value::Binary(vec![1, 32, 1, 1, 34])),
+        ])
+    );
+}
+
+#[test]
+fn test_delegated_logic_signature_encoding() {
+    let signing_key = SigningKey::from_bytes(&[1; 32]);
+    let delegator = Address::from_pubkey(&signing_key.verifying_key().to_bytes());
+
+    let mut lsig = LogicSignature::new(vec![1, 32, 1, 1, 34], None);
+    let signature = signing_key.sign(&lsig.bytes_to_sign());
+    lsig.signature = Some(signature.to_bytes());
+    assert!(lsig.is_delegated());
+    assert!(signing_key
+        .verifying_key()
+        .verify(&lsig.bytes_to_sign(), &signature)
+        .is_ok());
+
+    // The delegating account remains the sender of the transaction
+    let mut tx = TransactionMother::simple_payment().build().unwrap();
+    tx.header_mut().sender = delegator;
+
+    let signed_tx = SignedTransaction {
+        transaction: tx,
+        signature: None,
+        auth_address: None,
+        multisignature: None,
+        logic_signature: Some(lsig),
+        unknown_fields: Default::default(),
+    };
+    let encoded_stx = signed_tx.encode().unwrap();
+    let decoded_stx = SignedTransaction::decode(&encoded_stx).unwrap();
+    assert_eq!(decoded_stx, signed_tx);
+    decoded_stx.verify().unwrap();
+}
+
+#[test]
+fn test_multisig_delegated_logic_signature_encoding() {
+    let signing_keys: Vec<SigningKey> = (1..=2u8)
+        .map(|i| SigningKey::from_bytes(&[i; 32]))
+        .collect();
+    let participants: Vec<Address> = signing_keys
+        .iter()
+        .map(|key| Address::from_pubkey(&key.verifying_key().to_bytes()))
+        .collect();
+    let multisig = MultisigSignature::from_participants(1, 2, participants.clone()).unwrap();
+    let delegator = multisig.address().unwrap();
+
+    // The participants sign the program together with the address of the multisig account
+    let mut lsig = LogicSignature::new(vec![1, 32, 1, 1, 34], None);
+    let bytes_to_sign = lsig.multisig_bytes_to_sign(&delegator);
+    assert!(bytes_to_sign.starts_with(b"MsigProgram"));
+    let mut logic_multisig = multisig.clone();
+    for (participant, signing_key) in participants.iter().zip(&signing_keys) {
+        logic_multisig = logic_multisig
+            .apply_subsignature(participant, signing_key.sign(&bytes_to_sign).to_bytes())
+            .unwrap();
+    }
+    lsig.logic_multisignature = Some(logic_multisig.clone());
+    assert!(lsig.is_delegated());
+
+    let mut tx = TransactionMother::simple_payment().build().unwrap();
+    tx.header_mut().sender = delegator;
+
+    let signed_tx = SignedTransaction {
+        transaction: tx,
+        signature: None,
+        auth_address: None,
+        multisignature: None,
+        logic_signature: Some(lsig.clone()),
+        unknown_fields: Default::default(),
+    };
+    let encoded_stx = signed_tx.encode().unwrap();
+    let decoded_stx = SignedTransaction::decode(&encoded_stx).unwrap();
+    assert_eq!(decoded_stx, signed_tx);
+    decoded_stx.verify().unwrap();
+
+    let value = rmpv::decode::read_value(&mut encoded_stx.as_slice()).unwrap();
+    let lsig_keys: Vec<&str> = value.as_map().unwrap()[0]
+        .1
+        .as_map()
+        .unwrap()
+        .iter()
+        .map(|(k, _)| k.as_str().unwrap())
+        .collect();
+    assert_eq!(lsig_keys, vec!["l", "lmsig"]);
+
+    // Subsignatures of the program alone are checked as a plain multisig delegation
+    let mut program_multisig = multisig;
+    for (participant, signing_key) in participants.iter().zip(&signing_keys) {
+        program_multisig = program_multisig
+            .apply_subsignature(
+                participant,
+                signing_key.sign(&lsig.bytes_to_sign()).to_bytes(),
+            )
+            .unwrap();
+    }
+    let mut program_signed = signed_tx.clone();
+    let program_lsig = program_signed.logic_signature.as_mut().unwrap();
+    program_lsig.logic_multisignature = Some(program_multisig.clone());
+    assert!(program_signed.verify().is_err());
+    let program_lsig = program_signed.logic_signature.as_mut().unwrap();
+    program_lsig.logic_multisignature = None;
+    program_lsig.multisignature = Some(program_multisig);
+    program_signed.verify().unwrap();
+
+    let mut both = signed_tx;
+    both.logic_signature.as_mut().unwrap().multisignature = Some(logic_multisig);
+    assert_eq!(
+        both.verify().unwrap_err().to_string(),
+        "Logic signature may only have one of a signature, multisig signature or logic multisig signature"
+    );
+}
+
+#[test]
+fn test_sign_and_verify_transaction() {
+    let signing_key = SigningKey::from_bytes(&[1; 32]);
+    let mut tx = TransactionMother::simple_payment().build().unwrap();
+    tx.header_mut().sender = signing_key.address();
+
+    let signed_tx = tx.sign(&signing_key).unwrap();
+    assert_eq!(signed_tx.auth_address, None);
+    assert_eq!(
+        signed_tx.signature.unwrap(),
+        signing_key.sign(&tx.encode().unwrap()).to_bytes()
+    );
+    signed_tx.verify().unwrap();
+
+    // The signature doesn't cover a modified transaction
+    let mut tampered_tx = signed_tx.clone();
+    tampered_tx.transaction.header_mut().fee = Some(2000);
+    assert_eq!(
+        tampered_tx.verify().unwrap_err().to_string(),
+        format!(
+            "Signature is not valid for address {}",
+            signing_key.address()
+        )
+    );
+
+    let unsigned_tx = SignedTransaction {
+        signature: None,
+        ..signed_tx
+    };
+    assert_eq!(
+        unsigned_tx.verify().unwrap_err().to_string(),
+        "Transaction is not signed"
+    );
+}
+
+#[test]
+fn test_sign_and_verify_rekeyed_transaction() {
+    let signing_key = SigningKey::from_bytes(&[1; 32]);
+    let tx = TransactionMother::simple_payment().build().unwrap();
+
+    // The signer isn't the sender, so the signer is the auth address
+    let signed_tx = tx.sign(&signing_key).unwrap();
+    assert_eq!(signed_tx.auth_address, Some(signing_key.address()));
+    signed_tx.verify().unwrap();
+
+    let wrong_auth_tx = SignedTransaction {
+        auth_address: None,
+        ..signed_tx
+    };
+    assert!(wrong_auth_tx.verify().is_err());
+}
+
+#[test]
+fn test_sign_with_custom_signer() {
+    // A signer which holds its key elsewhere, such as in an HSM
+    struct RemoteSigner {
+        key: SigningKey,
+    }
+
+    impl TransactionSigner for RemoteSigner {
+        fn address(&self) -> Address {
+            Address::from_pubkey(&self.key.verifying_key().to_bytes())
+        }
+
+        fn sign_bytes(
+            &self,
+            bytes: &[u8],
+        ) -> Result<[u8; ALGORAND_SIGNATURE_BYTE_LENGTH], AlgoKitTransactError> {
+            Ok(self.key.sign(bytes).to_bytes())
+        }
+    }
+
+    let signer: Box<dyn TransactionSigner> = Box::new(RemoteSigner {
+        key: SigningKey::from_bytes(&[2; 32]),
+    });
+    let mut tx = TransactionMother::simple_payment().build().unwrap();
+    tx.header_mut().sender = signer.address();
+
+    let signed_tx = tx.sign(signer.as_ref()).unwrap();
+    signed_tx.verify().unwrap();
+}
+
+#[test]
+fn test_zero_address() {
+    let addr = AddressMother::zero_address();
+    assert_eq!(
+        addr.to_string(),
+        "AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAY5HFKQ"
+    );
+
+    let addr_from_str = addr.to_string().parse::<Address>().unwrap();
+    assert_eq!(addr, addr_from_str);
+}
+
+#[test]
+fn test_address() {
+    let addr = AddressMother::address();
+    assert_eq!(
+        addr.to_string(),
+        "RIMARGKZU46OZ77OLPDHHPUJ7YBSHRTCYMQUC64KZCCMESQAFQMYU6SL2Q"
+    );
+
+    let addr_from_str = addr.to_string().parse::<Address>().unwrap();
+    assert_eq!(addr, addr_from_str);
+}
+
+#[test]
+fn test_invalid_address() {
+    let address = AddressMother::address().to_string();
+    let invalid_reason = |s: &str| match s.parse::<Address>() {
+        Err(AlgoKitTransactError::InvalidAddress(reason)) => reason,
+        result => panic!("expected an invalid address error, got {:?}", result),
+    };
+
+    assert_eq!(
+        invalid_reason(&address[..57]),
+        "address length is 57 but must be 58"
+    );
+    assert_eq!(invalid_reason(""), "address length is 0 but must be 58");
+    assert_eq!(
+        invalid_reason(&address.to_lowercase()),
+        "address must be uppercase"
+    );
+    assert_eq!(
+        invalid_reason(&format!("{}1", &address[..57])),
+        "address contains invalid base32 character '1'"
+    );
+    assert_eq!(
+        invalid_reason(&format!("{}=", &address[..57])),
+        "address contains invalid base32 character '='"
+    );
+    assert_eq!(
+        invalid_reason(&format!("é{}", &address[..56])),
+        "address contains invalid base32 character 'é'"
+    );
+    // The last character encodes 3 bits of the checksum followed by 2 zero bits
+    assert_eq!(
+        invalid_reason(&format!("{}R", &address[..57])),
+        "address is not canonically encoded"
+    );
+    assert_eq!(
+        invalid_reason(&format!("{}A", &address[..57])),
+        "checksum is invalid"
+    );
+}
+
+proptest! {
+    #[test]
+    fn prop_address_round_trip(pub_key in any::<[u8; 32]>()) {
+        let address = Address::from_pubkey(&pub_key);
+        let encoded = address.to_string();
+        prop_assert_eq!(encoded.len(), 58);
+        prop_assert_eq!(encoded.parse::<Address>().unwrap(), address);
+    }
+
+    #[test]
+    fn prop_address_from_str_never_panics(s in "\\PC{0,70}") {
+        if let Ok(address) = s.parse::<Address>() {
+            prop_assert_eq!(address.to_string(), s);
+        }
+    }
+
+    #[test]
+    fn prop_address_from_base32_str(s in "[A-Z2-7]{58}") {
+        match s.parse::<Address>() {
+            Ok(address) => prop_assert_eq!(address.to_string(), s),
+            Err(err) => prop_assert!(matches!(err, AlgoKitTransactError::InvalidAddress(_))),
+        }
+    }
+
+    #[test]
+    fn prop_address_corrupted_char(pub_key in any::<[u8; 32]>(), index in 0..58usize, offset in 1..32u8) {
+        let encoded = Address::from_pubkey(&pub_key).to_string();
+        let alphabet = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+        let position = alphabet.iter().position(|c| *c == encoded.as_bytes()[index]).unwrap();
+        let mut corrupted = encoded.into_bytes();
+        corrupted[index] = alphabet[(position + offset as usize) % 32];
+        let corrupted = String::from_utf8(corrupted).unwrap();
+        prop_assert!(corrupted.parse::<Address>().is_err());
+    }
+}
+
+const TEST_MNEMONIC: &str = "cactus amount account expect army achieve embark anxiety lift crouch mandate abstract captain setup party bench tissue gate arrive random deal mansion wedding abandon curtain";
+
+#[test]
+fn test_mnemonic_from_seed() {
+    assert_eq!(
+        mnemonic_from_seed(&[0u8; 32]),
+        format!("{} invest", ["abandon"; 24].join(" "))
+    );
+
+    let seed: [u8; 32] = core::array::from_fn(|i| i as u8);
+    assert_eq!(mnemonic_from_seed(&seed), TEST_MNEMONIC);
+}
+
+#[test]
+fn test_seed_from_mnemonic() {
+    let seed: [u8; 32] = core::array::from_fn(|i| i as u8);
+    assert_eq!(seed_from_mnemonic(TEST_MNEMONIC).unwrap(), seed);
+
+    let padded = format!("  {}\n", TEST_MNEMONIC.to_uppercase().replace(' ', "  "));
+    assert_eq!(seed_from_mnemonic(&padded).unwrap(), seed);
+
+    let seed = AddressMother::address().pub_key;
+    assert_eq!(
+        seed_from_mnemonic(&mnemonic_from_seed(&seed)).unwrap(),
+        seed
+    );
+}
+
+#[test]
+fn test_seed_from_invalid_mnemonic() {
+    let words: Vec<&str> = TEST_MNEMONIC.split(' ').collect();
+
+    let result = seed_from_mnemonic(&words[..24].join(" "));
+    assert!(matches!(
+        result,
+        Err(AlgoKitTransactError::InvalidMnemonicLength(24))
+    ));
+
+    let unknown_word = TEST_MNEMONIC.replace("cactus", "cactuses");
+    let result = seed_from_mnemonic(&unknown_word);
+    assert!(matches!(
+        result,
+        Err(AlgoKitTransactError::InvalidMnemonicWord(word)) if word == "cactuses"
+    ));
+
+    let wrong_checksum = TEST_MNEMONIC.replace("curtain", "abandon");
+    let result = seed_from_mnemonic(&wrong_checksum);
+    assert!(matches!(
+        result,
+        Err(AlgoKitTransactError::InvalidMnemonicChecksum)
+    ));
+
+    // The last key word only holds 8 bits of the seed, the remaining bits must be unset
+    let overflowing_key_word = TEST_MNEMONIC.replace("wedding abandon", "wedding zoo");
+    let result = seed_from_mnemonic(&overflowing_key_word);
+    assert!(matches!(
+        result,
+        Err(AlgoKitTransactError::InvalidMnemonicChecksum)
+    ));
+}
+
+#[test]
+fn test_pay_transaction_id() {
+    let expected_tx_id_raw = [
+        35, 93, 0, 170, 96, 221, 1, 74, 119, 147, 131, 116, 7, 31, 225, 40, 215, 47, 44, 120, 128,
+        245, 41, 65, 116, 255, 147, 64, 90, 80, 147, 223,
+    ];
+    let expected_tx_id = "ENOQBKTA3UAUU54TQN2AOH7BFDLS6LDYQD2SSQLU76JUAWSQSPPQ";
+
+    let tx_builder = TransactionMother::payment_with_note();
+    let payment_tx = tx_builder.build().unwrap();
+    let signed_tx = SignedTransaction {
+        transaction: payment_tx.clone(),
+        signature: Some([0; ALGORAND_SIGNATURE_BYTE_LENGTH]),
+        auth_address: None,
+        multisignature: None,
+        logic_signature: None,
+        unknown_fields: Default::default(),
+    };
+
+    assert_eq!(payment_tx.id().unwrap(), expected_tx_id);
+    assert_eq!(payment_tx.id_raw().unwrap(), expected_tx_id_raw);
+    assert_eq!(signed_tx.id().unwrap(), expected_tx_id);
+    assert_eq!(signed_tx.id_raw().unwrap(), expected_tx_id_raw);
+}
+
+#[test]
+fn test_estimate_transaction_size() {
+    let tx_builder = TransactionMother::simple_payment();
+    let payment_tx = tx_builder.build().unwrap();
+    let encoding_length = payment_tx.encode_raw().unwrap().len();
+    let estimation = payment_tx.estimate_size().unwrap();
+
+    let signed_tx = SignedTransaction {
+        transaction: payment_tx.clone(),
+        signature: Some([0; ALGORAND_SIGNATURE_BYTE_LENGTH]),
+        auth_address: None,
+        multisignature: None,
+        logic_signature: None,
+        unknown_fields: Default::default(),
+    };
+    let actual_size = signed_tx.encode().unwrap().len();
+
+    assert_eq!(
+        estimation,
+        encoding_length + ALGORAND_SIGNATURE_ENCODING_INCR
+    );
+    assert_eq!(estimation, actual_size);
+}
+
+#[test]
+fn test_min_fee() {
+    let txn: Transaction = TransactionMother::simple_payment().build().unwrap();
+
+    let updated_transaction = txn
+        .assign_fee(FeeParams {
+            fee_per_byte: 0,
+            min_fee: 1000,
+            extra_fee: None,
+            max_fee: None,
+        })
+        .unwrap();
+    assert_eq!(updated_transaction.header().fee, Some(1000));
+}
+
+#[test]
+fn test_extra_fee() {
+    let txn: Transaction = TransactionMother::simple_payment().build().unwrap();
+
+    let updated_transaction = txn
+        .assign_fee(FeeParams {
+            fee_per_byte: 1,
+            min_fee: 1000,
+            extra_fee: Some(500),
+            max_fee: None,
+        })
+        .unwrap();
+    assert_eq!(updated_transaction.header().fee, Some(1500));
+}
+
+#[test]
+fn test_max_fee() {
+    let txn: Transaction = TransactionMother::simple_payment().build().unwrap();
+
+    let result = txn.assign_fee(FeeParams {
+        fee_per_byte: 10,
+        min_fee: 500,
+        extra_fee: None,
+        max_fee: Some(1000),
+    });
+
+    assert!(result.is_err());
+    let err: crate::AlgoKitTransactError = result.unwrap_err();
+    let msg = format!("{}", err);
+    assert!(
+        msg == "Transaction fee 2470 µALGO is greater than max fee 1000 µALGO",
+        "Unexpected error message: {}",
+        msg
+    );
+}
+
+#[test]
+fn test_calculate_fee() {
+    let txn: Transaction = TransactionMother::simple_payment().build().unwrap();
+
+    let updated_transaction = txn
+        .assign_fee(FeeParams {
+            fee_per_byte: 5,
+            min_fee: 1000,
+            extra_fee: None,
+            max_fee: None,
+        })
+        .unwrap();
+
+    assert_eq!(updated_transaction.header().fee, Some(1235));
+}
+
+fn group_fees(
+    transactions: &[Transaction],
+    params: Vec<TransactionFeeParams>,
+) -> Result<Vec<u64>, AlgoKitTransactError> {
+    let with_fees = transactions.assign_fees(&GroupFeeParams {
+        fee_per_byte: 0,
+        min_fee: 1000,
+        transactions: params,
+    })?;
+    Ok(with_fees
+        .iter()
+        .map(|tx| tx.header().fee.unwrap())
+        .collect())
+}
+
+#[test]
+fn test_group_fees_pooled() {
+    let txs = TransactionGroupMother::group_of(3);
+    let covered = TransactionFeeParams {
+        max_fee: Some(0),
+        ..Default::default()
+    };
+
+    assert_eq!(
+        group_fees(&txs, vec![Default::default(); 3]).unwrap(),
+        vec![1000, 1000, 1000]
+    );
+    assert_eq!(
+        group_fees(
+            &txs,
+            vec![Default::default(), covered.clone(), covered.clone()]
+        )
+        .unwrap(),
+        vec![3000, 0, 0]
+    );
+    assert_eq!(
+        group_fees(
+            &txs,
+            vec![
+                covered.clone(),
+                TransactionFeeParams {
+                    max_fee: Some(1500),
+                    ..Default::default()
+                },
+                Default::default()
+            ]
+        )
+        .unwrap(),
+        vec![0, 1500, 1500]
+    );
+}
+
+#[test]
+fn test_group_fees_extra_fee() {
+    let txs = TransactionGroupMother::group_of(2);
+
+    assert_eq!(
+        group_fees(
+            &txs,
+            vec![
+                TransactionFeeParams {
+                    extra_fee: Some(1000),
+                    ..Default::default()
+                },
+                TransactionFeeParams {
+                    max_fee: Some(0),
+                    ..Default::default()
+                },
+            ]
+        )
+        .unwrap(),
+        vec![2000, 0]
+    );
+}
+
+#[test]
+fn test_group_fees_inner_transactions() {
+    let txs = TransactionGroupMother::group_of(3);
+    let app_call = TransactionFeeParams {
+        max_fee: Some(2000),
+        inner_transactions: 3,
+        ..Default::default()
+    };
+    let capped = TransactionFeeParams {
+        max_fee: Some(1000),
+        ..Default::default()
+    };
+
+    assert_eq!(
+        group_fees(
+            &txs,
+            vec![capped.clone(), app_call.clone(), Default::default()]
+        )
+        .unwrap(),
+        vec![1000, 2000, 3000]
+    );
+
+    let err = group_fees(&txs, vec![capped.clone(), app_call, capped]).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Transaction group fees are 2000 µALGO short of the required 6000 µALGO, given the max fees of the transactions"
+    );
+}
+
+#[test]
+fn test_group_fees_params_length() {
+    let txs = TransactionGroupMother::group_of(2);
+
+    assert!(matches!(
+        group_fees(&txs, vec![Default::default()]),
+        Err(AlgoKitTransactError::InputError(_))
+    ));
+}
+
+#[test]
+fn test_multi_transaction_group() {
+    let expected_group: [u8; 32] = BASE64_STANDARD
+        .decode(String::from("uJA6BWzZ5g7Ve0FersqCLWsrEstt6p0+F3bNGEKH3I4="))
+        .unwrap()
+        .try_into()
+        .unwrap();
+    let txs = TransactionGroupMother::testnet_payment_group();
+
+    let grouped_txs = txs.assign_group().unwrap();
+
+    assert_eq!(grouped_txs.len(), txs.len());
+    for grouped_tx in grouped_txs.iter() {
+        assert_eq!(grouped_tx.header().group.unwrap(), expected_group);
+    }
+    assert_eq!(
+        &grouped_txs[0].id().unwrap(),
+        "6SIXGV2TELA2M5RHZ72CVKLBSJ2OPUAKYFTUUE27O23RN6TFMGHQ"
+    );
+    assert_eq!(
+        &grouped_txs[1].id().unwrap(),
+        "7OY3VQXJCDSKPMGEFJMNJL2L3XIOMRM2U7DM2L54CC7QM5YBFQEA"
+    );
+}
+
+#[test]
+fn test_single_transaction_group() {
+    let expected_group: [u8; 32] = BASE64_STANDARD
+        .decode(String::from("LLW3AwgyXbwoMMBNfLSAGHtqoKtj/c7MjNMR0MGW6sg="))
+        .unwrap()
+        .try_into()
+        .unwrap();
+    let txs: Vec<Transaction> = TransactionGroupMother::group_of(1);
+
+    let grouped_txs = txs.assign_group().unwrap();
+
+    assert_eq!(grouped_txs.len(), txs.len());
+    for grouped_tx in grouped_txs.iter() {
+        assert_eq!(grouped_tx.header().group.unwrap(), expected_group);
+    }
+}
+
+#[test]
+fn test_transaction_group_too_big() {
+    let txs: Vec<Transaction> = TransactionGroupMother::group_of(MAX_TX_GROUP_SIZE + 1);
+
+    let result = txs.assign_group();
+
+    let error = result.unwrap_err();
+    assert!(error
+        .to_string()
+        .starts_with("Transaction group size exceeds the max limit"));
+}
+
+#[test]
+fn test_transaction_group_too_small() {
+    let txs: Vec<Transaction> = TransactionGroupMother::group_of(0);
+
+    let result = txs.assign_group();
+
+    let error = result.unwrap_err();
+    assert!(error
+        .to_string()
+        .starts_with("Transaction group size cannot be 0"));
+}
+
+#[test]
+fn test_transaction_group_already_set() {
+    let tx: Transaction = TransactionMother::simple_payment()
+        .header(
+            TransactionHeaderMother::simple_testnet()
+                .group(
+                    BASE64_STANDARD
+                        .decode(String::from("y1Hz6KZhHJI4TZLwZqXO3TFgXVQdD/1+c6BLk3wTW6Q="))
+                        .unwrap()
+                        .try_into()
+                        .unwrap(),
                 )
                 .build()
                 .unwrap(),
@@ -330,30 +1772,458 @@ fn test_transaction_group_already_set() {
 }
 
 #[test]
-fn test_transaction_group_encoding() {
-    let grouped_txs = TransactionGroupMother::testnet_payment_group()
-        .assign_group()
-        .unwrap();
+fn test_transaction_group_encoding() {
+    let grouped_txs = TransactionGroupMother::testnet_payment_group()
+        .assign_group()
+        .unwrap();
+
+    let encoded_grouped_txs = grouped_txs
+        .iter()
+        .map(|tx| tx.encode())
+        .collect::<Result<Vec<Vec<u8>>, _>>()
+        .unwrap();
+    let decoded_grouped_txs = encoded_grouped_txs
+        .iter()
+        .map(|tx| Transaction::decode(tx))
+        .collect::<Result<Vec<Transaction>, _>>()
+        .unwrap();
+
+    for ((grouped_tx, encoded_tx), decoded_tx) in grouped_txs
+        .iter()
+        .zip(encoded_grouped_txs.into_iter())
+        .zip(decoded_grouped_txs.iter())
+    {
+        assert_eq!(encoded_tx, grouped_tx.encode().unwrap());
+        assert_eq!(decoded_tx, grouped_tx);
+    }
+}
+
+#[test]
+fn test_signed_transaction_decode_errors() {
+    let mut missing_txn = Vec::new();
+    rmp::encode::write_map_len(&mut missing_txn, 1).unwrap();
+    rmp::encode::write_str(&mut missing_txn, "sig").unwrap();
+    rmp::encode::write_bin(&mut missing_txn, &[0; 64]).unwrap();
+    assert_eq!(
+        SignedTransaction::decode(&missing_txn)
+            .unwrap_err()
+            .to_string(),
+        "expected signed transaction to have a txn field"
+    );
+
+    assert!(SignedTransaction::decode(&[]).is_err());
+    assert!(SignedTransaction::decode(&[0xc0]).is_err());
+    assert!(SignedTransaction::decode(&[0x81, 0xa3, b't', b'x', b'n', 0x80]).is_err());
+    assert!(Transaction::decode(b"TX").is_err());
+}
+
+proptest! {
+    #[test]
+    fn prop_decode_arbitrary_bytes(bytes in proptest::collection::vec(any::<u8>(), 0..256)) {
+        let _ = Transaction::decode(&bytes);
+        let _ = SignedTransaction::decode(&bytes);
+        for model_type in supported_models() {
+            let _ = decode_msgpack_to_json(model_type, &bytes);
+        }
+    }
+
+    #[test]
+    fn prop_decode_corrupted_signed_transaction(index in any::<prop::sample::Index>(), byte in any::<u8>()) {
+        let mut encoded = SignedTransaction {
+            transaction: TransactionMother::simple_payment().build().unwrap(),
+            signature: Some([1; ALGORAND_SIGNATURE_BYTE_LENGTH]),
+            auth_address: None,
+            multisignature: None,
+            logic_signature: None,
+            unknown_fields: Default::default(),
+        }
+        .encode()
+        .unwrap();
+        let index = index.index(encoded.len());
+        encoded[index] = byte;
+        if let Ok(decoded) = SignedTransaction::decode(&encoded) {
+            prop_assert!(decoded.encode().is_ok());
+        }
+    }
+}
+
+fn encode_msgpack_value(value: &rmpv::Value) -> Vec<u8> {
+    let mut buf = Vec::new();
+    rmpv::encode::write_value(&mut buf, value).unwrap();
+    buf
+}
+
+fn non_canonical_reason(result: Result<impl std::fmt::Debug, AlgoKitTransactError>) -> String {
+    match result {
+        Err(AlgoKitTransactError::NonCanonicalEncoding(reason)) => reason,
+        result => panic!("expected a non-canonical encoding error, got {:?}", result),
+    }
+}
+
+#[test]
+fn test_decode_strict() {
+    let tx = TransactionMother::simple_payment().build().unwrap();
+    let encoded = tx.encode().unwrap();
+    let raw = tx.encode_raw().unwrap();
+    assert_eq!(Transaction::decode_strict(&encoded).unwrap(), tx);
+    assert_eq!(Transaction::decode_strict(&raw).unwrap(), tx);
+
+    let rmpv::Value::Map(fields) = rmpv::decode::read_value(&mut raw.as_slice()).unwrap() else {
+        panic!("expected a map");
+    };
+
+    let mut unsorted = fields.clone();
+    unsorted.swap(0, 1);
+    assert_eq!(
+        non_canonical_reason(Transaction::decode_strict(&encode_msgpack_value(
+            &rmpv::Value::Map(unsorted)
+        ))),
+        "field fee is out of order"
+    );
+
+    let mut zero_field = fields.clone();
+    let position = zero_field
+        .iter()
+        .position(|(k, _)| k.as_str() > Some("lx"))
+        .unwrap();
+    zero_field.insert(
+        position,
+        (rmpv::Value::from("lx"), rmpv::Value::Binary(vec![0; 32])),
+    );
+    let zero_field = encode_msgpack_value(&rmpv::Value::Map(zero_field));
+    assert!(Transaction::decode(&zero_field).is_ok());
+    assert_eq!(
+        non_canonical_reason(Transaction::decode_strict(&zero_field)),
+        "field lx is empty or unknown and must be omitted"
+    );
+
+    // amt (101000) encoded as a uint64 rather than a uint32
+    let amt = [0xa3, b'a', b'm', b't', 0xce, 0x00, 0x01, 0x8a, 0x88];
+    let amt_offset = raw.windows(amt.len()).position(|w| w == amt).unwrap();
+    let wide_amt = [
+        &raw[..amt_offset + 4],
+        &[0xcf, 0, 0, 0, 0, 0x00, 0x01, 0x8a, 0x88],
+        &raw[amt_offset + amt.len()..],
+    ]
+    .concat();
+    assert_eq!(Transaction::decode(&wide_amt).unwrap(), tx);
+    assert_eq!(
+        non_canonical_reason(Transaction::decode_strict(&wide_amt)),
+        format!("non-minimal encoding at byte {}", amt_offset + 4)
+    );
+
+    let trailing = [raw.as_slice(), &[0xc0]].concat();
+    assert_eq!(
+        non_canonical_reason(Transaction::decode_strict(&trailing)),
+        format!("unexpected trailing bytes at byte {}", raw.len())
+    );
+}
+
+#[test]
+fn test_validate() {
+    let params = ConsensusParams::default();
+    assert_eq!(params, ConsensusParams::mainnet());
+    for tx in [
+        TransactionMother::simple_payment().build().unwrap(),
+        TransactionMother::payment_with_note().build().unwrap(),
+        TransactionMother::opt_in_asset_transfer().build().unwrap(),
+        TransactionMother::asset_create().build().unwrap(),
+        TransactionMother::asset_reconfigure().build().unwrap(),
+        TransactionMother::asset_destroy().build().unwrap(),
+        TransactionMother::asset_freeze().build().unwrap(),
+        TransactionMother::application_create().build().unwrap(),
+        TransactionMother::application_call().build().unwrap(),
+        TransactionMother::online_key_registration()
+            .build()
+            .unwrap(),
+        TransactionMother::state_proof(),
+        TransactionMother::heartbeat(),
+    ] {
+        assert!(tx.validate(&params).is_ok(), "{:?}", tx.validate(&params));
+    }
+
+    let violations = |tx: Transaction| match tx.validate(&params) {
+        Err(AlgoKitTransactError::InvalidTransaction(violations)) => violations,
+        result => panic!("expected an invalid transaction but got {:?}", result),
+    };
+
+    let header = TransactionHeaderMother::simple_testnet()
+        .sender(AddressMother::zero_address())
+        .first_valid(100)
+        .last_valid(50)
+        .note(vec![0; 1025])
+        .build()
+        .unwrap();
+    let receiver = AddressMother::address();
+    let tx = TransactionMother::simple_payment()
+        .header(header)
+        .receiver(receiver.clone())
+        .close_remainder_to(receiver)
+        .build()
+        .unwrap();
+    assert_eq!(
+        violations(tx),
+        [
+            "sender must not be the zero address",
+            "last valid round 50 is before first valid round 100",
+            "note is 1025 bytes but must not exceed 1024",
+            "close remainder to must not be the receiver",
+        ]
+    );
+
+    // Rekeying to the sender is valid, as it undoes a previous rekey
+    let sender = AddressMother::address();
+    let header = TransactionHeaderMother::simple_testnet()
+        .sender(sender.clone())
+        .first_valid(100)
+        .last_valid(1101)
+        .rekey_to(sender)
+        .build()
+        .unwrap();
+    let tx = TransactionMother::simple_payment()
+        .header(header)
+        .build()
+        .unwrap();
+    assert_eq!(
+        violations(tx.clone()),
+        ["validity window of 1001 rounds exceeds the max of 1000"]
+    );
+    let relaxed = ConsensusParams {
+        max_txn_life: 1001,
+        ..ConsensusParams::mainnet()
+    };
+    assert!(tx.validate(&relaxed).is_ok());
+
+    let mut tx = TransactionMother::asset_create().build().unwrap();
+    let Transaction::AssetConfig(asset_config) = &mut tx else {
+        unreachable!()
+    };
+    let asset_params = asset_config.params.as_mut().unwrap();
+    asset_params.decimals = Some(20);
+    asset_params.unit_name = Some(b"TOOLONGUNIT".to_vec());
+    asset_params.url = Some(vec![b'u'; 97]);
+    assert_eq!(
+        violations(tx),
+        [
+            "asset decimals must not exceed 19",
+            "asset unit name is 11 bytes but must not exceed 8",
+            "asset url is 97 bytes but must not exceed 96",
+        ]
+    );
+
+    let tx = TransactionMother::application_call()
+        .args(vec![vec![0; 200]; 17])
+        .account_references(vec![AddressMother::address(); 5])
+        .asset_references(vec![1; 4])
+        .extra_program_pages(4)
+        .global_state_schema(StateSchema {
+            num_uints: 60,
+            num_byte_slices: 5,
+            unknown_fields: Default::default(),
+        })
+        .build()
+        .unwrap();
+    assert_eq!(
+        violations(tx),
+        [
+            "17 application args exceeds the max of 16",
+            "application args are 3400 bytes but must not exceed 2048",
+            "5 account references exceeds the max of 4",
+            "12 total references exceeds the max of 8",
+            "4 extra program pages exceeds the max of 3",
+            "global state schema has 65 entries but must not exceed 64",
+        ]
+    );
+}
+
+/// Encodes a simple payment with the unknown fields `hdr` and `zzz` added.
+fn simple_payment_with_unknown_fields() -> Vec<u8> {
+    let raw = TransactionMother::simple_payment()
+        .build()
+        .unwrap()
+        .encode_raw()
+        .unwrap();
+    let rmpv::Value::Map(mut fields) = rmpv::decode::read_value(&mut raw.as_slice()).unwrap()
+    else {
+        panic!("expected a map");
+    };
+    fields.push((rmpv::Value::from("zzz"), rmpv::Value::from(7)));
+    fields.push((rmpv::Value::from("hdr"), rmpv::Value::Binary(vec![1, 2, 3])));
+    fields.sort_by(|(a, _), (b, _)| a.as_str().cmp(&b.as_str()));
+    encode_msgpack_value(&rmpv::Value::Map(fields))
+}
+
+#[test]
+fn test_preserve_unknown_fields() {
+    let tx = TransactionMother::simple_payment().build().unwrap();
+    let extended = simple_payment_with_unknown_fields();
+
+    let decoded = Transaction::decode_preserving_unknown_fields(&extended).unwrap();
+    assert_eq!(
+        decoded.header().unknown_fields,
+        std::collections::BTreeMap::from([
+            (String::from("hdr"), rmpv::Value::Binary(vec![1, 2, 3])),
+            (String::from("zzz"), rmpv::Value::from(7)),
+        ])
+    );
+    assert_ne!(decoded, tx);
+    assert_eq!(decoded.encode_raw().unwrap(), extended);
+
+    let mut with_prefix = b"TX".to_vec();
+    with_prefix.extend_from_slice(&extended);
+    assert_eq!(decoded.id_raw().unwrap(), crate::utils::hash(&with_prefix));
+    assert_ne!(decoded.id().unwrap(), tx.id().unwrap());
+}
+
+/// Adds the unknown field `zzz` to the map at `path` within `value`, where array elements are
+/// addressed by their index.
+fn add_unknown_field(value: &mut rmpv::Value, path: &[&str]) {
+    let target = path.iter().fold(value, |value, key| match value {
+        rmpv::Value::Map(fields) => {
+            &mut fields
+                .iter_mut()
+                .find(|(k, _)| k.as_str() == Some(key))
+                .unwrap()
+                .1
+        }
+        rmpv::Value::Array(items) => &mut items[key.parse::<usize>().unwrap()],
+        _ => panic!("expected a map or array at {}", key),
+    });
+    let rmpv::Value::Map(fields) = target else {
+        panic!("expected a map at {:?}", path);
+    };
+    fields.push((rmpv::Value::from("zzz"), rmpv::Value::from(7)));
+}
+
+#[test]
+fn test_preserve_nested_unknown_fields() {
+    let mut multisig =
+        MultisigSignature::from_participants(1, 1, vec![AddressMother::address()]).unwrap();
+    multisig.subsignatures[0].signature = Some([1; ALGORAND_SIGNATURE_BYTE_LENGTH]);
+    let logic_signature = LogicSignature {
+        multisignature: Some(multisig),
+        ..LogicSignature::new(vec![1, 32, 1, 1, 34], None)
+    };
+    let cases = [
+        (
+            TransactionMother::application_create()
+                .box_references(vec![BoxReference {
+                    app_id: 0,
+                    name: b"box".to_vec(),
+                    unknown_fields: Default::default(),
+                }])
+                .build()
+                .unwrap(),
+            vec![
+                vec!["txn", "apgs"],
+                vec!["txn", "apls"],
+                vec!["txn", "apbx", "0"],
+            ],
+        ),
+        (
+            TransactionMother::asset_create().build().unwrap(),
+            vec![vec!["txn", "apar"]],
+        ),
+    ];
+
+    for (transaction, paths) in cases {
+        let signed = SignedTransaction {
+            transaction,
+            signature: None,
+            auth_address: None,
+            multisignature: None,
+            logic_signature: Some(logic_signature.clone()),
+            unknown_fields: Default::default(),
+        };
+        let mut value = rmpv::decode::read_value(&mut signed.encode().unwrap().as_slice()).unwrap();
+        for path in paths
+            .iter()
+            .chain(&[vec![], vec!["lsig"], vec!["lsig", "msig"]])
+        {
+            add_unknown_field(&mut value, path);
+        }
+        let extended = encode_msgpack_value(&crate::utils::sort_msgpack_value(value));
+
+        let decoded = SignedTransaction::decode_preserving_unknown_fields(&extended).unwrap();
+        let unknown_fields =
+            std::collections::BTreeMap::from([(String::from("zzz"), rmpv::Value::from(7))]);
+        assert_eq!(decoded.unknown_fields, unknown_fields);
+        let decoded_lsig = decoded.logic_signature.as_ref().unwrap();
+        assert_eq!(decoded_lsig.unknown_fields, unknown_fields);
+        assert_eq!(
+            decoded_lsig.multisignature.as_ref().unwrap().unknown_fields,
+            unknown_fields
+        );
+        match &decoded.transaction {
+            Transaction::ApplicationCall(fields) => {
+                for schema in [&fields.global_state_schema, &fields.local_state_schema] {
+                    assert_eq!(schema.as_ref().unwrap().unknown_fields, unknown_fields);
+                }
+                assert_eq!(
+                    fields.box_references.as_ref().unwrap()[0].unknown_fields,
+                    unknown_fields
+                );
+            }
+            Transaction::AssetConfig(fields) => {
+                assert_eq!(
+                    fields.params.as_ref().unwrap().unknown_fields,
+                    unknown_fields
+                );
+            }
+            _ => unreachable!(),
+        }
+        assert_eq!(decoded.encode().unwrap(), extended);
+        assert_ne!(decoded.id().unwrap(), signed.id().unwrap());
+
+        assert_eq!(SignedTransaction::decode(&extended).unwrap(), signed);
+    }
+}
+
+#[test]
+fn test_unknown_fields_dropped() {
+    let tx = TransactionMother::simple_payment().build().unwrap();
+    let extended = simple_payment_with_unknown_fields();
 
-    let encoded_grouped_txs = grouped_txs
-        .iter()
-        .map(|tx| tx.encode())
-        .collect::<Result<Vec<Vec<u8>>, _>>()
-        .unwrap();
-    let decoded_grouped_txs = encoded_grouped_txs
-        .iter()
-        .map(|tx| Transaction::decode(tx))
-        .collect::<Result<Vec<Transaction>, _>>()
-        .unwrap();
+    let decoded = Transaction::decode(&extended).unwrap();
+    assert!(decoded.header().unknown_fields.is_empty());
+    assert_eq!(decoded, tx);
+    assert_eq!(decoded.encode_raw().unwrap(), tx.encode_raw().unwrap());
+    assert!(matches!(
+        Transaction::decode_strict(&extended),
+        Err(AlgoKitTransactError::NonCanonicalEncoding(_))
+    ));
+}
 
-    for ((grouped_tx, encoded_tx), decoded_tx) in grouped_txs
-        .iter()
-        .zip(encoded_grouped_txs.into_iter())
-        .zip(decoded_grouped_txs.iter())
-    {
-        assert_eq!(encoded_tx, grouped_tx.encode().unwrap());
-        assert_eq!(decoded_tx, grouped_tx);
-    }
+#[test]
+fn test_signed_transaction_decode_strict() {
+    let signed_tx = SignedTransaction {
+        transaction: TransactionMother::simple_payment().build().unwrap(),
+        signature: Some([1; ALGORAND_SIGNATURE_BYTE_LENGTH]),
+        auth_address: None,
+        multisignature: None,
+        logic_signature: None,
+        unknown_fields: Default::default(),
+    };
+    let encoded = signed_tx.encode().unwrap();
+    assert_eq!(
+        SignedTransaction::decode_strict(&encoded).unwrap(),
+        signed_tx
+    );
+
+    let mut value = rmpv::decode::read_value(&mut encoded.as_slice()).unwrap();
+    let rmpv::Value::Map(fields) = &mut value else {
+        panic!("expected a map");
+    };
+    let rmpv::Value::Map(txn_fields) = &mut fields[1].1 else {
+        panic!("expected the txn map");
+    };
+    txn_fields.reverse();
+    assert_eq!(
+        non_canonical_reason(SignedTransaction::decode_strict(&encode_msgpack_value(
+            &value
+        ))),
+        "field txn.type is out of order"
+    );
 }
 
 #[test]
@@ -366,6 +2236,9 @@ fn test_signed_transaction_group_encoding() {
             transaction: tx.clone(),
             signature: Some([0; ALGORAND_SIGNATURE_BYTE_LENGTH]),
             auth_address: None,
+            multisignature: None,
+            logic_signature: None,
+            unknown_fields: Default::default(),
         })
         .collect::<Vec<SignedTransaction>>();
 
@@ -389,3 +2262,833 @@ fn test_signed_transaction_group_encoding() {
         assert_eq!(decoded_signed_tx, signed_grouped_tx);
     }
 }
+
+fn abi_type(type_str: &str) -> AbiType {
+    type_str.parse().unwrap()
+}
+
+fn hex(s: &str) -> Vec<u8> {
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+#[test]
+fn test_abi_type_parsing() {
+    let cases = [
+        ("uint64", AbiType::Uint(64)),
+        (
+            "ufixed128x10",
+            AbiType::Ufixed {
+                bit_size: 128,
+                precision: 10,
+            },
+        ),
+        (
+            "byte[32]",
+            AbiType::StaticArray(Box::new(AbiType::Byte), 32),
+        ),
+        ("bool[]", AbiType::DynamicArray(Box::new(AbiType::Bool))),
+        ("()", AbiType::Tuple(vec![])),
+        (
+            "(address,(string,bool)[2])[]",
+            AbiType::DynamicArray(Box::new(AbiType::Tuple(vec![
+                AbiType::Address,
+                AbiType::StaticArray(
+                    Box::new(AbiType::Tuple(vec![AbiType::String, AbiType::Bool])),
+                    2,
+                ),
+            ]))),
+        ),
+    ];
+
+    for (type_str, expected) in cases {
+        let parsed = abi_type(type_str);
+        assert_eq!(parsed, expected);
+        assert_eq!(parsed.to_string(), type_str);
+    }
+
+    for invalid in [
+        "uint7",
+        "uint0",
+        "uint520",
+        "uint",
+        "ufixed64",
+        "ufixed64x0",
+        "ufixed64x161",
+        "byte[",
+        "byte[-1]",
+        "(uint64",
+        "(uint64))",
+        "(uint64,)",
+        "uint64 ",
+        "foo",
+    ] {
+        assert!(
+            matches!(
+                invalid.parse::<AbiType>(),
+                Err(AlgoKitTransactError::InvalidAbiType(_))
+            ),
+            "{} should be invalid",
+            invalid
+        );
+    }
+}
+
+#[test]
+fn test_abi_static_byte_len() {
+    assert_eq!(abi_type("uint256").byte_len().unwrap(), 32);
+    assert_eq!(abi_type("bool[9]").byte_len().unwrap(), 2);
+    assert_eq!(abi_type("(bool,uint8,bool,bool)").byte_len().unwrap(), 3);
+    assert_eq!(abi_type("(address,byte[4])[2]").byte_len().unwrap(), 72);
+    assert!(abi_type("(uint64,string)").byte_len().is_err());
+
+    for type_str in [
+        "uint64[2305843009213693952]",
+        "(uint64[1152921504606846976],uint64[1152921504606846976])",
+    ] {
+        assert!(matches!(
+            abi_type(type_str).byte_len(),
+            Err(AlgoKitTransactError::InvalidAbiType(_))
+        ));
+        assert!(matches!(
+            abi_type(type_str).decode(&[1, 2, 3]),
+            Err(AlgoKitTransactError::InvalidAbiType(_))
+        ));
+    }
+}
+
+#[test]
+fn test_abi_type_nesting_limit() {
+    assert!(format!("{}uint8{}", "(".repeat(128), ")".repeat(128))
+        .parse::<AbiType>()
+        .is_ok());
+    assert!(format!("uint8{}", "[]".repeat(128))
+        .parse::<AbiType>()
+        .is_ok());
+
+    for type_str in [
+        format!("{}uint8{}", "(".repeat(200_000), ")".repeat(200_000)),
+        format!("uint8{}", "[]".repeat(200_000)),
+    ] {
+        assert!(matches!(
+            type_str.parse::<AbiType>(),
+            Err(AlgoKitTransactError::InvalidAbiType(_))
+        ));
+    }
+}
+
+#[test]
+fn test_abi_encoding() {
+    let address = AddressMother::address();
+    let cases = [
+        ("uint64", AbiValue::from(1), "0000000000000001"),
+        ("uint8", AbiValue::from(255), "ff"),
+        ("ufixed16x2", AbiValue::from(1234), "04d2"),
+        ("bool", AbiValue::from(true), "80"),
+        ("byte", AbiValue::Byte(0x2a), "2a"),
+        ("string", AbiValue::from("hello"), "000568656c6c6f"),
+        ("byte[3]", AbiValue::from_bytes(&[1, 2, 3]), "010203"),
+        ("byte[]", AbiValue::from_bytes(&[1, 2]), "00020102"),
+        (
+            "bool[3]",
+            AbiValue::Array(vec![true.into(), false.into(), true.into()]),
+            "a0",
+        ),
+        (
+            "bool[]",
+            AbiValue::Array(vec![true.into(), false.into(), true.into()]),
+            "0003a0",
+        ),
+        (
+            "string[]",
+            AbiValue::Array(vec!["a".into(), "bc".into()]),
+            "00020004000700016100026263",
+        ),
+        (
+            "(bool,bool,uint64,string)",
+            AbiValue::Array(vec![true.into(), true.into(), 1.into(), "a".into()]),
+            "c00000000000000001000b000161",
+        ),
+        (
+            "(uint16,bool,bool,bool,bool,bool,bool,bool,bool,bool)",
+            AbiValue::Array(
+                std::iter::once(AbiValue::from(3))
+                    .chain((0..9).map(|i| AbiValue::from(i % 2 == 0)))
+                    .collect(),
+            ),
+            "0003aa80",
+        ),
+        (
+            "(uint8[],bool[0])",
+            AbiValue::Array(vec![vec![].into(), vec![].into()]),
+            "00020000",
+        ),
+    ];
+
+    for (type_str, value, expected) in cases {
+        let abi_type = abi_type(type_str);
+        let encoded = abi_type.encode(&value).unwrap();
+        assert_eq!(encoded, hex(expected), "encoding {}", type_str);
+        assert_eq!(
+            abi_type.decode(&encoded).unwrap(),
+            value,
+            "decoding {}",
+            type_str
+        );
+    }
+
+    let address_type = abi_type("address");
+    let encoded = address_type.encode(&address.clone().into()).unwrap();
+    assert_eq!(encoded, address.pub_key);
+    assert_eq!(
+        address_type.decode(&encoded).unwrap(),
+        AbiValue::Address(address)
+    );
+}
+
+#[test]
+fn test_abi_nested_round_trip() {
+    let abi_type = abi_type("(string[],(uint512,address)[2],bool[10],ufixed64x4)");
+    let value = AbiValue::Array(vec![
+        AbiValue::Array(vec!["".into(), "algorand".into()]),
+        AbiValue::Array(vec![
+            AbiValue::Array(vec![
+                AbiValue::Uint(num_bigint::BigUint::from(2u8).pow(511)),
+                AddressMother::address().into(),
+            ]),
+            AbiValue::Array(vec![0.into(), AddressMother::zero_address().into()]),
+        ]),
+        AbiValue::Array((0..10).map(|i| AbiValue::from(i % 3 == 0)).collect()),
+        AbiValue::from(12345),
+    ]);
+
+    let encoded = abi_type.encode(&value).unwrap();
+    assert_eq!(abi_type.decode(&encoded).unwrap(), value);
+}
+
+#[test]
+fn test_abi_encoding_errors() {
+    let invalid_values = [
+        ("uint8", AbiValue::from(256)),
+        ("uint64", AbiValue::from(true)),
+        ("byte[2]", AbiValue::from_bytes(&[1, 2, 3])),
+        ("(uint64,bool)", AbiValue::Array(vec![1.into()])),
+        ("bool[2]", AbiValue::Array(vec![true.into(), 1.into()])),
+        ("string", AbiValue::from("a".repeat(65536))),
+    ];
+    for (type_str, value) in invalid_values {
+        assert!(
+            matches!(
+                abi_type(type_str).encode(&value),
+                Err(AlgoKitTransactError::AbiEncodingError(_))
+            ),
+            "encoding {} should fail",
+            type_str
+        );
+    }
+
+    let invalid_encodings = [
+        ("uint64", "01"),
+        ("bool", "01"),
+        ("bool[3]", "a1"),
+        ("string", "0005616263"),
+        ("string", "0002ffff"),
+        ("string[]", "000100"),
+        ("(string,string)", "00050004000161000162"),
+        ("(uint8,string)", "01000300016100"),
+        ("string[9223372036854775808]", "0000"),
+        ("()[65536]", ""),
+    ];
+    for (type_str, encoded) in invalid_encodings {
+        assert!(
+            matches!(
+                abi_type(type_str).decode(&hex(encoded)),
+                Err(AlgoKitTransactError::AbiDecodingError(_))
+            ),
+            "decoding {} from {} should fail",
+            type_str,
+            encoded
+        );
+    }
+}
+
+#[test]
+fn test_abi_method_signature_and_selector() {
+    let method: AbiMethod = "add(uint64,uint64)uint128".parse().unwrap();
+    assert_eq!(method.name, "add");
+    assert_eq!(method.args.len(), 2);
+    assert_eq!(method.returns, Some(AbiType::Uint(128)));
+    assert_eq!(method.selector(), [0x8a, 0xa3, 0xb6, 0x1f]);
+
+    let signature = "foo(pay,account,asset,application,(string,bool[])[])void";
+    let method: AbiMethod = signature.parse().unwrap();
+    assert_eq!(method.signature(), signature);
+    assert_eq!(
+        method.args[0].arg_type,
+        AbiMethodArgType::Transaction(AbiTransactionType::Payment)
+    );
+    assert_eq!(
+        method.args[1].arg_type,
+        AbiMethodArgType::Reference(AbiReferenceType::Account)
+    );
+    assert_eq!(method.returns, None);
+
+    for invalid in [
+        "foo",
+        "(uint64)void",
+        "foo(uint64",
+        "foo(uint64)",
+        "foo(uint7)void",
+    ] {
+        assert!(
+            invalid.parse::<AbiMethod>().is_err(),
+            "{} should be invalid",
+            invalid
+        );
+    }
+}
+
+#[test]
+fn test_abi_method_json() {
+    let json = r#"{
+        "name": "transfer",
+        "desc": "Transfers tokens",
+        "args": [
+            { "type": "address", "name": "receiver", "desc": "The receiver" },
+            { "type": "uint64", "name": "amount" }
+        ],
+        "returns": { "type": "bool", "desc": "Whether the transfer succeeded" }
+    }"#;
+
+    let method: AbiMethod = serde_json::from_str(json).unwrap();
+    assert_eq!(method.signature(), "transfer(address,uint64)bool");
+    assert_eq!(method.selector(), [0x94, 0xc3, 0x78, 0x90]);
+    assert_eq!(method.description.as_deref(), Some("Transfers tokens"));
+    assert_eq!(method.args[0].name.as_deref(), Some("receiver"));
+    assert_eq!(method.args[1].description, None);
+    assert_eq!(
+        method.returns_description.as_deref(),
+        Some("Whether the transfer succeeded")
+    );
+
+    let reserialized = serde_json::to_string(&method).unwrap();
+    assert_eq!(
+        serde_json::from_str::<AbiMethod>(&reserialized).unwrap(),
+        method
+    );
+
+    let invalid =
+        r#"{ "name": "foo", "args": [{ "type": "uint7" }], "returns": { "type": "void" } }"#;
+    assert!(serde_json::from_str::<AbiMethod>(invalid).is_err());
+}
+
+#[test]
+fn test_abi_method_encode_call_args() {
+    let method: AbiMethod = "foo(pay,account,asset,application,account,string)void"
+        .parse()
+        .unwrap();
+    let sender = AddressMother::address();
+    let other = AddressMother::neil();
+
+    let call_args = method
+        .encode_call_args(
+            &[
+                AbiMethodArgValue::Account(other.clone()),
+                AbiMethodArgValue::Asset(12345),
+                AbiMethodArgValue::Application(100),
+                AbiMethodArgValue::Account(sender.clone()),
+                AbiMethodArgValue::Value("hi".into()),
+            ],
+            &sender,
+            100,
+        )
+        .unwrap();
+
+    assert_eq!(
+        call_args.args,
+        vec![
+            method.selector().to_vec(),
+            vec![1],
+            vec![0],
+            vec![0],
+            vec![0],
+            hex("00026869")
+        ]
+    );
+    assert_eq!(call_args.account_references, vec![other]);
+    assert_eq!(call_args.asset_references, vec![12345]);
+    assert!(call_args.app_references.is_empty());
+
+    assert!(matches!(
+        method.encode_call_args(&[AbiMethodArgValue::Asset(1)], &sender, 100),
+        Err(AlgoKitTransactError::AbiEncodingError(_))
+    ));
+    assert!(matches!(
+        method.encode_call_args(
+            &[
+                AbiMethodArgValue::Asset(1),
+                AbiMethodArgValue::Asset(1),
+                AbiMethodArgValue::Application(1),
+                AbiMethodArgValue::Account(sender.clone()),
+                AbiMethodArgValue::Value("hi".into()),
+            ],
+            &sender,
+            100
+        ),
+        Err(AlgoKitTransactError::AbiEncodingError(_))
+    ));
+}
+
+#[test]
+fn test_abi_method_encode_more_than_15_args() {
+    let signature = format!("many({})void", vec!["uint8"; 17].join(","));
+    let method: AbiMethod = signature.parse().unwrap();
+    let values: Vec<AbiMethodArgValue> = (0..17)
+        .map(|i| AbiMethodArgValue::Value(AbiValue::from(i)))
+        .collect();
+
+    let call_args = method
+        .encode_call_args(&values, &AddressMother::address(), 1)
+        .unwrap();
+
+    assert_eq!(call_args.args.len(), 16);
+    for i in 0..14 {
+        assert_eq!(call_args.args[i + 1], vec![i as u8]);
+    }
+    assert_eq!(call_args.args[15], vec![14, 15, 16]);
+}
+
+#[test]
+fn test_abi_method_decode_return_value() {
+    let method: AbiMethod = "add(uint64,uint64)uint128".parse().unwrap();
+    let logs = vec![
+        b"some log".to_vec(),
+        hex("151f7c7500000000000000000000000000000003"),
+    ];
+    assert_eq!(
+        method.decode_return_value(&logs).unwrap(),
+        Some(AbiValue::from(3))
+    );
+
+    assert!(matches!(
+        method.decode_return_value(&logs[..1]),
+        Err(AlgoKitTransactError::AbiDecodingError(_))
+    ));
+    assert!(matches!(
+        method.decode_return_value(&[]),
+        Err(AlgoKitTransactError::AbiDecodingError(_))
+    ));
+
+    let void_method: AbiMethod = "noop()void".parse().unwrap();
+    assert_eq!(void_method.decode_return_value(&[]).unwrap(), None);
+}
+
+#[test]
+fn test_abi_value_json() {
+    let tuple_type = abi_type("(uint64,uint128,bool,byte[2],address,string)");
+    let json = serde_json::json!([
+        1,
+        "340282366920938463463374607431768211455",
+        true,
+        [1, 2],
+        AddressMother::address().to_string(),
+        "hi"
+    ]);
+
+    let value = tuple_type.value_from_json(&json).unwrap();
+    assert_eq!(
+        value,
+        AbiValue::Array(vec![
+            1.into(),
+            AbiValue::Uint(num_bigint::BigUint::from(u128::MAX)),
+            true.into(),
+            AbiValue::from_bytes(&[1, 2]),
+            AddressMother::address().into(),
+            "hi".into(),
+        ])
+    );
+    assert_eq!(tuple_type.value_to_json(&value).unwrap(), json);
+
+    assert!(tuple_type.value_from_json(&serde_json::json!([1])).is_err());
+    assert!(abi_type("byte")
+        .value_from_json(&serde_json::json!(256))
+        .is_err());
+}
+
+#[test]
+fn test_arc56_app_spec() {
+    let app_spec = Arc56Contract::from_json(AppSpecMother::arc56_json()).unwrap();
+
+    assert_eq!(app_spec.name, "Counter");
+    assert_eq!(app_spec.arcs, vec![22, 28]);
+    assert_eq!(
+        app_spec.networks.as_ref().unwrap()["wGHE2Pwdvd7S12BL5FaOP20EGYesN73ktiC1qzkkit8="].app_id,
+        1234
+    );
+    assert_eq!(
+        app_spec.structs["Config"][2].field_type,
+        StructFieldType::Fields(vec![
+            StructField {
+                name: "min".to_string(),
+                field_type: StructFieldType::Type("uint64".to_string()),
+            },
+            StructField {
+                name: "max".to_string(),
+                field_type: StructFieldType::Type("uint64".to_string()),
+            },
+        ])
+    );
+
+    let increment = app_spec.find_method("increment").unwrap();
+    assert_eq!(
+        increment.actions.call,
+        vec![OnCompleteAction::NoOp, OnCompleteAction::OptIn]
+    );
+    assert_eq!(
+        increment.args[0].default_value.as_ref().unwrap().source,
+        DefaultValueSource::Global
+    );
+    assert_eq!(
+        increment.recommendations.as_ref().unwrap().apps,
+        Some(vec![1])
+    );
+    assert_eq!(
+        increment.to_abi_method().unwrap().signature(),
+        "increment(uint64,pay)uint64"
+    );
+    assert_eq!(
+        app_spec
+            .find_method("get_config()(uint64,address,(uint64,uint64))")
+            .unwrap()
+            .returns
+            .struct_name,
+        Some("Config".to_string())
+    );
+
+    assert_eq!(app_spec.state.schema.global.ints, 2);
+    assert_eq!(app_spec.state.keys.boxes["config"].value_type, "Config");
+    assert_eq!(
+        app_spec.state.maps.boxes["balances"].prefix,
+        Some("Yg==".to_string())
+    );
+    assert_eq!(
+        app_spec.bare_actions.call,
+        vec![OnCompleteAction::DeleteApplication]
+    );
+    assert_eq!(
+        app_spec.source_info.as_ref().unwrap().approval.source_info[0].error_message,
+        Some("OnCompletion is not NoOp".to_string())
+    );
+    assert_eq!(app_spec.byte_code.as_ref().unwrap().approval, "CoEBQw==");
+    assert_eq!(app_spec.compiler_info.as_ref().unwrap().compiler, "puya");
+    assert_eq!(app_spec.events.as_ref().unwrap()[0].name, "Incremented");
+    assert_eq!(
+        app_spec.template_variables.as_ref().unwrap()["MAX_STEP"].value,
+        None
+    );
+    assert_eq!(app_spec.scratch_variables.as_ref().unwrap()["tmp"].slot, 3);
+
+    let round_tripped = Arc56Contract::from_json(&app_spec.to_json().unwrap()).unwrap();
+    assert_eq!(round_tripped, app_spec);
+}
+
+#[test]
+fn test_arc56_find_method() {
+    let mut app_spec = Arc56Contract::from_json(AppSpecMother::arc56_json()).unwrap();
+    let mut overload = app_spec.find_method("increment").unwrap().clone();
+    overload.args.truncate(1);
+    app_spec.methods.push(overload);
+
+    assert!(matches!(
+        app_spec.find_method("increment"),
+        Err(AlgoKitTransactError::InvalidAppSpec(_))
+    ));
+    assert_eq!(
+        app_spec
+            .find_method("increment(uint64)uint64")
+            .unwrap()
+            .args
+            .len(),
+        1
+    );
+    assert!(app_spec.find_method("decrement").is_err());
+    assert!(app_spec.find_method("increment(uint32)uint64").is_err());
+}
+
+#[test]
+fn test_arc32_app_spec() {
+    let app_spec = Arc56Contract::from_arc32_json(AppSpecMother::arc32_json()).unwrap();
+
+    assert_eq!(app_spec.name, "HelloWorld");
+    assert_eq!(app_spec.methods.len(), 4);
+
+    let hello = app_spec.find_method("hello").unwrap();
+    assert_eq!(hello.readonly, Some(true));
+    assert_eq!(
+        hello.args[0].default_value,
+        Some(DefaultValue {
+            data: "AAV3b3JsZA==".to_string(),
+            value_type: Some("string".to_string()),
+            source: DefaultValueSource::Literal,
+        })
+    );
+
+    let add = app_spec.find_method("add").unwrap();
+    assert_eq!(add.actions.create, vec![OnCompleteAction::OptIn]);
+    assert_eq!(
+        add.actions.call,
+        vec![OnCompleteAction::NoOp, OnCompleteAction::OptIn]
+    );
+    assert_eq!(
+        add.args[0].default_value,
+        Some(DefaultValue {
+            data: "Y291bnRlcg==".to_string(),
+            value_type: None,
+            source: DefaultValueSource::Global,
+        })
+    );
+    assert_eq!(
+        add.args[1].default_value.as_ref().unwrap().data,
+        "AAAAAAAAAAU="
+    );
+
+    let get_point = app_spec.find_method("get_point").unwrap();
+    assert_eq!(get_point.args[0].struct_name, Some("Point".to_string()));
+    assert_eq!(get_point.returns.struct_name, Some("Point".to_string()));
+    assert_eq!(
+        get_point.args[0].default_value,
+        Some(DefaultValue {
+            data: "origin()(uint64,uint64)".to_string(),
+            value_type: None,
+            source: DefaultValueSource::Method,
+        })
+    );
+    assert_eq!(app_spec.structs["Point"].len(), 2);
+
+    let noop = app_spec.find_method("noop").unwrap();
+    assert_eq!(noop.actions.call, vec![OnCompleteAction::NoOp]);
+    assert_eq!(noop.returns.return_type, "void");
+
+    assert_eq!(app_spec.state.schema.global.ints, 1);
+    assert_eq!(app_spec.state.schema.local.ints, 2);
+    assert_eq!(
+        app_spec.state.keys.global["counter"],
+        StorageKey {
+            desc: Some("The counter".to_string()),
+            key_type: "AVMString".to_string(),
+            value_type: "AVMUint64".to_string(),
+            key: "Y291bnRlcg==".to_string(),
+        }
+    );
+    assert_eq!(app_spec.state.keys.global["owner"].value_type, "AVMBytes");
+    assert_eq!(app_spec.bare_actions.create, vec![OnCompleteAction::NoOp]);
+    assert_eq!(
+        app_spec.bare_actions.call,
+        vec![OnCompleteAction::DeleteApplication]
+    );
+
+    assert!(matches!(
+        Arc56Contract::from_arc32_json("{}"),
+        Err(AlgoKitTransactError::InvalidAppSpec(_))
+    ));
+}
+
+fn composer_header(sender: &Address) -> TransactionHeader {
+    TransactionHeaderBuilder::default()
+        .sender(sender.clone())
+        .fee(0)
+        .first_valid(0)
+        .last_valid(0)
+        .build()
+        .unwrap()
+}
+
+fn composer_suggested_params() -> SuggestedParams {
+    SuggestedParams {
+        fee_per_byte: 0,
+        min_fee: 1000,
+        first_valid: 100,
+        last_valid: 1100,
+        genesis_hash: [1; 32],
+        genesis_id: String::from("testnet-v1.0"),
+    }
+}
+
+fn composer_method_call(sender: &Address, method: &str, args: Vec<MethodCallArg>) -> MethodCall {
+    MethodCall {
+        app_call: ApplicationCallTransactionFields {
+            args: None,
+            ..TransactionMother::application_call()
+                .header(composer_header(sender))
+                .app_id(123)
+                .build_fields()
+                .unwrap()
+        },
+        method: method.parse().unwrap(),
+        args,
+    }
+}
+
+#[test]
+fn test_composer_method_call() {
+    let key = SigningKey::from_bytes(&[7; 32]);
+    let sender = key.address();
+    let payment = TransactionMother::simple_payment()
+        .header(composer_header(&sender))
+        .build()
+        .unwrap();
+    let method_call = composer_method_call(
+        &sender,
+        "deposit(pay,account,uint64)void",
+        vec![
+            MethodCallArg::Transaction(payment.clone().into()),
+            MethodCallArg::Value(AbiMethodArgValue::Account(AddressMother::address())),
+            MethodCallArg::Value(AbiMethodArgValue::Value(5.into())),
+        ],
+    );
+
+    let mut composer = TransactionComposer::new(composer_suggested_params());
+    composer
+        .add_transaction(payment)
+        .add_transaction(
+            ComposerTransaction::from(method_call.clone()).with_fee_params(TransactionFeeParams {
+                inner_transactions: 1,
+                ..Default::default()
+            }),
+        )
+        .add_signer(sender.clone(), std::sync::Arc::new(key));
+
+    let txs = composer.build().unwrap();
+    assert_eq!(txs.len(), 3);
+    let group = txs[0].header().group;
+    assert!(group.is_some());
+    for tx in &txs {
+        let header = tx.header();
+        assert_eq!(header.group, group);
+        assert_eq!(header.first_valid, 100);
+        assert_eq!(header.last_valid, 1100);
+        assert_eq!(header.genesis_hash, Some([1; 32]));
+        assert_eq!(header.genesis_id.as_deref(), Some("testnet-v1.0"));
+    }
+    assert_eq!(
+        txs.iter().map(|tx| tx.header().fee).collect::<Vec<_>>(),
+        vec![Some(1000), Some(1000), Some(2000)]
+    );
+
+    let Transaction::ApplicationCall(app_call) = &txs[2] else {
+        panic!("expected an application call");
+    };
+    let call_args = method_call
+        .method
+        .encode_call_args(
+            &[
+                AbiMethodArgValue::Account(AddressMother::address()),
+                AbiMethodArgValue::Value(5.into()),
+            ],
+            &sender,
+            123,
+        )
+        .unwrap();
+    assert_eq!(app_call.args, Some(call_args.args));
+    assert_eq!(
+        app_call.account_references,
+        Some(vec![AddressMother::address(), AddressMother::neil()])
+    );
+    assert_eq!(app_call.box_references.as_ref().unwrap().len(), 2);
+
+    let signed = composer.sign().unwrap();
+    assert_eq!(signed.len(), 3);
+    for (signed_tx, tx) in signed.iter().zip(&txs) {
+        assert_eq!(&signed_tx.transaction, tx);
+        signed_tx.verify().unwrap();
+    }
+}
+
+#[test]
+fn test_composer_nested_method_call() {
+    let sender = AddressMother::address();
+    let inner = composer_method_call(
+        &sender,
+        "inner(uint64)void",
+        vec![MethodCallArg::Value(AbiMethodArgValue::Value(1.into()))],
+    );
+    let outer = composer_method_call(
+        &sender,
+        "outer(appl)void",
+        vec![MethodCallArg::Transaction(inner.into())],
+    );
+
+    let txs = TransactionComposer::new(composer_suggested_params())
+        .add_transaction(outer)
+        .build()
+        .unwrap();
+
+    let selectors: Vec<Vec<u8>> = txs
+        .iter()
+        .map(|tx| match tx {
+            Transaction::ApplicationCall(app_call) => app_call.args.as_ref().unwrap()[0].clone(),
+            _ => panic!("expected an application call"),
+        })
+        .collect();
+    assert_eq!(
+        selectors,
+        vec![
+            "inner(uint64)void"
+                .parse::<AbiMethod>()
+                .unwrap()
+                .selector()
+                .to_vec(),
+            "outer(appl)void"
+                .parse::<AbiMethod>()
+                .unwrap()
+                .selector()
+                .to_vec(),
+        ]
+    );
+}
+
+#[test]
+fn test_composer_errors() {
+    let sender = AddressMother::address();
+    let payment = TransactionMother::simple_payment()
+        .header(composer_header(&sender))
+        .build()
+        .unwrap();
+
+    assert!(TransactionComposer::new(composer_suggested_params())
+        .build()
+        .is_err());
+
+    let mut composer = TransactionComposer::new(composer_suggested_params());
+    for _ in 0..=MAX_TX_GROUP_SIZE {
+        composer.add_transaction(payment.clone());
+    }
+    assert!(matches!(
+        composer.build(),
+        Err(AlgoKitTransactError::InputError(_))
+    ));
+
+    let asset_transfer = TransactionMother::opt_in_asset_transfer()
+        .header(composer_header(&sender))
+        .build()
+        .unwrap();
+    let wrong_arg_type = composer_method_call(
+        &sender,
+        "deposit(pay)void",
+        vec![MethodCallArg::Transaction(asset_transfer.into())],
+    );
+    let err = TransactionComposer::new(composer_suggested_params())
+        .add_transaction(wrong_arg_type)
+        .build()
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "deposit(pay)void: arg must be a pay transaction"
+    );
+
+    let mut composer = TransactionComposer::new(composer_suggested_params());
+    composer.add_transaction(payment);
+    assert!(composer.sign().is_err());
+}
@@ -4,7 +4,9 @@
 //! Algorand data structures and for calculating transaction identifiers.
 
 use crate::error::AlgoKitTransactError;
-use crate::utils::sort_msgpack_value;
+use crate::transactions::GroupFeeParams;
+use crate::utils::{describe_encoding_difference, sort_msgpack_value, write_msgpack_value};
+use crate::value_serializer::to_value;
 use crate::Transaction;
 use crate::{constants::HASH_BYTES_LENGTH, utils::hash};
 use serde::{Deserialize, Serialize};
@@ -30,21 +32,13 @@ pub trait AlgorandMsgpack: Serialize + for<'de> Deserialize<'de> {
     /// # Returns
     /// The raw encoded bytes or an AlgoKitTransactError if serialization fails.
     fn encode_raw(&self) -> Result<Vec<u8>, AlgoKitTransactError> {
-        // First serialize to a temporary buffer to get the map entries
-        let mut temp_buf = Vec::new();
-        let mut temp_serializer = rmp_serde::Serializer::new(&mut temp_buf)
-            .with_struct_map()
-            .with_bytes(rmp_serde::config::BytesMode::ForceAll);
-
-        self.serialize(&mut temp_serializer)?;
-
-        // Deserialize into a Value and sort recursively
-        let value = rmpv::decode::read_value(&mut temp_buf.as_slice())?;
+        // Serialize to a msgpack value and sort its maps recursively
+        let value = to_value(self)?;
         let sorted_value = sort_msgpack_value(value);
 
         // Serialize the sorted value
         let mut final_buf = Vec::new();
-        rmpv::encode::write_value(&mut final_buf, &sorted_value)?;
+        write_msgpack_value(&mut final_buf, &sorted_value)?;
 
         Ok(final_buf)
     }
@@ -54,6 +48,9 @@ pub trait AlgorandMsgpack: Serialize + for<'de> Deserialize<'de> {
     /// If the bytes start with the expected PREFIX for this type, the prefix is
     /// automatically removed before decoding.
     ///
+    /// Fields which aren't recognised, such as those added by a newer protocol version, are
+    /// dropped. Use `decode_preserving_unknown_fields()` to retain them.
+    ///
     /// # Parameters
     /// * `bytes` - The MessagePack encoded bytes
     ///
@@ -61,6 +58,24 @@ pub trait AlgorandMsgpack: Serialize + for<'de> Deserialize<'de> {
     /// The decoded instance or an AlgoKitTransactError if the input is empty or
     /// deserialization fails.
     fn decode(bytes: &[u8]) -> Result<Self, AlgoKitTransactError> {
+        let mut decoded = Self::decode_preserving_unknown_fields(bytes)?;
+        decoded.drop_unknown_fields();
+        Ok(decoded)
+    }
+
+    /// Decodes MessagePack bytes into an instance of this same type, retaining the fields which
+    /// aren't recognised, such as those added by a newer protocol version.
+    ///
+    /// The unknown fields are emitted again when encoding, so the decoded instance re-encodes to
+    /// the same bytes and, for transactions, has the same ID.
+    ///
+    /// # Parameters
+    /// * `bytes` - The MessagePack encoded bytes
+    ///
+    /// # Returns
+    /// The decoded instance or an AlgoKitTransactError if the input is empty or
+    /// deserialization fails.
+    fn decode_preserving_unknown_fields(bytes: &[u8]) -> Result<Self, AlgoKitTransactError> {
         if bytes.is_empty() {
             return Err(AlgoKitTransactError::InputError(
                 "attempted to decode 0 bytes".to_string(),
@@ -69,17 +84,53 @@ pub trait AlgorandMsgpack: Serialize + for<'de> Deserialize<'de> {
 
         // If there is a PREFIX defined, bytes is longer than the prefix, and the bytes start
         // with the prefix, decode the bytes without the prefix
-        if Self::PREFIX.len() > 0
+        if !Self::PREFIX.is_empty()
             && bytes.len() > Self::PREFIX.len()
             && &bytes[..Self::PREFIX.len()] == Self::PREFIX
         {
             let without_prefix = &bytes[Self::PREFIX.len()..];
-            Ok(rmp_serde::from_slice(&without_prefix)?)
+            Ok(rmp_serde::from_slice(without_prefix)?)
         } else {
             Ok(rmp_serde::from_slice(bytes)?)
         }
     }
 
+    /// Drops the fields which aren't recognised, which are retained when decoding with
+    /// `decode_preserving_unknown_fields()`.
+    ///
+    /// Types without unknown fields don't need to implement this.
+    fn drop_unknown_fields(&mut self) {}
+
+    /// Decodes MessagePack bytes, requiring them to be exactly the canonical encoding of the
+    /// decoded value, i.e. what `encode_raw()` produces (optionally preceded by the PREFIX).
+    ///
+    /// Non-canonical encodings, such as unsorted map keys, empty fields which should have been
+    /// omitted, non-minimal integer widths or trailing bytes, are rejected, as they would be
+    /// rejected by algod or hash to a different ID.
+    ///
+    /// # Parameters
+    /// * `bytes` - The MessagePack encoded bytes
+    ///
+    /// # Returns
+    /// The decoded instance, or an error if decoding fails or a `NonCanonicalEncoding` error
+    /// describing the first difference from the canonical encoding.
+    fn decode_strict(bytes: &[u8]) -> Result<Self, AlgoKitTransactError> {
+        let decoded = Self::decode(bytes)?;
+
+        let raw = match bytes.strip_prefix(Self::PREFIX) {
+            Some(raw) if !Self::PREFIX.is_empty() && !raw.is_empty() => raw,
+            _ => bytes,
+        };
+        let canonical = decoded.encode_raw()?;
+        if raw != canonical.as_slice() {
+            return Err(AlgoKitTransactError::NonCanonicalEncoding(
+                describe_encoding_difference(&canonical, raw),
+            ));
+        }
+
+        Ok(decoded)
+    }
+
     /// Encodes the object to MessagePack format with the appropriate prefix.
     ///
     /// This method performs canonical encoding and prepends the domain separation
@@ -147,4 +198,12 @@ pub trait Transactions: Sized {
     /// # Returns
     /// A result containing the transactions with group assign or an error if grouping fails.
     fn assign_group(self) -> Result<Vec<Transaction>, AlgoKitTransactError>;
+
+    /// Assigns the fees of the supplied transactions, pooling them across the group.
+    ///
+    /// # Returns
+    /// A result containing the transactions with fees assigned or an error if the fees cannot be
+    /// covered.
+    fn assign_fees(self, params: &GroupFeeParams)
+        -> Result<Vec<Transaction>, AlgoKitTransactError>;
 }
@@ -0,0 +1,432 @@
+//! Application call transaction module for AlgoKit Core.
+//!
+//! This module provides functionality for creating and managing application call transactions,
+//! which are used to create, call, update and delete Algorand smart contracts.
+
+use crate::address::Address;
+use crate::transactions::common::{TransactionHeader, UnknownFields};
+use crate::utils::{is_empty_vec_opt, is_zero, is_zero_opt};
+use derive_builder::Builder;
+use serde::{ser::Error as _, Deserialize, Serialize, Serializer};
+use serde_with::{serde_as, skip_serializing_none, Bytes};
+
+/// The action to perform on the application after the application call has been evaluated.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[serde(into = "u64", try_from = "u64")]
+pub enum OnApplicationComplete {
+    /// Only execute the approval program associated with the application.
+    #[default]
+    NoOp = 0,
+
+    /// Execute the approval program and opt the sender into the application.
+    OptIn = 1,
+
+    /// Execute the approval program and remove the application's local state from the sender.
+    CloseOut = 2,
+
+    /// Execute the clear state program and remove the application's local state from the sender,
+    /// regardless of the outcome of the program.
+    ClearState = 3,
+
+    /// Execute the approval program and replace the approval and clear state programs.
+    UpdateApplication = 4,
+
+    /// Execute the approval program and delete the application.
+    DeleteApplication = 5,
+}
+
+impl From<OnApplicationComplete> for u64 {
+    fn from(value: OnApplicationComplete) -> Self {
+        value as u64
+    }
+}
+
+impl TryFrom<u64> for OnApplicationComplete {
+    type Error = String;
+
+    fn try_from(value: u64) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(OnApplicationComplete::NoOp),
+            1 => Ok(OnApplicationComplete::OptIn),
+            2 => Ok(OnApplicationComplete::CloseOut),
+            3 => Ok(OnApplicationComplete::ClearState),
+            4 => Ok(OnApplicationComplete::UpdateApplication),
+            5 => Ok(OnApplicationComplete::DeleteApplication),
+            _ => Err(format!("unknown on completion value: {}", value)),
+        }
+    }
+}
+
+fn is_no_op(on_complete: &OnApplicationComplete) -> bool {
+    *on_complete == OnApplicationComplete::NoOp
+}
+
+/// The number of global or local state values an application may store.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+pub struct StateSchema {
+    /// The maximum number of uint64 values.
+    #[serde(rename = "nui")]
+    #[serde(skip_serializing_if = "is_zero")]
+    #[serde(default)]
+    pub num_uints: u64,
+
+    /// The maximum number of byte slice values.
+    #[serde(rename = "nbs")]
+    #[serde(skip_serializing_if = "is_zero")]
+    #[serde(default)]
+    pub num_byte_slices: u64,
+
+    /// Fields of the schema which are not recognised by this crate.
+    #[serde(flatten)]
+    #[serde(skip_serializing_if = "UnknownFields::is_empty")]
+    #[serde(default)]
+    pub unknown_fields: UnknownFields,
+}
+
+impl StateSchema {
+    fn is_empty(&self) -> bool {
+        self.num_uints == 0 && self.num_byte_slices == 0 && self.unknown_fields.is_empty()
+    }
+}
+
+fn is_empty_state_schema_opt(schema: &Option<StateSchema>) -> bool {
+    schema.as_ref().is_none_or(StateSchema::is_empty)
+}
+
+/// A reference to a box that the application call may access.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct BoxReference {
+    /// The ID of the application that owns the box.
+    ///
+    /// A value of 0 refers to the application being called. Any other application ID
+    /// must also be present in the application references of the transaction, unless it's the
+    /// ID of the application being called.
+    ///
+    /// The ID of the application being called is encoded as 0 when it isn't in the application
+    /// references, so it decodes as 0.
+    pub app_id: u64,
+
+    /// The name of the box.
+    pub name: Vec<u8>,
+
+    /// Fields of the box reference which are not recognised by this crate.
+    pub unknown_fields: UnknownFields,
+}
+
+/// Represents an application call transaction that interacts with an Algorand smart contract.
+#[derive(Deserialize, Debug, PartialEq, Clone, Builder)]
+#[serde(try_from = "ApplicationCallTransactionFieldsEncoding")]
+#[builder(
+    name = "ApplicationCallTransactionBuilder",
+    setter(strip_option),
+    build_fn(name = "build_fields")
+)]
+pub struct ApplicationCallTransactionFields {
+    /// Common transaction header fields.
+    pub header: TransactionHeader,
+
+    /// ID of the application being called.
+    ///
+    /// Set this to 0 to indicate an application creation call.
+    #[builder(default)]
+    pub app_id: u64,
+
+    /// Defines what additional actions occur with the transaction.
+    #[builder(default)]
+    pub on_complete: OnApplicationComplete,
+
+    /// Logic executed for every application call transaction, except when on-completion is set to
+    /// "clear".
+    ///
+    /// Approval programs may reject the transaction.
+    /// Only required for application creation and update transactions.
+    #[builder(default)]
+    pub approval_program: Option<Vec<u8>>,
+
+    /// Logic executed for application call transactions with on-completion set to "clear".
+    ///
+    /// Clear state programs cannot reject the transaction.
+    /// Only required for application creation and update transactions.
+    #[builder(default)]
+    pub clear_state_program: Option<Vec<u8>>,
+
+    /// Holds the maximum number of global state values.
+    ///
+    /// Only required for application creation transactions.
+    /// This cannot be changed after creation.
+    #[builder(default)]
+    pub global_state_schema: Option<StateSchema>,
+
+    /// Holds the maximum number of local state values.
+    ///
+    /// Only required for application creation transactions.
+    /// This cannot be changed after creation.
+    #[builder(default)]
+    pub local_state_schema: Option<StateSchema>,
+
+    /// Number of additional pages allocated to the application's approval and clear state programs.
+    ///
+    /// Each extra program page is 2048 bytes. The sum of approval program and clear state program
+    /// may not exceed 2048*(1+extra_program_pages) bytes.
+    /// Only required for application creation transactions.
+    /// This cannot be changed after creation.
+    #[builder(default)]
+    pub extra_program_pages: Option<u64>,
+
+    /// Transaction specific arguments available in the application's
+    /// approval program and clear state program.
+    #[builder(default)]
+    pub args: Option<Vec<Vec<u8>>>,
+
+    /// List of accounts in addition to the sender that may be accessed
+    /// from the application's approval program and clear state program.
+    #[builder(default)]
+    pub account_references: Option<Vec<Address>>,
+
+    /// List of applications in addition to the application ID that may be called
+    /// from the application's approval program and clear state program.
+    #[builder(default)]
+    pub app_references: Option<Vec<u64>>,
+
+    /// Lists the assets whose parameters may be accessed by this application's
+    /// approval program and clear state program.
+    ///
+    /// The access is read-only.
+    #[builder(default)]
+    pub asset_references: Option<Vec<u64>>,
+
+    /// The boxes that may be accessed by this transaction (and other transactions in the same group).
+    #[builder(default)]
+    pub box_references: Option<Vec<BoxReference>>,
+
+    /// The lowest application version for which this transaction should immediately fail.
+    ///
+    /// A value of 0 (or None) indicates that the version is not checked.
+    #[builder(default)]
+    pub reject_version: Option<u64>,
+}
+
+/// The wire representation of a box reference, where the owning application is
+/// encoded as an index into the foreign apps array of the transaction.
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+struct BoxReferenceEncoding {
+    #[serde(rename = "i")]
+    #[serde(skip_serializing_if = "is_zero")]
+    #[serde(default)]
+    app_index: u64,
+
+    #[serde(rename = "n")]
+    #[serde_as(as = "Bytes")]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
+    name: Vec<u8>,
+
+    #[serde(flatten)]
+    #[serde(skip_serializing_if = "UnknownFields::is_empty")]
+    #[serde(default)]
+    unknown_fields: UnknownFields,
+}
+
+/// The wire representation of an application call transaction.
+///
+/// Box references can't be encoded without knowledge of the app references, so the
+/// conversion to and from this struct resolves the box reference app indexes.
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+struct ApplicationCallTransactionFieldsEncoding {
+    #[serde(flatten)]
+    header: TransactionHeader,
+
+    #[serde(rename = "apid")]
+    #[serde(skip_serializing_if = "is_zero")]
+    #[serde(default)]
+    app_id: u64,
+
+    #[serde(rename = "apan")]
+    #[serde(skip_serializing_if = "is_no_op")]
+    #[serde(default)]
+    on_complete: OnApplicationComplete,
+
+    #[serde(rename = "apap")]
+    #[serde_as(as = "Option<Bytes>")]
+    #[serde(skip_serializing_if = "is_empty_vec_opt")]
+    #[serde(default)]
+    approval_program: Option<Vec<u8>>,
+
+    #[serde(rename = "apsu")]
+    #[serde_as(as = "Option<Bytes>")]
+    #[serde(skip_serializing_if = "is_empty_vec_opt")]
+    #[serde(default)]
+    clear_state_program: Option<Vec<u8>>,
+
+    #[serde(rename = "apgs")]
+    #[serde(skip_serializing_if = "is_empty_state_schema_opt")]
+    #[serde(default)]
+    global_state_schema: Option<StateSchema>,
+
+    #[serde(rename = "apls")]
+    #[serde(skip_serializing_if = "is_empty_state_schema_opt")]
+    #[serde(default)]
+    local_state_schema: Option<StateSchema>,
+
+    #[serde(rename = "apep")]
+    #[serde(skip_serializing_if = "is_zero_opt")]
+    #[serde(default)]
+    extra_program_pages: Option<u64>,
+
+    #[serde(rename = "apaa")]
+    #[serde_as(as = "Option<Vec<Bytes>>")]
+    #[serde(skip_serializing_if = "is_empty_vec_opt")]
+    #[serde(default)]
+    args: Option<Vec<Vec<u8>>>,
+
+    #[serde(rename = "apat")]
+    #[serde(skip_serializing_if = "is_empty_vec_opt")]
+    #[serde(default)]
+    account_references: Option<Vec<Address>>,
+
+    #[serde(rename = "apfa")]
+    #[serde(skip_serializing_if = "is_empty_vec_opt")]
+    #[serde(default)]
+    app_references: Option<Vec<u64>>,
+
+    #[serde(rename = "apas")]
+    #[serde(skip_serializing_if = "is_empty_vec_opt")]
+    #[serde(default)]
+    asset_references: Option<Vec<u64>>,
+
+    #[serde(rename = "apbx")]
+    #[serde(skip_serializing_if = "is_empty_vec_opt")]
+    #[serde(default)]
+    box_references: Option<Vec<BoxReferenceEncoding>>,
+
+    #[serde(rename = "aprv")]
+    #[serde(skip_serializing_if = "is_zero_opt")]
+    #[serde(default)]
+    reject_version: Option<u64>,
+}
+
+impl Serialize for ApplicationCallTransactionFields {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        ApplicationCallTransactionFieldsEncoding::try_from(self.clone())
+            .map_err(S::Error::custom)?
+            .serialize(serializer)
+    }
+}
+
+impl TryFrom<ApplicationCallTransactionFields> for ApplicationCallTransactionFieldsEncoding {
+    type Error = String;
+
+    fn try_from(fields: ApplicationCallTransactionFields) -> Result<Self, Self::Error> {
+        let app_id = fields.app_id;
+        let app_references = fields.app_references;
+
+        let box_references = fields
+            .box_references
+            .map(|boxes| {
+                boxes
+                    .into_iter()
+                    .map(|box_ref| {
+                        let position = app_references
+                            .as_ref()
+                            .and_then(|refs| refs.iter().position(|id| *id == box_ref.app_id));
+                        let app_index = match position {
+                            _ if box_ref.app_id == 0 => 0,
+                            Some(position) => position as u64 + 1,
+                            None if box_ref.app_id == app_id => 0,
+                            None => {
+                                return Err(format!(
+                                    "box reference app id {} is not in the app references",
+                                    box_ref.app_id
+                                ))
+                            }
+                        };
+
+                        Ok(BoxReferenceEncoding {
+                            app_index,
+                            name: box_ref.name,
+                            unknown_fields: box_ref.unknown_fields,
+                        })
+                    })
+                    .collect::<Result<Vec<_>, String>>()
+            })
+            .transpose()?;
+
+        Ok(Self {
+            header: fields.header,
+            app_id,
+            on_complete: fields.on_complete,
+            approval_program: fields.approval_program,
+            clear_state_program: fields.clear_state_program,
+            global_state_schema: fields.global_state_schema,
+            local_state_schema: fields.local_state_schema,
+            extra_program_pages: fields.extra_program_pages,
+            args: fields.args,
+            account_references: fields.account_references,
+            app_references,
+            asset_references: fields.asset_references,
+            box_references,
+            reject_version: fields.reject_version,
+        })
+    }
+}
+
+impl TryFrom<ApplicationCallTransactionFieldsEncoding> for ApplicationCallTransactionFields {
+    type Error = String;
+
+    fn try_from(encoding: ApplicationCallTransactionFieldsEncoding) -> Result<Self, Self::Error> {
+        let box_references = encoding
+            .box_references
+            .map(|boxes| {
+                boxes
+                    .into_iter()
+                    .map(|box_ref| {
+                        let app_id = match box_ref.app_index {
+                            0 => 0,
+                            index => encoding
+                                .app_references
+                                .as_ref()
+                                .and_then(|refs| refs.get(index as usize - 1))
+                                .copied()
+                                .ok_or_else(|| {
+                                    format!(
+                                        "box reference app index {} is not in the app references",
+                                        index
+                                    )
+                                })?,
+                        };
+
+                        Ok(BoxReference {
+                            app_id,
+                            name: box_ref.name,
+                            unknown_fields: box_ref.unknown_fields,
+                        })
+                    })
+                    .collect::<Result<Vec<_>, String>>()
+            })
+            .transpose()?;
+
+        Ok(Self {
+            header: encoding.header,
+            app_id: encoding.app_id,
+            on_complete: encoding.on_complete,
+            approval_program: encoding.approval_program,
+            clear_state_program: encoding.clear_state_program,
+            global_state_schema: encoding.global_state_schema,
+            local_state_schema: encoding.local_state_schema,
+            extra_program_pages: encoding.extra_program_pages,
+            args: encoding.args,
+            account_references: encoding.account_references,
+            app_references: encoding.app_references,
+            asset_references: encoding.asset_references,
+            box_references,
+            reject_version: encoding.reject_version,
+        })
+    }
+}
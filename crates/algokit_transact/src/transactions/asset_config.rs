@@ -0,0 +1,231 @@
+//! Asset config transaction module for AlgoKit Core.
+//!
+//! This module provides functionality for creating and managing asset config transactions,
+//! which are used to create, reconfigure and destroy Algorand Standard Assets (ASAs).
+
+use crate::address::Address;
+use crate::constants::{
+    Byte32, MAX_ASSET_DECIMALS, MAX_ASSET_NAME_LENGTH, MAX_ASSET_UNIT_NAME_LENGTH,
+    MAX_ASSET_URL_LENGTH,
+};
+use crate::transactions::common::{TransactionHeader, UnknownFields};
+use crate::utils::{
+    is_empty_bytes32_opt, is_empty_vec_opt, is_false_opt, is_zero, is_zero_addr_opt, is_zero_opt,
+    is_zero_u32_opt, ByteString,
+};
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, skip_serializing_none, Bytes};
+
+/// The parameters of an Algorand Standard Asset.
+///
+/// When creating an asset all parameters may be set. When reconfiguring an asset only the
+/// manager, reserve, freeze and clawback addresses may be supplied, and any address which is
+/// omitted is cleared.
+///
+/// The unit name, asset name and URL are usually UTF-8 text, but may be any bytes.
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+pub struct AssetParams {
+    /// The total amount of the smallest divisible (decimal) unit to create.
+    #[serde(rename = "t")]
+    #[serde(skip_serializing_if = "is_zero_opt")]
+    #[serde(default)]
+    pub total: Option<u64>,
+
+    /// The amount of decimal places the asset should have.
+    ///
+    /// This is a value between 0 and 19 (inclusive).
+    #[serde(rename = "dc")]
+    #[serde(skip_serializing_if = "is_zero_u32_opt")]
+    #[serde(default)]
+    pub decimals: Option<u32>,
+
+    /// Whether the asset is frozen by default for all accounts.
+    #[serde(rename = "df")]
+    #[serde(skip_serializing_if = "is_false_opt")]
+    #[serde(default)]
+    pub default_frozen: Option<bool>,
+
+    /// The optional name of the unit of this asset (e.g. ticker name).
+    ///
+    /// Max size is 8 bytes.
+    #[serde(rename = "un")]
+    #[serde_as(as = "Option<ByteString>")]
+    #[serde(skip_serializing_if = "is_empty_vec_opt")]
+    #[serde(default)]
+    pub unit_name: Option<Vec<u8>>,
+
+    /// The optional name of the asset.
+    ///
+    /// Max size is 32 bytes.
+    #[serde(rename = "an")]
+    #[serde_as(as = "Option<ByteString>")]
+    #[serde(skip_serializing_if = "is_empty_vec_opt")]
+    #[serde(default)]
+    pub asset_name: Option<Vec<u8>>,
+
+    /// Specifies an optional URL where more information about the asset can be retrieved.
+    ///
+    /// Max size is 96 bytes.
+    #[serde(rename = "au")]
+    #[serde_as(as = "Option<ByteString>")]
+    #[serde(skip_serializing_if = "is_empty_vec_opt")]
+    #[serde(default)]
+    pub url: Option<Vec<u8>>,
+
+    /// 32-byte hash of some metadata that is relevant to your asset and/or asset holders.
+    #[serde(rename = "am")]
+    #[serde_as(as = "Option<Bytes>")]
+    #[serde(skip_serializing_if = "is_empty_bytes32_opt")]
+    #[serde(default)]
+    pub metadata_hash: Option<Byte32>,
+
+    /// The address of the optional account that can manage the configuration of the asset and
+    /// destroy it.
+    #[serde(rename = "m")]
+    #[serde(skip_serializing_if = "is_zero_addr_opt")]
+    #[serde(default)]
+    pub manager: Option<Address>,
+
+    /// The address of the optional account that holds the reserve (uncirculated supply) units of
+    /// the asset.
+    #[serde(rename = "r")]
+    #[serde(skip_serializing_if = "is_zero_addr_opt")]
+    #[serde(default)]
+    pub reserve: Option<Address>,
+
+    /// The address of the optional account that can be used to freeze or unfreeze holdings of
+    /// this asset for any account.
+    #[serde(rename = "f")]
+    #[serde(skip_serializing_if = "is_zero_addr_opt")]
+    #[serde(default)]
+    pub freeze: Option<Address>,
+
+    /// The address of the optional account that can clawback holdings of this asset from any
+    /// account.
+    #[serde(rename = "c")]
+    #[serde(skip_serializing_if = "is_zero_addr_opt")]
+    #[serde(default)]
+    pub clawback: Option<Address>,
+
+    /// Fields of the asset params which are not recognised by this crate.
+    #[serde(flatten)]
+    #[serde(skip_serializing_if = "UnknownFields::is_empty")]
+    #[serde(default)]
+    pub unknown_fields: UnknownFields,
+}
+
+impl AssetParams {
+    /// Returns true if no parameters are set, which is the case for an asset destroy transaction.
+    pub fn is_empty(&self) -> bool {
+        !self.has_immutable_params()
+            && is_zero_addr_opt(&self.manager)
+            && is_zero_addr_opt(&self.reserve)
+            && is_zero_addr_opt(&self.freeze)
+            && is_zero_addr_opt(&self.clawback)
+    }
+
+    /// Returns true if any of the parameters which can't be changed after creation are set.
+    fn has_immutable_params(&self) -> bool {
+        !(is_zero_opt(&self.total)
+            && is_zero_u32_opt(&self.decimals)
+            && is_false_opt(&self.default_frozen)
+            && is_empty_vec_opt(&self.unit_name)
+            && is_empty_vec_opt(&self.asset_name)
+            && is_empty_vec_opt(&self.url)
+            && is_empty_bytes32_opt(&self.metadata_hash))
+    }
+}
+
+fn is_empty_asset_params_opt(params: &Option<AssetParams>) -> bool {
+    params
+        .as_ref()
+        .is_none_or(|params| params.is_empty() && params.unknown_fields.is_empty())
+}
+
+/// Represents an asset config transaction that creates, reconfigures or destroys an ASA.
+///
+/// - Create: `asset_id` is 0 and `params` contains the parameters of the new asset.
+/// - Reconfigure: `asset_id` is the asset to reconfigure and `params` contains the new
+///   manager, reserve, freeze and clawback addresses.
+/// - Destroy: `asset_id` is the asset to destroy and `params` is empty.
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Builder)]
+#[builder(
+    name = "AssetConfigTransactionBuilder",
+    setter(strip_option),
+    build_fn(name = "build_fields", validate = "Self::validate")
+)]
+pub struct AssetConfigTransactionFields {
+    /// Common transaction header fields.
+    #[serde(flatten)]
+    pub header: TransactionHeader,
+
+    /// The ID of the asset being reconfigured or destroyed.
+    ///
+    /// Set this to 0 to indicate an asset creation transaction.
+    #[serde(rename = "caid")]
+    #[serde(skip_serializing_if = "is_zero")]
+    #[serde(default)]
+    #[builder(default)]
+    pub asset_id: u64,
+
+    /// The parameters of the asset.
+    ///
+    /// Required when creating an asset and omitted when destroying an asset.
+    #[serde(rename = "apar")]
+    #[serde(skip_serializing_if = "is_empty_asset_params_opt")]
+    #[serde(default)]
+    #[builder(default)]
+    pub params: Option<AssetParams>,
+}
+
+impl AssetConfigTransactionBuilder {
+    fn validate(&self) -> Result<(), String> {
+        let asset_id = self.asset_id.unwrap_or_default();
+        let params = self
+            .params
+            .as_ref()
+            .and_then(Option::as_ref)
+            .filter(|params| !params.is_empty());
+
+        let Some(params) = params else {
+            if asset_id == 0 {
+                return Err("Asset params are required when creating an asset".to_string());
+            }
+            return Ok(());
+        };
+
+        if asset_id != 0 && params.has_immutable_params() {
+            return Err(
+                "Only the manager, reserve, freeze and clawback addresses can be reconfigured"
+                    .to_string(),
+            );
+        }
+
+        if params.decimals.unwrap_or_default() > MAX_ASSET_DECIMALS {
+            return Err(format!(
+                "Asset decimals must not exceed {}",
+                MAX_ASSET_DECIMALS
+            ));
+        }
+
+        for (name, value, max_length) in [
+            ("unit name", &params.unit_name, MAX_ASSET_UNIT_NAME_LENGTH),
+            ("asset name", &params.asset_name, MAX_ASSET_NAME_LENGTH),
+            ("url", &params.url, MAX_ASSET_URL_LENGTH),
+        ] {
+            if value.as_ref().is_some_and(|value| value.len() > max_length) {
+                return Err(format!(
+                    "Asset {} must not exceed {} bytes",
+                    name, max_length
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,49 @@
+//! Asset freeze transaction module for AlgoKit Core.
+//!
+//! This module provides functionality for creating and managing asset freeze transactions,
+//! which are used to freeze or unfreeze holdings of an ASA for a specific account.
+
+use crate::address::Address;
+use crate::transactions::common::TransactionHeader;
+use crate::utils::{is_false, is_zero, is_zero_addr};
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, skip_serializing_none};
+
+/// Represents an asset freeze transaction that freezes or unfreezes an account's ASA holding.
+///
+/// The sender of the transaction must be the freeze address of the asset.
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Builder)]
+#[builder(
+    name = "AssetFreezeTransactionBuilder",
+    setter(strip_option),
+    build_fn(name = "build_fields")
+)]
+pub struct AssetFreezeTransactionFields {
+    /// Common transaction header fields.
+    #[serde(flatten)]
+    pub header: TransactionHeader,
+
+    /// The ID of the asset being frozen or unfrozen.
+    #[serde(rename = "faid")]
+    #[serde(skip_serializing_if = "is_zero")]
+    #[serde(default)]
+    pub asset_id: u64,
+
+    /// The address of the account whose holding of the asset is being frozen or unfrozen.
+    #[serde(rename = "fadd")]
+    #[serde(skip_serializing_if = "is_zero_addr")]
+    #[serde(default)]
+    pub freeze_target: Address,
+
+    /// The new frozen state of the holding.
+    ///
+    /// `true` freezes the holding, `false` unfreezes it.
+    #[serde(rename = "afrz")]
+    #[serde(skip_serializing_if = "is_false")]
+    #[serde(default)]
+    #[builder(default)]
+    pub frozen: bool,
+}
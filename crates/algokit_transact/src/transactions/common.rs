@@ -12,6 +12,14 @@ use crate::utils::{
 use derive_builder::Builder;
 use serde::{Deserialize, Serialize};
 use serde_with::{serde_as, skip_serializing_none, Bytes};
+use std::collections::BTreeMap;
+
+/// The fields of a msgpack map which are not recognised by this crate, such as fields added by
+/// a newer protocol version, keyed by their msgpack key.
+///
+/// These are only retained when decoding with `decode_preserving_unknown_fields()`, in which case
+/// they are emitted again when encoding. Otherwise they are dropped when decoding.
+pub type UnknownFields = BTreeMap<String, rmpv::Value>;
 
 /// Common header fields shared by all transaction types.
 ///
@@ -112,4 +120,18 @@ pub struct TransactionHeader {
     #[serde(default)]
     #[builder(default)]
     pub group: Option<Byte32>,
+
+    /// Fields of the transaction which are not recognised by this crate, such as fields added by
+    /// a newer protocol version.
+    ///
+    /// These are the unknown top level keys of the transaction, which hold the header and the
+    /// fields of each transaction type. The unknown keys of the asset params, state schemas and
+    /// box references are retained on those. Unknown keys within the heartbeat and state proof
+    /// fields are still dropped, as are non-UTF-8 strings within unknown fields, which are
+    /// re-encoded as binary. Re-encoding a transaction with any of these changes its ID.
+    #[serde(flatten)]
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    #[serde(default)]
+    #[builder(default)]
+    pub unknown_fields: UnknownFields,
 }
@@ -0,0 +1,122 @@
+//! Heartbeat transaction module for AlgoKit Core.
+//!
+//! This module provides decode support for heartbeat transactions, which are issued by
+//! participating nodes to prove that an account's participation keys are still online. These
+//! transactions are created by the node itself, so no builder is provided; they are modelled so
+//! they can be decoded, re-encoded and have their transaction ID computed.
+
+use crate::address::Address;
+use crate::constants::{Byte32, ALGORAND_SIGNATURE_BYTE_LENGTH};
+use crate::transactions::common::TransactionHeader;
+use crate::utils::{is_default, is_empty_bytes32, is_empty_bytes64, is_zero, is_zero_addr};
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, Bytes};
+
+/// A proof that the heartbeat was signed by the account's current participation key.
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct HeartbeatProof {
+    /// The signature of the heartbeat seed by the one-time key.
+    #[serde(rename = "s")]
+    #[serde_as(as = "Bytes")]
+    #[serde(skip_serializing_if = "is_empty_bytes64")]
+    #[serde(default = "HeartbeatProof::empty_signature")]
+    pub signature: [u8; ALGORAND_SIGNATURE_BYTE_LENGTH],
+
+    /// The one-time public key.
+    #[serde(rename = "p")]
+    #[serde_as(as = "Bytes")]
+    #[serde(skip_serializing_if = "is_empty_bytes32")]
+    #[serde(default)]
+    pub public_key: Byte32,
+
+    /// The batch public key which signs the one-time public key.
+    #[serde(rename = "p2")]
+    #[serde_as(as = "Bytes")]
+    #[serde(skip_serializing_if = "is_empty_bytes32")]
+    #[serde(default)]
+    pub public_key_2: Byte32,
+
+    /// The signature of the one-time public key by the batch key.
+    #[serde(rename = "p1s")]
+    #[serde_as(as = "Bytes")]
+    #[serde(skip_serializing_if = "is_empty_bytes64")]
+    #[serde(default = "HeartbeatProof::empty_signature")]
+    pub public_key_1_signature: [u8; ALGORAND_SIGNATURE_BYTE_LENGTH],
+
+    /// The signature of the batch public key by the vote key.
+    #[serde(rename = "p2s")]
+    #[serde_as(as = "Bytes")]
+    #[serde(skip_serializing_if = "is_empty_bytes64")]
+    #[serde(default = "HeartbeatProof::empty_signature")]
+    pub public_key_2_signature: [u8; ALGORAND_SIGNATURE_BYTE_LENGTH],
+}
+
+impl HeartbeatProof {
+    fn empty_signature() -> [u8; ALGORAND_SIGNATURE_BYTE_LENGTH] {
+        [0; ALGORAND_SIGNATURE_BYTE_LENGTH]
+    }
+}
+
+impl Default for HeartbeatProof {
+    fn default() -> Self {
+        Self {
+            signature: Self::empty_signature(),
+            public_key: Byte32::default(),
+            public_key_2: Byte32::default(),
+            public_key_1_signature: Self::empty_signature(),
+            public_key_2_signature: Self::empty_signature(),
+        }
+    }
+}
+
+/// The parameters of a heartbeat.
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+pub struct HeartbeatParams {
+    /// The account this heartbeat is for.
+    #[serde(rename = "a")]
+    #[serde(skip_serializing_if = "is_zero_addr")]
+    #[serde(default)]
+    pub address: Address,
+
+    /// The proof that the account's participation key is online.
+    #[serde(rename = "prf")]
+    #[serde(skip_serializing_if = "is_default")]
+    #[serde(default)]
+    pub proof: HeartbeatProof,
+
+    /// The block seed for the first valid round of the heartbeat.
+    #[serde(rename = "sd")]
+    #[serde_as(as = "Bytes")]
+    #[serde(skip_serializing_if = "is_empty_bytes32")]
+    #[serde(default)]
+    pub seed: Byte32,
+
+    /// The vote ID (root participation public key) of the account.
+    #[serde(rename = "vid")]
+    #[serde_as(as = "Bytes")]
+    #[serde(skip_serializing_if = "is_empty_bytes32")]
+    #[serde(default)]
+    pub vote_id: Byte32,
+
+    /// The key dilution of the account's participation key.
+    #[serde(rename = "kd")]
+    #[serde(skip_serializing_if = "is_zero")]
+    #[serde(default)]
+    pub key_dilution: u64,
+}
+
+/// Represents a heartbeat transaction.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct HeartbeatTransactionFields {
+    /// Common transaction header fields.
+    #[serde(flatten)]
+    pub header: TransactionHeader,
+
+    /// The parameters of the heartbeat.
+    #[serde(rename = "hb")]
+    #[serde(skip_serializing_if = "is_default")]
+    #[serde(default)]
+    pub params: HeartbeatParams,
+}
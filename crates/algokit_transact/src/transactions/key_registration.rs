@@ -0,0 +1,157 @@
+//! Key registration transaction module for AlgoKit Core.
+//!
+//! This module provides functionality for creating and managing key registration transactions,
+//! which are used to register an account online or offline for participation in consensus.
+
+use crate::constants::{Byte32, ALGORAND_STATE_PROOF_KEY_BYTE_LENGTH};
+use crate::transactions::common::TransactionHeader;
+use crate::utils::{is_empty_bytes32_opt, is_empty_bytes64_opt, is_false_opt, is_zero_opt};
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, skip_serializing_none, Bytes};
+
+/// Represents a key registration transaction that registers an account's participation status.
+///
+/// - Online: all participation keys and vote parameters are set.
+/// - Offline: no participation keys or vote parameters are set.
+/// - Non-participating: `non_participation` is true and no participation keys or vote
+///   parameters are set. This permanently marks the account as not participating in consensus.
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Builder)]
+#[builder(
+    name = "KeyRegistrationTransactionBuilder",
+    setter(strip_option),
+    build_fn(name = "build_fields", validate = "Self::validate")
+)]
+pub struct KeyRegistrationTransactionFields {
+    /// Common transaction header fields.
+    #[serde(flatten)]
+    pub header: TransactionHeader,
+
+    /// The root participation public key.
+    #[serde(rename = "votekey")]
+    #[serde_as(as = "Option<Bytes>")]
+    #[serde(skip_serializing_if = "is_empty_bytes32_opt")]
+    #[serde(default)]
+    #[builder(default)]
+    pub vote_key: Option<Byte32>,
+
+    /// The VRF public key.
+    #[serde(rename = "selkey")]
+    #[serde_as(as = "Option<Bytes>")]
+    #[serde(skip_serializing_if = "is_empty_bytes32_opt")]
+    #[serde(default)]
+    #[builder(default)]
+    pub selection_key: Option<Byte32>,
+
+    /// The 64-byte state proof public key commitment.
+    #[serde(rename = "sprfkey")]
+    #[serde_as(as = "Option<Bytes>")]
+    #[serde(skip_serializing_if = "is_empty_bytes64_opt")]
+    #[serde(default)]
+    #[builder(default)]
+    pub state_proof_key: Option<[u8; ALGORAND_STATE_PROOF_KEY_BYTE_LENGTH]>,
+
+    /// The first round that the participation key is valid.
+    #[serde(rename = "votefst")]
+    #[serde(skip_serializing_if = "is_zero_opt")]
+    #[serde(default)]
+    #[builder(default)]
+    pub vote_first: Option<u64>,
+
+    /// The last round that the participation key is valid.
+    #[serde(rename = "votelst")]
+    #[serde(skip_serializing_if = "is_zero_opt")]
+    #[serde(default)]
+    #[builder(default)]
+    pub vote_last: Option<u64>,
+
+    /// The dilution for the 2-level participation key.
+    #[serde(rename = "votekd")]
+    #[serde(skip_serializing_if = "is_zero_opt")]
+    #[serde(default)]
+    #[builder(default)]
+    pub vote_key_dilution: Option<u64>,
+
+    /// Mark the account as participating or non-participating in consensus.
+    ///
+    /// Once an account is marked as non-participating it can never be registered online again.
+    #[serde(rename = "nonpart")]
+    #[serde(skip_serializing_if = "is_false_opt")]
+    #[serde(default)]
+    #[builder(default)]
+    pub non_participation: Option<bool>,
+}
+
+impl KeyRegistrationTransactionFields {
+    /// Returns true if this transaction registers the account online.
+    pub fn is_online(&self) -> bool {
+        !is_empty_bytes32_opt(&self.vote_key)
+    }
+
+    /// Returns true if this transaction registers the account offline.
+    pub fn is_offline(&self) -> bool {
+        !self.is_online() && !self.is_non_participating()
+    }
+
+    /// Returns true if this transaction marks the account as non-participating.
+    pub fn is_non_participating(&self) -> bool {
+        !is_false_opt(&self.non_participation)
+    }
+}
+
+impl KeyRegistrationTransactionBuilder {
+    fn validate(&self) -> Result<(), String> {
+        let online_fields = [
+            ("vote key", !is_empty_bytes32_opt(&self.vote_key.flatten())),
+            (
+                "selection key",
+                !is_empty_bytes32_opt(&self.selection_key.flatten()),
+            ),
+            (
+                "state proof key",
+                !is_empty_bytes64_opt(&self.state_proof_key.flatten()),
+            ),
+            ("vote first", !is_zero_opt(&self.vote_first.flatten())),
+            ("vote last", !is_zero_opt(&self.vote_last.flatten())),
+            (
+                "vote key dilution",
+                !is_zero_opt(&self.vote_key_dilution.flatten()),
+            ),
+        ];
+        let any_online_field = online_fields.iter().any(|(_, is_set)| *is_set);
+
+        if !is_false_opt(&self.non_participation.flatten()) {
+            if any_online_field {
+                return Err(
+                    "Non-participating key registration must not set participation keys or vote parameters"
+                        .to_string(),
+                );
+            }
+            return Ok(());
+        }
+
+        if !any_online_field {
+            return Ok(());
+        }
+
+        let missing: Vec<&str> = online_fields
+            .iter()
+            .filter(|(_, is_set)| !*is_set)
+            .map(|(name, _)| *name)
+            .collect();
+        if !missing.is_empty() {
+            return Err(format!(
+                "Online key registration is missing: {}",
+                missing.join(", ")
+            ));
+        }
+
+        if self.vote_first.flatten() > self.vote_last.flatten() {
+            return Err("Vote first must not be greater than vote last".to_string());
+        }
+
+        Ok(())
+    }
+}
@@ -4,21 +4,47 @@
 //! This module includes support for various transaction types, along with the ability to sign,
 //! serialize, and deserialize them.
 
+mod application_call;
+mod asset_config;
+mod asset_freeze;
 mod asset_transfer;
 mod common;
+mod heartbeat;
+mod key_registration;
 mod payment;
+mod state_proof;
+mod validation;
 
+use application_call::ApplicationCallTransactionBuilderError;
+pub use application_call::{
+    ApplicationCallTransactionBuilder, ApplicationCallTransactionFields, BoxReference,
+    OnApplicationComplete, StateSchema,
+};
+use asset_config::AssetConfigTransactionBuilderError;
+pub use asset_config::{AssetConfigTransactionBuilder, AssetConfigTransactionFields, AssetParams};
+use asset_freeze::AssetFreezeTransactionBuilderError;
+pub use asset_freeze::{AssetFreezeTransactionBuilder, AssetFreezeTransactionFields};
 use asset_transfer::AssetTransferTransactionBuilderError;
 pub use asset_transfer::{AssetTransferTransactionBuilder, AssetTransferTransactionFields};
-pub use common::{TransactionHeader, TransactionHeaderBuilder};
+pub use common::{TransactionHeader, TransactionHeaderBuilder, UnknownFields};
+pub use heartbeat::{HeartbeatParams, HeartbeatProof, HeartbeatTransactionFields};
+use key_registration::KeyRegistrationTransactionBuilderError;
+pub use key_registration::{KeyRegistrationTransactionBuilder, KeyRegistrationTransactionFields};
 use payment::PaymentTransactionBuilderError;
 pub use payment::{PaymentTransactionBuilder, PaymentTransactionFields};
+pub use state_proof::{
+    FalconVerifier, HashFactory, MerkleArrayProof, MerkleSignature, MerkleSignatureVerifier,
+    Participant, Reveal, SigslotCommit, StateProof, StateProofMessage, StateProofTransactionFields,
+};
+pub use validation::ConsensusParams;
 
 use crate::constants::{
     ALGORAND_SIGNATURE_BYTE_LENGTH, ALGORAND_SIGNATURE_ENCODING_INCR, HASH_BYTES_LENGTH,
     MAX_TX_GROUP_SIZE,
 };
 use crate::error::AlgoKitTransactError;
+use crate::logic_signature::LogicSignature;
+use crate::multisig::MultisigSignature;
 use crate::traits::{AlgorandMsgpack, EstimateTransactionSize, TransactionId, Transactions};
 use crate::utils::{compute_group_id, is_zero_addr_opt};
 use crate::Address;
@@ -35,18 +61,24 @@ pub enum Transaction {
 
     #[serde(rename = "axfer")]
     AssetTransfer(AssetTransferTransactionFields),
-    // All the below transaction variants will be implemented in the future
-    // #[serde(rename = "afrz")]
-    // AssetFreeze(...),
 
-    // #[serde(rename = "acfg")]
-    // AssetConfig(...),
+    #[serde(rename = "appl")]
+    ApplicationCall(ApplicationCallTransactionFields),
+
+    #[serde(rename = "acfg")]
+    AssetConfig(AssetConfigTransactionFields),
 
-    // #[serde(rename = "keyreg")]
-    // KeyRegistration(...),
+    #[serde(rename = "afrz")]
+    AssetFreeze(AssetFreezeTransactionFields),
 
-    // #[serde(rename = "appl")]
-    // ApplicationCall(...),
+    #[serde(rename = "keyreg")]
+    KeyRegistration(KeyRegistrationTransactionFields),
+
+    #[serde(rename = "stpf")]
+    StateProof(StateProofTransactionFields),
+
+    #[serde(rename = "hb")]
+    Heartbeat(HeartbeatTransactionFields),
 }
 
 pub struct FeeParams {
@@ -56,11 +88,37 @@ pub struct FeeParams {
     pub max_fee: Option<u64>,
 }
 
+/// Parameters for assigning the fees of a transaction group, where the fees of the
+/// transactions are pooled so one transaction can pay for others.
+pub struct GroupFeeParams {
+    pub fee_per_byte: u64,
+    pub min_fee: u64,
+    /// The fee parameters of each transaction, in group order.
+    pub transactions: Vec<TransactionFeeParams>,
+}
+
+/// The fee parameters of a single transaction within a group.
+#[derive(Debug, Clone, Default)]
+pub struct TransactionFeeParams {
+    /// An additional fee to pay on top of the required fee of the transaction.
+    pub extra_fee: Option<u64>,
+    /// The maximum fee the transaction will pay.
+    pub max_fee: Option<u64>,
+    /// The number of inner transactions, issued by the transaction, whose fees it covers.
+    pub inner_transactions: u64,
+}
+
 impl Transaction {
     pub fn header(&self) -> &TransactionHeader {
         match self {
             Transaction::Payment(p) => &p.header,
             Transaction::AssetTransfer(a) => &a.header,
+            Transaction::ApplicationCall(a) => &a.header,
+            Transaction::AssetConfig(a) => &a.header,
+            Transaction::AssetFreeze(a) => &a.header,
+            Transaction::KeyRegistration(k) => &k.header,
+            Transaction::StateProof(s) => &s.header,
+            Transaction::Heartbeat(h) => &h.header,
         }
     }
 
@@ -68,21 +126,18 @@ impl Transaction {
         match self {
             Transaction::Payment(p) => &mut p.header,
             Transaction::AssetTransfer(a) => &mut a.header,
+            Transaction::ApplicationCall(a) => &mut a.header,
+            Transaction::AssetConfig(a) => &mut a.header,
+            Transaction::AssetFreeze(a) => &mut a.header,
+            Transaction::KeyRegistration(k) => &mut k.header,
+            Transaction::StateProof(s) => &mut s.header,
+            Transaction::Heartbeat(h) => &mut h.header,
         }
     }
 
     pub fn assign_fee(&self, request: FeeParams) -> Result<Transaction, AlgoKitTransactError> {
         let mut tx = self.clone();
-        let mut calculated_fee: u64 = 0;
-
-        if request.fee_per_byte > 0 {
-            let estimated_size = tx.estimate_size()?;
-            calculated_fee = request.fee_per_byte * estimated_size as u64;
-        }
-
-        if calculated_fee < request.min_fee {
-            calculated_fee = request.min_fee;
-        }
+        let mut calculated_fee = tx.required_fee(request.fee_per_byte, request.min_fee)?;
 
         if let Some(extra_fee) = request.extra_fee {
             calculated_fee += extra_fee;
@@ -100,30 +155,93 @@ impl Transaction {
         let header = tx.header_mut();
         header.fee = Some(calculated_fee);
 
-        return Ok(tx);
+        Ok(tx)
+    }
+
+    /// Calculates the fee required for the transaction, which is the fee for its estimated size,
+    /// but no less than the minimum fee.
+    fn required_fee(&self, fee_per_byte: u64, min_fee: u64) -> Result<u64, AlgoKitTransactError> {
+        let mut calculated_fee: u64 = 0;
+
+        if fee_per_byte > 0 {
+            let estimated_size = self.estimate_size()?;
+            calculated_fee = fee_per_byte * estimated_size as u64;
+        }
+
+        Ok(calculated_fee.max(min_fee))
     }
 }
 
 impl PaymentTransactionBuilder {
     pub fn build(&self) -> Result<Transaction, PaymentTransactionBuilderError> {
-        self.build_fields().map(|d| Transaction::Payment(d))
+        self.build_fields().map(Transaction::Payment)
     }
 }
 
 impl AssetTransferTransactionBuilder {
     pub fn build(&self) -> Result<Transaction, AssetTransferTransactionBuilderError> {
-        self.build_fields().map(|d| Transaction::AssetTransfer(d))
+        self.build_fields().map(Transaction::AssetTransfer)
+    }
+}
+
+impl ApplicationCallTransactionBuilder {
+    pub fn build(&self) -> Result<Transaction, ApplicationCallTransactionBuilderError> {
+        self.build_fields().map(Transaction::ApplicationCall)
+    }
+}
+
+impl AssetConfigTransactionBuilder {
+    pub fn build(&self) -> Result<Transaction, AssetConfigTransactionBuilderError> {
+        self.build_fields().map(Transaction::AssetConfig)
+    }
+}
+
+impl AssetFreezeTransactionBuilder {
+    pub fn build(&self) -> Result<Transaction, AssetFreezeTransactionBuilderError> {
+        self.build_fields().map(Transaction::AssetFreeze)
+    }
+}
+
+impl KeyRegistrationTransactionBuilder {
+    pub fn build(&self) -> Result<Transaction, KeyRegistrationTransactionBuilderError> {
+        self.build_fields().map(Transaction::KeyRegistration)
     }
 }
 
 impl AlgorandMsgpack for Transaction {
     const PREFIX: &'static [u8] = b"TX";
+
+    fn drop_unknown_fields(&mut self) {
+        self.header_mut().unknown_fields.clear();
+        match self {
+            Transaction::AssetConfig(fields) => {
+                if let Some(params) = &mut fields.params {
+                    params.unknown_fields.clear();
+                }
+            }
+            Transaction::ApplicationCall(fields) => {
+                for schema in [
+                    &mut fields.global_state_schema,
+                    &mut fields.local_state_schema,
+                ]
+                .into_iter()
+                .flatten()
+                {
+                    schema.unknown_fields.clear();
+                }
+                for box_ref in fields.box_references.iter_mut().flatten() {
+                    box_ref.unknown_fields.clear();
+                }
+            }
+            _ => {}
+        }
+    }
 }
 impl TransactionId for Transaction {}
 
 impl EstimateTransactionSize for Transaction {
     fn estimate_size(&self) -> Result<usize, AlgoKitTransactError> {
-        return Ok(self.encode_raw()?.len() + ALGORAND_SIGNATURE_ENCODING_INCR);
+        Ok(self.encode_raw()?.len() + ALGORAND_SIGNATURE_ENCODING_INCR)
     }
 }
 
@@ -138,13 +256,79 @@ pub struct SignedTransaction {
     /// Optional Ed25519 signature authorizing the transaction.
     #[serde(rename = "sig")]
     #[serde_as(as = "Option<Bytes>")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
     pub signature: Option<[u8; ALGORAND_SIGNATURE_BYTE_LENGTH]>,
 
+    /// Optional multisig signature authorizing the transaction, applicable if the transaction
+    /// is authorized by a multisig account.
+    #[serde(rename = "msig")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub multisignature: Option<MultisigSignature>,
+
+    /// Optional logic signature authorizing the transaction, applicable if the transaction is
+    /// authorized by a program.
+    #[serde(rename = "lsig")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub logic_signature: Option<LogicSignature>,
+
     /// Optional auth address applicable if the transaction sender is a rekeyed account.
     #[serde(rename = "sgnr")]
     #[serde(skip_serializing_if = "is_zero_addr_opt")]
     #[serde(default)]
     pub auth_address: Option<Address>,
+
+    /// Fields of the signed transaction which are not recognised by this crate, such as fields
+    /// added by a newer protocol version.
+    ///
+    /// The unknown keys of the multisig and logic signatures are retained on those.
+    #[serde(flatten)]
+    #[serde(skip_serializing_if = "UnknownFields::is_empty")]
+    #[serde(default)]
+    pub unknown_fields: UnknownFields,
+}
+
+impl SignedTransaction {
+    /// Merges the multisig signatures of two signed copies of the same transaction.
+    ///
+    /// This is used to combine the signatures of multisig participants who each signed the
+    /// transaction separately.
+    ///
+    /// # Parameters
+    /// * `other` - Another signed copy of the same transaction
+    ///
+    /// # Returns
+    /// The signed transaction with the merged multisig signature or an error if the
+    /// transactions differ or either is not signed by a multisig account.
+    pub fn merge_multisignatures(
+        &self,
+        other: &SignedTransaction,
+    ) -> Result<SignedTransaction, AlgoKitTransactError> {
+        if self.transaction != other.transaction {
+            return Err(AlgoKitTransactError::InputError(
+                "Cannot merge multisig signatures of different transactions".to_string(),
+            ));
+        }
+        if self.auth_address != other.auth_address {
+            return Err(AlgoKitTransactError::InputError(
+                "Cannot merge multisig signatures with different auth addresses".to_string(),
+            ));
+        }
+
+        let (Some(multisig), Some(other_multisig)) = (&self.multisignature, &other.multisignature)
+        else {
+            return Err(AlgoKitTransactError::InputError(
+                "Both transactions must have a multisig signature".to_string(),
+            ));
+        };
+
+        Ok(SignedTransaction {
+            multisignature: Some(multisig.merge(other_multisig)?),
+            ..self.clone()
+        })
+    }
 }
 
 impl AlgorandMsgpack for SignedTransaction {
@@ -159,7 +343,7 @@ impl AlgorandMsgpack for SignedTransaction {
     // transaction type the bytes actually correspond with. To fix this we need to manually
     // decode the transaction using Transaction::decode (which does check the type) and
     // then add it to the decoded struct
-    fn decode(bytes: &[u8]) -> Result<Self, AlgoKitTransactError> {
+    fn decode_preserving_unknown_fields(bytes: &[u8]) -> Result<Self, AlgoKitTransactError> {
         let value: rmpv::Value = rmp_serde::from_slice(bytes)?;
 
         match value {
@@ -167,25 +351,38 @@ impl AlgorandMsgpack for SignedTransaction {
                 let txn_value = &map
                     .iter()
                     .find(|(k, _)| k.as_str() == Some("txn"))
-                    .unwrap()
+                    .ok_or_else(|| {
+                        AlgoKitTransactError::InputError(
+                            "expected signed transaction to have a txn field".to_string(),
+                        )
+                    })?
                     .1;
 
                 let mut txn_buf = Vec::new();
-                rmpv::encode::write_value(&mut txn_buf, &txn_value)?;
+                rmpv::encode::write_value(&mut txn_buf, txn_value)?;
 
                 let stxn = SignedTransaction {
-                    transaction: Transaction::decode(&txn_buf)?,
+                    transaction: Transaction::decode_preserving_unknown_fields(&txn_buf)?,
                     ..rmp_serde::from_slice(bytes)?
                 };
 
-                return Ok(stxn);
-            }
-            _ => {
-                return Err(AlgoKitTransactError::InputError(format!(
-                    "expected signed transaction to be a map, but got a: {:#?}",
-                    value.type_id()
-                )))
+                Ok(stxn)
             }
+            _ => Err(AlgoKitTransactError::InputError(format!(
+                "expected signed transaction to be a map, but got a: {:#?}",
+                value.type_id()
+            ))),
+        }
+    }
+
+    fn drop_unknown_fields(&mut self) {
+        self.transaction.drop_unknown_fields();
+        self.unknown_fields.clear();
+        if let Some(multisig) = &mut self.multisignature {
+            multisig.unknown_fields.clear();
+        }
+        if let Some(lsig) = &mut self.logic_signature {
+            lsig.drop_unknown_fields();
         }
     }
 }
@@ -201,7 +398,7 @@ impl TransactionId for SignedTransaction {
 
 impl EstimateTransactionSize for SignedTransaction {
     fn estimate_size(&self) -> Result<usize, AlgoKitTransactError> {
-        return Ok(self.encode()?.len());
+        Ok(self.encode()?.len())
     }
 }
 
@@ -234,4 +431,84 @@ impl Transactions for &[Transaction] {
             })
             .collect())
     }
+
+    /// Assigns the fees of the supplied transactions, pooling them so the group as a whole pays
+    /// the fees required by its transactions and the inner transactions they cover.
+    ///
+    /// Each transaction pays its required fee, plus its extra fee and the minimum fee of each
+    /// inner transaction it covers, capped at its max fee. Any shortfall is then paid by the
+    /// transactions of the group, in group order, up to their max fees.
+    ///
+    /// Fees must be assigned before the group is assigned, as they change the transaction IDs.
+    ///
+    /// # Returns
+    /// A result containing the transactions with fees assigned or an error if the fees cannot be
+    /// covered within the max fees of the transactions.
+    fn assign_fees(
+        self,
+        params: &GroupFeeParams,
+    ) -> Result<Vec<Transaction>, AlgoKitTransactError> {
+        if self.len() != params.transactions.len() {
+            return Err(AlgoKitTransactError::InputError(format!(
+                "Expected fee params for {} transactions but got {}",
+                self.len(),
+                params.transactions.len()
+            )));
+        }
+
+        let overflow =
+            || AlgoKitTransactError::InputError(String::from("Transaction group fee overflow"));
+
+        let mut required_total: u64 = 0;
+        let mut fees = Vec::with_capacity(self.len());
+        for (tx, tx_params) in self.iter().zip(&params.transactions) {
+            let inner_fee = params
+                .min_fee
+                .checked_mul(tx_params.inner_transactions)
+                .ok_or_else(overflow)?;
+            let required = tx
+                .required_fee(params.fee_per_byte, params.min_fee)?
+                .checked_add(inner_fee)
+                .ok_or_else(overflow)?;
+            required_total = required_total.checked_add(required).ok_or_else(overflow)?;
+
+            let fee = required
+                .checked_add(tx_params.extra_fee.unwrap_or(0))
+                .ok_or_else(overflow)?;
+            fees.push(tx_params.max_fee.map_or(fee, |max_fee| fee.min(max_fee)));
+        }
+
+        let paid_total = fees
+            .iter()
+            .try_fold(0u64, |total, fee| total.checked_add(*fee))
+            .ok_or_else(overflow)?;
+        let mut shortfall = required_total.saturating_sub(paid_total);
+
+        for (fee, tx_params) in fees.iter_mut().zip(&params.transactions) {
+            if shortfall == 0 {
+                break;
+            }
+            let headroom = tx_params.max_fee.map_or(u64::MAX, |max_fee| max_fee - *fee);
+            let contribution = headroom.min(shortfall);
+            *fee += contribution;
+            shortfall -= contribution;
+        }
+
+        if shortfall > 0 {
+            return Err(AlgoKitTransactError::InputError(format!(
+                "Transaction group fees are {} µALGO short of the required {} µALGO, given the max fees of the transactions",
+                shortfall, required_total
+            )));
+        }
+
+        Ok(self
+            .iter()
+            .zip(fees)
+            .map(|(tx, fee)| {
+                let mut tx = tx.clone();
+                tx.header_mut().fee = Some(fee);
+                tx
+            })
+            .collect())
+    }
 }
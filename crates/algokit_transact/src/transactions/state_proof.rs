@@ -0,0 +1,282 @@
+//! State proof transaction module for AlgoKit Core.
+//!
+//! This module provides decode support for state proof transactions, which are issued by the
+//! network to attest to the state of the chain. These transactions can't be submitted by users,
+//! so no builder is provided; they are modelled so they can be decoded, re-encoded and have their
+//! transaction ID computed.
+
+use crate::transactions::common::TransactionHeader;
+use crate::utils::{is_default, is_empty_bytes64, is_zero, is_zero_u16, is_zero_u8};
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, Bytes};
+use std::collections::BTreeMap;
+
+/// A Merkle proof of a set of elements in a Merkle array.
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+pub struct MerkleArrayProof {
+    /// The sibling hashes required to reconstruct the root.
+    #[serde(rename = "pth")]
+    #[serde_as(as = "Vec<Bytes>")]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
+    pub path: Vec<Vec<u8>>,
+
+    /// The hash function used to construct the tree.
+    #[serde(rename = "hsh")]
+    #[serde(skip_serializing_if = "is_default")]
+    #[serde(default)]
+    pub hash_factory: HashFactory,
+
+    /// The depth of the tree.
+    #[serde(rename = "td")]
+    #[serde(skip_serializing_if = "is_zero_u8")]
+    #[serde(default)]
+    pub tree_depth: u8,
+}
+
+/// Identifies the hash function used by a Merkle array.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+pub struct HashFactory {
+    /// The hash type identifier.
+    #[serde(rename = "t")]
+    #[serde(skip_serializing_if = "is_zero_u16")]
+    #[serde(default)]
+    pub hash_type: u16,
+}
+
+/// A Falcon signature over the state proof message, together with the proof that the
+/// signing key is part of the participant's key commitment.
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+pub struct MerkleSignature {
+    /// The Falcon signature.
+    #[serde(rename = "sig")]
+    #[serde_as(as = "Bytes")]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
+    pub signature: Vec<u8>,
+
+    /// The index of the signing key in the participant's key commitment.
+    #[serde(rename = "idx")]
+    #[serde(skip_serializing_if = "is_zero")]
+    #[serde(default)]
+    pub vector_commitment_index: u64,
+
+    /// The proof that the verifying key is part of the participant's key commitment.
+    #[serde(rename = "prf")]
+    #[serde(skip_serializing_if = "is_default")]
+    #[serde(default)]
+    pub proof: MerkleArrayProof,
+
+    /// The Falcon public key used to verify the signature.
+    #[serde(rename = "vkey")]
+    #[serde(skip_serializing_if = "is_default")]
+    #[serde(default)]
+    pub verifying_key: FalconVerifier,
+}
+
+/// A Falcon public key.
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+pub struct FalconVerifier {
+    /// The encoded Falcon public key.
+    #[serde(rename = "k")]
+    #[serde_as(as = "Bytes")]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
+    pub public_key: Vec<u8>,
+}
+
+/// A signature slot in the state proof, committing to a participant's signature.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+pub struct SigslotCommit {
+    /// The participant's signature over the state proof message.
+    #[serde(rename = "s")]
+    #[serde(skip_serializing_if = "is_default")]
+    #[serde(default)]
+    pub signature: MerkleSignature,
+
+    /// The total weight of the signatures in the slots before this one.
+    #[serde(rename = "l")]
+    #[serde(skip_serializing_if = "is_zero")]
+    #[serde(default)]
+    pub lower_sig_weight: u64,
+}
+
+/// The key commitment of a participant.
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct MerkleSignatureVerifier {
+    /// The 64-byte commitment to the participant's Falcon keys.
+    #[serde(rename = "cmt")]
+    #[serde_as(as = "Bytes")]
+    #[serde(skip_serializing_if = "is_empty_bytes64")]
+    #[serde(default = "MerkleSignatureVerifier::empty_commitment")]
+    pub commitment: [u8; 64],
+
+    /// The number of rounds each Falcon key is valid for.
+    #[serde(rename = "lf")]
+    #[serde(skip_serializing_if = "is_zero")]
+    #[serde(default)]
+    pub key_lifetime: u64,
+}
+
+impl MerkleSignatureVerifier {
+    fn empty_commitment() -> [u8; 64] {
+        [0; 64]
+    }
+}
+
+impl Default for MerkleSignatureVerifier {
+    fn default() -> Self {
+        Self {
+            commitment: Self::empty_commitment(),
+            key_lifetime: 0,
+        }
+    }
+}
+
+/// A participant in the state proof and their online weight.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+pub struct Participant {
+    /// The participant's key commitment.
+    #[serde(rename = "p")]
+    #[serde(skip_serializing_if = "is_default")]
+    #[serde(default)]
+    pub verifier: MerkleSignatureVerifier,
+
+    /// The participant's online weight.
+    #[serde(rename = "w")]
+    #[serde(skip_serializing_if = "is_zero")]
+    #[serde(default)]
+    pub weight: u64,
+}
+
+/// A revealed signature slot and participant.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+pub struct Reveal {
+    /// The revealed signature slot.
+    #[serde(rename = "s")]
+    #[serde(skip_serializing_if = "is_default")]
+    #[serde(default)]
+    pub sigslot: SigslotCommit,
+
+    /// The revealed participant.
+    #[serde(rename = "p")]
+    #[serde(skip_serializing_if = "is_default")]
+    #[serde(default)]
+    pub participant: Participant,
+}
+
+/// A compact certificate proving that a weighted set of participants signed a message.
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+pub struct StateProof {
+    /// The root of the Merkle tree of signature slots.
+    #[serde(rename = "c")]
+    #[serde_as(as = "Bytes")]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
+    pub sig_commit: Vec<u8>,
+
+    /// The total weight of the signatures in the proof.
+    #[serde(rename = "w")]
+    #[serde(skip_serializing_if = "is_zero")]
+    #[serde(default)]
+    pub signed_weight: u64,
+
+    /// The Merkle proof of the revealed signature slots.
+    #[serde(rename = "S")]
+    #[serde(skip_serializing_if = "is_default")]
+    #[serde(default)]
+    pub sig_proofs: MerkleArrayProof,
+
+    /// The Merkle proof of the revealed participants.
+    #[serde(rename = "P")]
+    #[serde(skip_serializing_if = "is_default")]
+    #[serde(default)]
+    pub part_proofs: MerkleArrayProof,
+
+    /// The salt version of the Merkle signature scheme.
+    #[serde(rename = "v")]
+    #[serde(skip_serializing_if = "is_zero_u8")]
+    #[serde(default)]
+    pub merkle_signature_salt_version: u8,
+
+    /// The revealed signature slots and participants, keyed by their position.
+    #[serde(rename = "r")]
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    #[serde(default)]
+    pub reveals: BTreeMap<u64, Reveal>,
+
+    /// The positions which were chosen to be revealed.
+    #[serde(rename = "pr")]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
+    pub positions_to_reveal: Vec<u64>,
+}
+
+/// The message attested to by a state proof.
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+pub struct StateProofMessage {
+    /// The commitment to the block headers in the attested interval.
+    #[serde(rename = "b")]
+    #[serde_as(as = "Bytes")]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
+    pub block_headers_commitment: Vec<u8>,
+
+    /// The commitment to the voters for the next state proof.
+    #[serde(rename = "v")]
+    #[serde_as(as = "Bytes")]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
+    pub voters_commitment: Vec<u8>,
+
+    /// The natural log of the proven weight, scaled by 2^16.
+    #[serde(rename = "P")]
+    #[serde(skip_serializing_if = "is_zero")]
+    #[serde(default)]
+    pub ln_proven_weight: u64,
+
+    /// The first round attested to by the state proof.
+    #[serde(rename = "f")]
+    #[serde(skip_serializing_if = "is_zero")]
+    #[serde(default)]
+    pub first_attested_round: u64,
+
+    /// The last round attested to by the state proof.
+    #[serde(rename = "l")]
+    #[serde(skip_serializing_if = "is_zero")]
+    #[serde(default)]
+    pub last_attested_round: u64,
+}
+
+/// Represents a state proof transaction.
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct StateProofTransactionFields {
+    /// Common transaction header fields.
+    #[serde(flatten)]
+    pub header: TransactionHeader,
+
+    /// The type of the state proof.
+    #[serde(rename = "sptype")]
+    #[serde(skip_serializing_if = "is_zero")]
+    #[serde(default)]
+    pub state_proof_type: u64,
+
+    /// The state proof.
+    #[serde(rename = "sp")]
+    #[serde(skip_serializing_if = "is_default")]
+    #[serde(default)]
+    pub state_proof: StateProof,
+
+    /// The message attested to by the state proof.
+    #[serde(rename = "spmsg")]
+    #[serde(skip_serializing_if = "is_default")]
+    #[serde(default)]
+    pub message: StateProofMessage,
+}
@@ -0,0 +1,329 @@
+//! Validation of transactions against the consensus rules of the network.
+
+use super::{
+    ApplicationCallTransactionFields, AssetConfigTransactionFields, AssetTransferTransactionFields,
+    PaymentTransactionFields, Transaction, TransactionHeader,
+};
+use crate::constants::{
+    MAX_ASSET_DECIMALS, MAX_ASSET_NAME_LENGTH, MAX_ASSET_UNIT_NAME_LENGTH, MAX_ASSET_URL_LENGTH,
+};
+use crate::error::AlgoKitTransactError;
+use crate::utils::is_zero_addr;
+
+/// The consensus parameters which limit the contents of a transaction.
+///
+/// The default values are those of the current mainnet consensus protocol.
+#[derive(Debug, PartialEq, Clone)]
+pub struct ConsensusParams {
+    /// The maximum number of rounds between the first and last valid rounds of a transaction.
+    pub max_txn_life: u64,
+
+    /// The maximum length of a transaction note, in bytes.
+    pub max_txn_note_bytes: usize,
+
+    /// The maximum number of decimals of an asset.
+    pub max_asset_decimals: u32,
+
+    /// The maximum length of the unit name of an asset, in bytes.
+    pub max_asset_unit_name_bytes: usize,
+
+    /// The maximum length of the name of an asset, in bytes.
+    pub max_asset_name_bytes: usize,
+
+    /// The maximum length of the URL of an asset, in bytes.
+    pub max_asset_url_bytes: usize,
+
+    /// The maximum number of application args of an application call.
+    pub max_app_args: usize,
+
+    /// The maximum combined length of the application args of an application call, in bytes.
+    pub max_app_total_arg_len: usize,
+
+    /// The maximum number of account references of an application call.
+    pub max_app_txn_accounts: usize,
+
+    /// The maximum number of application references of an application call.
+    pub max_app_txn_foreign_apps: usize,
+
+    /// The maximum number of asset references of an application call.
+    pub max_app_txn_foreign_assets: usize,
+
+    /// The maximum number of box references of an application call.
+    pub max_app_box_references: usize,
+
+    /// The maximum combined number of account, application, asset and box references of an
+    /// application call.
+    pub max_app_total_txn_references: usize,
+
+    /// The maximum combined length of the approval and clear state programs of an application,
+    /// in bytes, excluding extra program pages.
+    pub max_app_program_len: usize,
+
+    /// The maximum number of extra program pages of an application.
+    pub max_extra_app_program_pages: u64,
+
+    /// The maximum number of values in the global state schema of an application.
+    pub max_global_schema_entries: u64,
+
+    /// The maximum number of values in the local state schema of an application.
+    pub max_local_schema_entries: u64,
+}
+
+impl ConsensusParams {
+    /// Returns the consensus parameters of the current mainnet consensus protocol.
+    pub fn mainnet() -> Self {
+        ConsensusParams {
+            max_txn_life: 1000,
+            max_txn_note_bytes: 1024,
+            max_asset_decimals: MAX_ASSET_DECIMALS,
+            max_asset_unit_name_bytes: MAX_ASSET_UNIT_NAME_LENGTH,
+            max_asset_name_bytes: MAX_ASSET_NAME_LENGTH,
+            max_asset_url_bytes: MAX_ASSET_URL_LENGTH,
+            max_app_args: 16,
+            max_app_total_arg_len: 2048,
+            max_app_txn_accounts: 4,
+            max_app_txn_foreign_apps: 8,
+            max_app_txn_foreign_assets: 8,
+            max_app_box_references: 8,
+            max_app_total_txn_references: 8,
+            max_app_program_len: 2048,
+            max_extra_app_program_pages: 3,
+            max_global_schema_entries: 64,
+            max_local_schema_entries: 16,
+        }
+    }
+}
+
+impl Default for ConsensusParams {
+    fn default() -> Self {
+        Self::mainnet()
+    }
+}
+
+impl Transaction {
+    /// Checks that the transaction is well-formed under the supplied consensus parameters.
+    ///
+    /// This checks the limits on the note, the validity window, the asset params and the
+    /// application call fields, along with fields which are valid to encode but rejected by the
+    /// network, such as a zero sender or a payment which closes to its own receiver. The lease and
+    /// group are always 32 bytes, as enforced by their type.
+    ///
+    /// # Returns
+    /// `Ok(())` if the transaction is valid, or an `InvalidTransaction` error with every violation
+    /// found.
+    pub fn validate(&self, params: &ConsensusParams) -> Result<(), AlgoKitTransactError> {
+        let mut violations = validate_header(self.header(), params);
+        match self {
+            Transaction::Payment(payment) => validate_payment(payment, &mut violations),
+            Transaction::AssetTransfer(asset_transfer) => {
+                validate_asset_transfer(asset_transfer, &mut violations)
+            }
+            Transaction::AssetConfig(asset_config) => {
+                validate_asset_config(asset_config, params, &mut violations)
+            }
+            Transaction::ApplicationCall(app_call) => {
+                validate_application_call(app_call, params, &mut violations)
+            }
+            Transaction::AssetFreeze(_)
+            | Transaction::KeyRegistration(_)
+            | Transaction::StateProof(_)
+            | Transaction::Heartbeat(_) => {}
+        }
+
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(AlgoKitTransactError::InvalidTransaction(violations))
+        }
+    }
+}
+
+fn validate_header(header: &TransactionHeader, params: &ConsensusParams) -> Vec<String> {
+    let mut violations = Vec::new();
+
+    if is_zero_addr(&header.sender) {
+        violations.push(String::from("sender must not be the zero address"));
+    }
+
+    if header.last_valid < header.first_valid {
+        violations.push(format!(
+            "last valid round {} is before first valid round {}",
+            header.last_valid, header.first_valid
+        ));
+    } else if header.last_valid - header.first_valid > params.max_txn_life {
+        violations.push(format!(
+            "validity window of {} rounds exceeds the max of {}",
+            header.last_valid - header.first_valid,
+            params.max_txn_life
+        ));
+    }
+
+    if let Some(note) = &header.note {
+        if note.len() > params.max_txn_note_bytes {
+            violations.push(format!(
+                "note is {} bytes but must not exceed {}",
+                note.len(),
+                params.max_txn_note_bytes
+            ));
+        }
+    }
+
+    violations
+}
+
+fn validate_payment(payment: &PaymentTransactionFields, violations: &mut Vec<String>) {
+    if payment.close_remainder_to.as_ref() == Some(&payment.receiver) {
+        violations.push(String::from("close remainder to must not be the receiver"));
+    }
+}
+
+fn validate_asset_transfer(
+    asset_transfer: &AssetTransferTransactionFields,
+    violations: &mut Vec<String>,
+) {
+    if asset_transfer.close_remainder_to.as_ref() == Some(&asset_transfer.receiver) {
+        violations.push(String::from("close remainder to must not be the receiver"));
+    }
+}
+
+fn validate_asset_config(
+    asset_config: &AssetConfigTransactionFields,
+    params: &ConsensusParams,
+    violations: &mut Vec<String>,
+) {
+    let Some(asset_params) = &asset_config.params else {
+        return;
+    };
+
+    if asset_params.decimals.unwrap_or_default() > params.max_asset_decimals {
+        violations.push(format!(
+            "asset decimals must not exceed {}",
+            params.max_asset_decimals
+        ));
+    }
+
+    for (name, value, max_length) in [
+        (
+            "unit name",
+            &asset_params.unit_name,
+            params.max_asset_unit_name_bytes,
+        ),
+        (
+            "asset name",
+            &asset_params.asset_name,
+            params.max_asset_name_bytes,
+        ),
+        ("url", &asset_params.url, params.max_asset_url_bytes),
+    ] {
+        if let Some(value) = value.as_ref().filter(|value| value.len() > max_length) {
+            violations.push(format!(
+                "asset {} is {} bytes but must not exceed {}",
+                name,
+                value.len(),
+                max_length
+            ));
+        }
+    }
+}
+
+fn validate_application_call(
+    app_call: &ApplicationCallTransactionFields,
+    params: &ConsensusParams,
+    violations: &mut Vec<String>,
+) {
+    let args = app_call.args.as_deref().unwrap_or_default();
+    if args.len() > params.max_app_args {
+        violations.push(format!(
+            "{} application args exceeds the max of {}",
+            args.len(),
+            params.max_app_args
+        ));
+    }
+    let args_len: usize = args.iter().map(Vec::len).sum();
+    if args_len > params.max_app_total_arg_len {
+        violations.push(format!(
+            "application args are {} bytes but must not exceed {}",
+            args_len, params.max_app_total_arg_len
+        ));
+    }
+
+    let references = [
+        (
+            "account references",
+            app_call.account_references.as_ref().map_or(0, Vec::len),
+            params.max_app_txn_accounts,
+        ),
+        (
+            "application references",
+            app_call.app_references.as_ref().map_or(0, Vec::len),
+            params.max_app_txn_foreign_apps,
+        ),
+        (
+            "asset references",
+            app_call.asset_references.as_ref().map_or(0, Vec::len),
+            params.max_app_txn_foreign_assets,
+        ),
+        (
+            "box references",
+            app_call.box_references.as_ref().map_or(0, Vec::len),
+            params.max_app_box_references,
+        ),
+    ];
+    for (name, count, max_count) in references {
+        if count > max_count {
+            violations.push(format!(
+                "{} {} exceeds the max of {}",
+                count, name, max_count
+            ));
+        }
+    }
+    let total_references: usize = references.iter().map(|(_, count, _)| count).sum();
+    if total_references > params.max_app_total_txn_references {
+        violations.push(format!(
+            "{} total references exceeds the max of {}",
+            total_references, params.max_app_total_txn_references
+        ));
+    }
+
+    let extra_pages = app_call.extra_program_pages.unwrap_or_default();
+    if extra_pages > params.max_extra_app_program_pages {
+        violations.push(format!(
+            "{} extra program pages exceeds the max of {}",
+            extra_pages, params.max_extra_app_program_pages
+        ));
+    }
+    let program_len = app_call.approval_program.as_ref().map_or(0, Vec::len)
+        + app_call.clear_state_program.as_ref().map_or(0, Vec::len);
+    let max_program_len = params
+        .max_app_program_len
+        .saturating_mul(1 + extra_pages.min(params.max_extra_app_program_pages) as usize);
+    if program_len > max_program_len {
+        violations.push(format!(
+            "programs are {} bytes but must not exceed {}",
+            program_len, max_program_len
+        ));
+    }
+
+    for (name, schema, max_entries) in [
+        (
+            "global",
+            &app_call.global_state_schema,
+            params.max_global_schema_entries,
+        ),
+        (
+            "local",
+            &app_call.local_state_schema,
+            params.max_local_schema_entries,
+        ),
+    ] {
+        let entries = schema.as_ref().map_or(0, |schema| {
+            schema.num_uints.saturating_add(schema.num_byte_slices)
+        });
+        if entries > max_entries {
+            violations.push(format!(
+                "{} state schema has {} entries but must not exceed {}",
+                name, entries, max_entries
+            ));
+        }
+    }
+}
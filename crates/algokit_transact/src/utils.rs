@@ -1,33 +1,96 @@
 use crate::address::Address;
 use crate::constants::{
-    Byte32, ALGORAND_CHECKSUM_BYTE_LENGTH, ALGORAND_PUBLIC_KEY_BYTE_LENGTH, HASH_BYTES_LENGTH,
+    Byte32, ALGORAND_CHECKSUM_BYTE_LENGTH, ALGORAND_PUBLIC_KEY_BYTE_LENGTH,
+    ALGORAND_STATE_PROOF_KEY_BYTE_LENGTH, HASH_BYTES_LENGTH,
 };
 use crate::{AlgoKitTransactError, AlgorandMsgpack, Transaction, TransactionId};
-use serde::{Deserialize, Serialize};
-use serde_with::{serde_as, skip_serializing_none, Bytes};
+use rmp::encode::{self as rmp_encode, ValueWriteError};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_with::{serde_as, skip_serializing_none, Bytes, DeserializeAs, SerializeAs};
 use sha2::{Digest, Sha512_256};
-use std::collections::BTreeMap;
 
-pub fn sort_msgpack_value(value: rmpv::Value) -> rmpv::Value {
-    match value {
-        rmpv::Value::Map(m) => {
-            let mut sorted_map: BTreeMap<String, rmpv::Value> = BTreeMap::new();
+/// The name of the newtype struct which [`ByteString`] serializes bytes which aren't valid UTF-8
+/// as, since serde can only serialize strings of UTF-8. The serializer used by
+/// [`AlgorandMsgpack::encode_raw`] writes it as a string of the same bytes, while other
+/// serializers see the bytes it wraps.
+pub(crate) const BYTE_STRING_STRUCT_NAME: &str = "_AlgoKitByteString";
+
+/// Serializes bytes as a msgpack string, for fields which are strings in the protocol but may hold
+/// arbitrary bytes, such as asset names.
+///
+/// Deserializes from either a msgpack string (which needn't be valid UTF-8) or binary value, or
+/// the array of bytes other serializers such as `serde_json` write bytes which aren't UTF-8 as.
+pub struct ByteString;
+
+impl SerializeAs<Vec<u8>> for ByteString {
+    fn serialize_as<S: Serializer>(bytes: &Vec<u8>, serializer: S) -> Result<S::Ok, S::Error> {
+        match std::str::from_utf8(bytes) {
+            Ok(string) => serializer.serialize_str(string),
+            Err(_) => serializer
+                .serialize_newtype_struct(BYTE_STRING_STRUCT_NAME, serde_bytes::Bytes::new(bytes)),
+        }
+    }
+}
+
+impl<'de> DeserializeAs<'de, Vec<u8>> for ByteString {
+    fn deserialize_as<D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+        struct ByteStringVisitor;
 
-            // Convert and sort all key-value pairs
-            for (k, v) in m {
-                if let rmpv::Value::String(key) = k {
-                    let key_str = key.into_str().unwrap_or_default();
-                    sorted_map.insert(key_str, sort_msgpack_value(v));
+        impl<'de> serde::de::Visitor<'de> for ByteStringVisitor {
+            type Value = Vec<u8>;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("a string or bytes")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Vec<u8>, E> {
+                Ok(v.as_bytes().to_vec())
+            }
+
+            fn visit_bytes<E: serde::de::Error>(self, v: &[u8]) -> Result<Vec<u8>, E> {
+                Ok(v.to_vec())
+            }
+
+            fn visit_seq<A: serde::de::SeqAccess<'de>>(
+                self,
+                mut seq: A,
+            ) -> Result<Vec<u8>, A::Error> {
+                let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or_default());
+                while let Some(byte) = seq.next_element()? {
+                    bytes.push(byte);
                 }
+                Ok(bytes)
             }
+        }
+
+        deserializer.deserialize_any(ByteStringVisitor)
+    }
+}
 
-            // Convert back to rmpv::Value::Map
-            rmpv::Value::Map(
-                sorted_map
-                    .into_iter()
-                    .map(|(k, v)| (rmpv::Value::String(k.into()), v))
-                    .collect(),
-            )
+/// A msgpack string of the bytes, which needn't be valid UTF-8.
+pub(crate) fn byte_string_value(bytes: &[u8]) -> rmpv::Value {
+    let mut buf = Vec::with_capacity(bytes.len() + 5);
+    rmp_encode::write_str_len(&mut buf, bytes.len() as u32).expect("writing to a Vec can't fail");
+    buf.extend_from_slice(bytes);
+    rmpv::decode::read_value(&mut &buf[..]).expect("the string was just encoded")
+}
+
+pub fn sort_msgpack_value(value: rmpv::Value) -> rmpv::Value {
+    match value {
+        rmpv::Value::Map(m) => {
+            // String keys are sorted lexicographically and integer keys numerically, which
+            // matches the canonical encoding used by algod
+            let mut sorted_map: Vec<(rmpv::Value, rmpv::Value)> = m
+                .into_iter()
+                .filter(|(k, _)| k.is_str() || k.is_u64())
+                .map(|(k, v)| (k, sort_msgpack_value(v)))
+                .collect();
+            sorted_map.sort_by(|(a, _), (b, _)| match (a.as_u64(), b.as_u64()) {
+                (Some(a), Some(b)) => a.cmp(&b),
+                _ => a.as_str().cmp(&b.as_str()),
+            });
+
+            rmpv::Value::Map(sorted_map)
         }
         rmpv::Value::Array(arr) => {
             rmpv::Value::Array(arr.into_iter().map(sort_msgpack_value).collect())
@@ -37,12 +100,159 @@ pub fn sort_msgpack_value(value: rmpv::Value) -> rmpv::Value {
     }
 }
 
+/// Writes a msgpack value, keeping strings which aren't valid UTF-8 as strings, which
+/// `rmpv::encode::write_value` writes as binary.
+pub fn write_msgpack_value(buf: &mut Vec<u8>, value: &rmpv::Value) -> Result<(), ValueWriteError> {
+    use rmpv::Value as V;
+    match value {
+        V::Nil => rmp_encode::write_nil(buf).map_err(ValueWriteError::InvalidMarkerWrite)?,
+        V::Boolean(b) => {
+            rmp_encode::write_bool(buf, *b).map_err(ValueWriteError::InvalidMarkerWrite)?
+        }
+        V::Integer(i) => match i.as_u64() {
+            Some(n) => rmp_encode::write_uint(buf, n).map(|_| ())?,
+            None => rmp_encode::write_sint(buf, i.as_i64().expect("integer is either u64 or i64"))
+                .map(|_| ())?,
+        },
+        V::F32(f) => rmp_encode::write_f32(buf, *f)?,
+        V::F64(f) => rmp_encode::write_f64(buf, *f)?,
+        V::String(s) => {
+            rmp_encode::write_str_len(buf, s.as_bytes().len() as u32)?;
+            buf.extend_from_slice(s.as_bytes());
+        }
+        V::Binary(bytes) => rmp_encode::write_bin(buf, bytes)?,
+        V::Array(items) => {
+            rmp_encode::write_array_len(buf, items.len() as u32)?;
+            for item in items {
+                write_msgpack_value(buf, item)?;
+            }
+        }
+        V::Map(entries) => {
+            rmp_encode::write_map_len(buf, entries.len() as u32)?;
+            for (key, value) in entries {
+                write_msgpack_value(buf, key)?;
+                write_msgpack_value(buf, value)?;
+            }
+        }
+        V::Ext(ext_type, data) => {
+            rmp_encode::write_ext_meta(buf, data.len() as u32, *ext_type)?;
+            buf.extend_from_slice(data);
+        }
+    }
+    Ok(())
+}
+
+/// Describes the first difference between the canonical encoding of a value and another
+/// encoding of the same value.
+pub fn describe_encoding_difference(canonical: &[u8], actual: &[u8]) -> String {
+    if let (Ok(canonical_value), Ok(actual_value)) = (
+        rmpv::decode::read_value(&mut &canonical[..]),
+        rmpv::decode::read_value(&mut &actual[..]),
+    ) {
+        if let Some(difference) = describe_value_difference(&canonical_value, &actual_value, "") {
+            return difference;
+        }
+    }
+
+    let offset = canonical
+        .iter()
+        .zip(actual)
+        .position(|(c, a)| c != a)
+        .unwrap_or(canonical.len().min(actual.len()));
+    if offset == canonical.len() {
+        format!("unexpected trailing bytes at byte {}", offset)
+    } else {
+        format!("non-minimal encoding at byte {}", offset)
+    }
+}
+
+fn describe_value_difference(
+    canonical: &rmpv::Value,
+    actual: &rmpv::Value,
+    path: &str,
+) -> Option<String> {
+    let child_path = |child: String| {
+        if path.is_empty() {
+            child
+        } else {
+            format!("{}.{}", path, child)
+        }
+    };
+    let key_name = |key: &rmpv::Value| key.as_str().map_or(key.to_string(), str::to_string);
+
+    match (canonical, actual) {
+        (rmpv::Value::Map(canonical), rmpv::Value::Map(actual)) => {
+            for (i, (key, value)) in actual.iter().enumerate() {
+                let field = child_path(key_name(key));
+                match canonical.iter().position(|(k, _)| k == key) {
+                    None => {
+                        return Some(format!(
+                            "field {} is empty or unknown and must be omitted",
+                            field
+                        ))
+                    }
+                    Some(j) if j != i => return Some(format!("field {} is out of order", field)),
+                    Some(j) => {
+                        if let Some(difference) =
+                            describe_value_difference(&canonical[j].1, value, &field)
+                        {
+                            return Some(difference);
+                        }
+                    }
+                }
+            }
+            canonical
+                .get(actual.len())
+                .map(|(key, _)| format!("field {} is missing", child_path(key_name(key))))
+        }
+        (rmpv::Value::Array(canonical), rmpv::Value::Array(actual)) => {
+            if canonical.len() != actual.len() {
+                return Some(format!(
+                    "{} has {} elements but the canonical encoding has {}",
+                    path,
+                    actual.len(),
+                    canonical.len()
+                ));
+            }
+            canonical
+                .iter()
+                .zip(actual)
+                .enumerate()
+                .find_map(|(i, (c, a))| {
+                    describe_value_difference(c, a, &format!("{}[{}]", path, i))
+                })
+        }
+        _ if canonical == actual => None,
+        _ => Some(format!("value of {} is not canonical", path)),
+    }
+}
+
 pub fn is_zero(n: &u64) -> bool {
     *n == 0u64
 }
 
 pub fn is_zero_opt(n: &Option<u64>) -> bool {
-    n.as_ref().map_or(true, is_zero)
+    n.as_ref().is_none_or(is_zero)
+}
+
+pub fn is_zero_u8(n: &u8) -> bool {
+    *n == 0u8
+}
+
+pub fn is_zero_u16(n: &u16) -> bool {
+    *n == 0u16
+}
+
+pub fn is_zero_u32_opt(n: &Option<u32>) -> bool {
+    n.is_none_or(|n| n == 0)
+}
+
+pub fn is_false(b: &bool) -> bool {
+    !*b
+}
+
+pub fn is_false_opt(b: &Option<bool>) -> bool {
+    b.is_none_or(|b| !b)
 }
 
 pub fn is_zero_addr(addr: &Address) -> bool {
@@ -50,7 +260,7 @@ pub fn is_zero_addr(addr: &Address) -> bool {
 }
 
 pub fn is_zero_addr_opt(addr: &Option<Address>) -> bool {
-    addr.as_ref().map_or(true, is_zero_addr)
+    addr.as_ref().is_none_or(is_zero_addr)
 }
 
 pub fn is_empty_bytes32(bytes: &Byte32) -> bool {
@@ -58,20 +268,32 @@ pub fn is_empty_bytes32(bytes: &Byte32) -> bool {
 }
 
 pub fn is_empty_bytes32_opt(bytes: &Option<Byte32>) -> bool {
-    bytes.as_ref().map_or(true, is_empty_bytes32)
+    bytes.as_ref().is_none_or(is_empty_bytes32)
+}
+
+pub fn is_empty_bytes64(bytes: &[u8; 64]) -> bool {
+    bytes == &[0u8; 64]
+}
+
+pub fn is_empty_bytes64_opt(bytes: &Option<[u8; ALGORAND_STATE_PROOF_KEY_BYTE_LENGTH]>) -> bool {
+    bytes.as_ref().is_none_or(is_empty_bytes64)
+}
+
+pub fn is_default<T: Default + PartialEq>(value: &T) -> bool {
+    *value == T::default()
 }
 
 pub fn is_empty_string_opt(string: &Option<String>) -> bool {
-    string.as_ref().map_or(true, String::is_empty)
+    string.as_ref().is_none_or(String::is_empty)
 }
 
 pub fn is_empty_vec_opt<T>(vec: &Option<Vec<T>>) -> bool {
-    vec.as_ref().map_or(true, Vec::is_empty)
+    vec.as_ref().is_none_or(Vec::is_empty)
 }
 
 pub fn pub_key_to_checksum(pub_key: &Byte32) -> [u8; ALGORAND_CHECKSUM_BYTE_LENGTH] {
     let mut hasher = Sha512_256::new();
-    hasher.update(&pub_key);
+    hasher.update(pub_key);
 
     let mut checksum = [0u8; ALGORAND_CHECKSUM_BYTE_LENGTH];
     checksum
@@ -81,7 +303,7 @@ pub fn pub_key_to_checksum(pub_key: &Byte32) -> [u8; ALGORAND_CHECKSUM_BYTE_LENG
 
 pub fn hash(bytes: &Vec<u8>) -> Byte32 {
     let mut hasher = Sha512_256::new();
-    hasher.update(&bytes);
+    hasher.update(bytes);
 
     let mut group = [0u8; HASH_BYTES_LENGTH];
     group.copy_from_slice(&hasher.finalize()[..HASH_BYTES_LENGTH]);
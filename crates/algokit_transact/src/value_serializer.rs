@@ -0,0 +1,525 @@
+//! A serde serializer to msgpack values, used by [`AlgorandMsgpack::encode_raw`](crate::AlgorandMsgpack::encode_raw).
+//!
+//! It serializes as `rmp_serde` does with named struct fields and [`BytesMode::ForceAll`], except
+//! that the bytes of a [`ByteString`](crate::utils::ByteString) which aren't valid UTF-8 are still
+//! a msgpack string, which other serializers see as plain bytes.
+//!
+//! [`BytesMode::ForceAll`]: rmp_serde::config::BytesMode::ForceAll
+
+use crate::utils::{byte_string_value, BYTE_STRING_STRUCT_NAME};
+use rmp_serde::encode::Error;
+use rmpv::Value;
+use serde::ser::{self, Serialize};
+
+/// Serializes a value to a msgpack value.
+pub(crate) fn to_value<T: Serialize + ?Sized>(value: &T) -> Result<Value, Error> {
+    value.serialize(ValueSerializer)
+}
+
+struct ValueSerializer;
+
+impl ser::Serializer for ValueSerializer {
+    type Ok = Value;
+    type Error = Error;
+
+    type SerializeSeq = SerializeVec;
+    type SerializeTuple = SerializeVec;
+    type SerializeTupleStruct = SerializeVec;
+    type SerializeTupleVariant = SerializeVariant<SerializeVec>;
+    type SerializeMap = SerializeMap;
+    type SerializeStruct = SerializeMap;
+    type SerializeStructVariant = SerializeVariant<SerializeMap>;
+
+    fn serialize_bool(self, v: bool) -> Result<Value, Error> {
+        Ok(Value::Boolean(v))
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<Value, Error> {
+        Ok(Value::from(v))
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<Value, Error> {
+        Ok(Value::from(v))
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<Value, Error> {
+        Ok(Value::from(v))
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<Value, Error> {
+        Ok(Value::from(v))
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<Value, Error> {
+        Ok(Value::from(v))
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<Value, Error> {
+        Ok(Value::from(v))
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<Value, Error> {
+        Ok(Value::from(v))
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<Value, Error> {
+        Ok(Value::from(v))
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<Value, Error> {
+        Ok(Value::F32(v))
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<Value, Error> {
+        Ok(Value::F64(v))
+    }
+
+    fn serialize_char(self, v: char) -> Result<Value, Error> {
+        Ok(Value::from(v.to_string()))
+    }
+
+    fn serialize_str(self, v: &str) -> Result<Value, Error> {
+        Ok(Value::from(v))
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<Value, Error> {
+        Ok(Value::Binary(v.to_vec()))
+    }
+
+    fn serialize_none(self) -> Result<Value, Error> {
+        Ok(Value::Nil)
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<Value, Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Value, Error> {
+        Ok(Value::Nil)
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Value, Error> {
+        Ok(Value::Array(Vec::new()))
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+    ) -> Result<Value, Error> {
+        Ok(Value::from(variant))
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        name: &'static str,
+        value: &T,
+    ) -> Result<Value, Error> {
+        let inner = value.serialize(self)?;
+        match (name, inner) {
+            (BYTE_STRING_STRUCT_NAME, Value::Binary(bytes)) => Ok(byte_string_value(&bytes)),
+            // e.g. the unknown fields of a transaction which are msgpack extensions
+            (rmp_serde::MSGPACK_EXT_STRUCT_NAME, Value::Array(fields)) => match &fields[..] {
+                [Value::Integer(ext_type), Value::Binary(data)] => {
+                    let ext_type = ext_type
+                        .as_i64()
+                        .and_then(|ext_type| i8::try_from(ext_type).ok())
+                        .ok_or(Error::InvalidDataModel("invalid extension type"))?;
+                    Ok(Value::Ext(ext_type, data.clone()))
+                }
+                _ => Err(Error::InvalidDataModel("invalid extension")),
+            },
+            (_, inner) => Ok(inner),
+        }
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<Value, Error> {
+        Ok(Value::Map(vec![(
+            Value::from(variant),
+            value.serialize(self)?,
+        )]))
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<SerializeVec, Error> {
+        Ok(SerializeVec::with_capacity(len.unwrap_or_default()))
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<SerializeVec, Error> {
+        Ok(SerializeVec::with_capacity(len))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<SerializeVec, Error> {
+        Ok(SerializeVec::with_capacity(len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<SerializeVariant<SerializeVec>, Error> {
+        Ok(SerializeVariant {
+            variant,
+            inner: SerializeVec::with_capacity(len),
+        })
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> Result<SerializeMap, Error> {
+        Ok(SerializeMap {
+            entries: Vec::with_capacity(len.unwrap_or_default()),
+            key: None,
+        })
+    }
+
+    fn serialize_struct(self, _name: &'static str, len: usize) -> Result<SerializeMap, Error> {
+        self.serialize_map(Some(len))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<SerializeVariant<SerializeMap>, Error> {
+        Ok(SerializeVariant {
+            variant,
+            inner: self.serialize_map(Some(len))?,
+        })
+    }
+}
+
+/// The items of a sequence or tuple, which is binary if they're all bytes.
+struct SerializeVec {
+    items: Vec<Value>,
+    bytes: bool,
+}
+
+impl SerializeVec {
+    fn with_capacity(len: usize) -> Self {
+        Self {
+            items: Vec::with_capacity(len),
+            bytes: true,
+        }
+    }
+
+    fn push<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        self.bytes &= value.serialize(OnlyByte).is_ok();
+        self.items.push(value.serialize(ValueSerializer)?);
+        Ok(())
+    }
+
+    fn into_value(self) -> Value {
+        if self.bytes && !self.items.is_empty() {
+            let bytes = self.items.iter().filter_map(Value::as_u64);
+            Value::Binary(bytes.map(|byte| byte as u8).collect())
+        } else {
+            Value::Array(self.items)
+        }
+    }
+}
+
+impl ser::SerializeSeq for SerializeVec {
+    type Ok = Value;
+    type Error = Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Value, Error> {
+        Ok(self.into_value())
+    }
+}
+
+impl ser::SerializeTuple for SerializeVec {
+    type Ok = Value;
+    type Error = Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Value, Error> {
+        Ok(self.into_value())
+    }
+}
+
+// Only sequences and tuples of bytes are binary with `BytesMode::ForceAll`, not tuple structs
+impl ser::SerializeTupleStruct for SerializeVec {
+    type Ok = Value;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        self.items.push(value.serialize(ValueSerializer)?);
+        Ok(())
+    }
+
+    fn end(self) -> Result<Value, Error> {
+        Ok(Value::Array(self.items))
+    }
+}
+
+struct SerializeMap {
+    entries: Vec<(Value, Value)>,
+    key: Option<Value>,
+}
+
+impl ser::SerializeMap for SerializeMap {
+    type Ok = Value;
+    type Error = Error;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), Error> {
+        self.key = Some(key.serialize(ValueSerializer)?);
+        Ok(())
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        let key = self
+            .key
+            .take()
+            .ok_or(Error::InvalidDataModel("map value without a key"))?;
+        self.entries.push((key, value.serialize(ValueSerializer)?));
+        Ok(())
+    }
+
+    fn end(self) -> Result<Value, Error> {
+        Ok(Value::Map(self.entries))
+    }
+}
+
+impl ser::SerializeStruct for SerializeMap {
+    type Ok = Value;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        self.entries
+            .push((Value::from(key), value.serialize(ValueSerializer)?));
+        Ok(())
+    }
+
+    fn end(self) -> Result<Value, Error> {
+        Ok(Value::Map(self.entries))
+    }
+}
+
+/// A tuple or struct variant, which is a map from the variant name to its fields.
+struct SerializeVariant<T> {
+    variant: &'static str,
+    inner: T,
+}
+
+impl ser::SerializeTupleVariant for SerializeVariant<SerializeVec> {
+    type Ok = Value;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        ser::SerializeTupleStruct::serialize_field(&mut self.inner, value)
+    }
+
+    fn end(self) -> Result<Value, Error> {
+        let fields = ser::SerializeTupleStruct::end(self.inner)?;
+        Ok(Value::Map(vec![(Value::from(self.variant), fields)]))
+    }
+}
+
+impl ser::SerializeStructVariant for SerializeVariant<SerializeMap> {
+    type Ok = Value;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        ser::SerializeStruct::serialize_field(&mut self.inner, key, value)
+    }
+
+    fn end(self) -> Result<Value, Error> {
+        let fields = ser::SerializeStruct::end(self.inner)?;
+        Ok(Value::Map(vec![(Value::from(self.variant), fields)]))
+    }
+}
+
+/// Serializes only a `u8`, to tell whether a sequence is of bytes.
+struct OnlyByte;
+
+impl ser::Serializer for OnlyByte {
+    type Ok = ();
+    type Error = Error;
+
+    type SerializeSeq = ser::Impossible<(), Error>;
+    type SerializeTuple = ser::Impossible<(), Error>;
+    type SerializeTupleStruct = ser::Impossible<(), Error>;
+    type SerializeTupleVariant = ser::Impossible<(), Error>;
+    type SerializeMap = ser::Impossible<(), Error>;
+    type SerializeStruct = ser::Impossible<(), Error>;
+    type SerializeStructVariant = ser::Impossible<(), Error>;
+
+    fn serialize_u8(self, _v: u8) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn serialize_bool(self, _v: bool) -> Result<(), Error> {
+        Err(not_a_byte())
+    }
+
+    fn serialize_i8(self, _v: i8) -> Result<(), Error> {
+        Err(not_a_byte())
+    }
+
+    fn serialize_i16(self, _v: i16) -> Result<(), Error> {
+        Err(not_a_byte())
+    }
+
+    fn serialize_i32(self, _v: i32) -> Result<(), Error> {
+        Err(not_a_byte())
+    }
+
+    fn serialize_i64(self, _v: i64) -> Result<(), Error> {
+        Err(not_a_byte())
+    }
+
+    fn serialize_u16(self, _v: u16) -> Result<(), Error> {
+        Err(not_a_byte())
+    }
+
+    fn serialize_u32(self, _v: u32) -> Result<(), Error> {
+        Err(not_a_byte())
+    }
+
+    fn serialize_u64(self, _v: u64) -> Result<(), Error> {
+        Err(not_a_byte())
+    }
+
+    fn serialize_f32(self, _v: f32) -> Result<(), Error> {
+        Err(not_a_byte())
+    }
+
+    fn serialize_f64(self, _v: f64) -> Result<(), Error> {
+        Err(not_a_byte())
+    }
+
+    fn serialize_char(self, _v: char) -> Result<(), Error> {
+        Err(not_a_byte())
+    }
+
+    fn serialize_str(self, _v: &str) -> Result<(), Error> {
+        Err(not_a_byte())
+    }
+
+    fn serialize_bytes(self, _v: &[u8]) -> Result<(), Error> {
+        Err(not_a_byte())
+    }
+
+    fn serialize_none(self) -> Result<(), Error> {
+        Err(not_a_byte())
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, _value: &T) -> Result<(), Error> {
+        Err(not_a_byte())
+    }
+
+    fn serialize_unit(self) -> Result<(), Error> {
+        Err(not_a_byte())
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<(), Error> {
+        Err(not_a_byte())
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        _variant: &'static str,
+    ) -> Result<(), Error> {
+        Err(not_a_byte())
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _value: &T,
+    ) -> Result<(), Error> {
+        Err(not_a_byte())
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _index: u32,
+        _variant: &'static str,
+        _value: &T,
+    ) -> Result<(), Error> {
+        Err(not_a_byte())
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq, Error> {
+        Err(not_a_byte())
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple, Error> {
+        Err(not_a_byte())
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleStruct, Error> {
+        Err(not_a_byte())
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant, Error> {
+        Err(not_a_byte())
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, Error> {
+        Err(not_a_byte())
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStruct, Error> {
+        Err(not_a_byte())
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant, Error> {
+        Err(not_a_byte())
+    }
+}
+
+fn not_a_byte() -> Error {
+    Error::InvalidDataModel("not a byte")
+}
@@ -1,29 +1,31 @@
-use algokit_msgpack::{Account, AlgoKitMsgPackError, ModelType};
+use algokit_transact::msgpack::{Account, AlgoKitMsgPackError, ModelType};
 use base64::engine::general_purpose::STANDARD as BASE64;
 use base64::Engine;
 
 #[test]
 fn test_account_decoding_error() {
     // Test that encoding returns the expected error
-    let mut account = Account::default();
-    account.address = "ABCDEFG".to_string();
-    account.amount = 100;
-    account.min_balance = 50;
-    account.amount_without_pending_rewards = 90;
-    account.pending_rewards = 10;
-    account.rewards = 20;
-    account.round = 1234;
-    account.status = "Online".to_string();
-    account.total_apps_opted_in = 0;
-    account.total_assets_opted_in = 0;
-    account.total_created_apps = 0;
-    account.total_created_assets = 0;
+    let account = Account {
+        address: "ABCDEFG".to_string(),
+        amount: 100,
+        min_balance: 50,
+        amount_without_pending_rewards: 90,
+        pending_rewards: 10,
+        rewards: 20,
+        round: 1234,
+        status: "Online".to_string(),
+        total_apps_opted_in: 0,
+        total_assets_opted_in: 0,
+        total_created_apps: 0,
+        total_created_assets: 0,
+        ..Default::default()
+    };
 
     // Convert to JSON
     let json = serde_json::to_string(&account).unwrap();
 
     // Test error response for encoding
-    let result = algokit_msgpack::encode_json_to_msgpack(ModelType::Account, &json);
+    let result = algokit_transact::msgpack::encode_json_to_msgpack(ModelType::Account, &json);
     assert!(result.is_err());
     if let Err(AlgoKitMsgPackError::MsgpackWriteError(msg)) = result {
         assert!(msg.contains("not supported"));
@@ -41,8 +43,9 @@ fn test_decode_simplified_account_msgpack() {
         .decode(msgpack_base64)
         .expect("Failed to decode base64");
 
-    let json_str = algokit_msgpack::decode_msgpack_to_json(ModelType::Account, &msgpack_bytes)
-        .expect("Failed to decode simplified account MessagePack");
+    let json_str =
+        algokit_transact::msgpack::decode_msgpack_to_json(ModelType::Account, &msgpack_bytes)
+            .expect("Failed to decode simplified account MessagePack");
 
     println!("Decoded JSON: {}", json_str);
 
@@ -61,8 +64,9 @@ fn test_decode_complex_account_msgpack() {
         .decode(msgpack_base64)
         .expect("Failed to decode base64");
 
-    let json_str = algokit_msgpack::decode_msgpack_to_json(ModelType::Account, &msgpack_bytes)
-        .expect("Failed to decode complex account MessagePack");
+    let json_str =
+        algokit_transact::msgpack::decode_msgpack_to_json(ModelType::Account, &msgpack_bytes)
+            .expect("Failed to decode complex account MessagePack");
 
     println!("Decoded JSON: {}", json_str);
 
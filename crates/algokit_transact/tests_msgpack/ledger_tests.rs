@@ -0,0 +1,126 @@
+use algokit_transact::msgpack::{
+    decode_msgpack_to_json, decode_msgpack_to_json_lossless, encode_json_to_msgpack_lossless,
+    stream_msgpack_to_json, Block, BlockReader, BytesFormat, LedgerStateDelta, ModelType,
+};
+use algokit_transact::Transaction;
+use serde_json::Value;
+
+const BLOCK: &str = include_str!("fixtures/block.json");
+const LEDGER_STATE_DELTA: &str = include_str!("fixtures/ledger_state_delta.json");
+
+/// Encodes a lossless JSON fixture to msgpack, checking it decodes back to the same JSON.
+fn fixture_msgpack(model_type: ModelType, json: &str) -> Vec<u8> {
+    let msgpack = encode_json_to_msgpack_lossless(model_type, json, BytesFormat::Base64).unwrap();
+    let decoded =
+        decode_msgpack_to_json_lossless(model_type, &msgpack, BytesFormat::Base64).unwrap();
+    assert_eq!(
+        serde_json::from_str::<Value>(&decoded).unwrap(),
+        serde_json::from_str::<Value>(json).unwrap()
+    );
+    msgpack
+}
+
+#[test]
+fn test_decode_block() {
+    let msgpack = fixture_msgpack(ModelType::Block, BLOCK);
+    let json = decode_msgpack_to_json(ModelType::Block, &msgpack).unwrap();
+    let block: Block = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(block.block.round, 48_213_607);
+    assert_eq!(block.block.genesis_id.as_deref(), Some("testnet-v1.0"));
+    assert_eq!(block.block.proposer_payout, Some(11_053_000));
+    let transactions = block.block.transactions.as_ref().unwrap();
+    assert_eq!(transactions.len(), 6);
+    assert!(transactions
+        .iter()
+        .all(|txn| txn.has_genesis_id == Some(true)));
+    assert_eq!(transactions[1].closing_amount, Some(8_731_205));
+    assert_eq!(transactions[3].config_asset, Some(726_543_140));
+
+    let app_call = &transactions[4];
+    let Transaction::ApplicationCall(call) = &app_call.signed_transaction.transaction else {
+        panic!("expected an application call");
+    };
+    assert_eq!(call.app_id, 726_543_112);
+    assert!(app_call.signed_transaction.logic_signature.is_some());
+    let eval_delta = app_call.eval_delta.as_ref().unwrap();
+    assert_eq!(
+        eval_delta.global_delta.as_ref().unwrap()["counter"].uint,
+        Some(42)
+    );
+    assert_eq!(
+        eval_delta.local_deltas.as_ref().unwrap()["1"]["stale"].action,
+        3
+    );
+    let inner_txns = eval_delta.inner_txns.as_ref().unwrap();
+    assert!(matches!(
+        inner_txns[0].signed_transaction.transaction,
+        Transaction::Payment(_)
+    ));
+    assert!(matches!(
+        transactions[5].signed_transaction.transaction,
+        Transaction::KeyRegistration(_)
+    ));
+
+    let cert = block.cert.as_ref().unwrap();
+    assert_eq!(cert.round, 48_213_607);
+    assert_eq!(cert.votes.as_ref().unwrap().len(), 3);
+
+    // Streaming the block gives the same JSON and model
+    let mut streamed = Vec::new();
+    stream_msgpack_to_json(&msgpack[..], &mut streamed).unwrap();
+    let streamed: Block = serde_json::from_slice(&streamed).unwrap();
+    assert_eq!(streamed, block);
+    let mut block_reader = BlockReader::new(&msgpack[..]).unwrap();
+    let read: Vec<_> = block_reader
+        .transactions()
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(&read, transactions);
+    assert_eq!(block_reader.finish().unwrap().cert, block.cert);
+}
+
+#[test]
+fn test_decode_ledger_state_delta() {
+    let msgpack = fixture_msgpack(ModelType::LedgerStateDelta, LEDGER_STATE_DELTA);
+    let json = decode_msgpack_to_json(ModelType::LedgerStateDelta, &msgpack).unwrap();
+    let delta: LedgerStateDelta = serde_json::from_str(&json).unwrap();
+
+    let accounts = delta.accounts.as_ref().unwrap();
+    let base_data = &accounts.accounts.as_ref().unwrap()[0]
+        .account_data
+        .account_base_data;
+    assert_eq!(base_data.micro_algos, Some(150_302_117_904));
+    assert_eq!(base_data.incentive_eligible, Some(true));
+    let app_resources = accounts.app_resources.as_ref().unwrap();
+    let app_params = app_resources[0].params.params.as_ref().unwrap();
+    assert_eq!(app_params.global_state.as_ref().unwrap().len(), 2);
+    assert!(app_resources[1].state.local_state.is_some());
+    let asset_resources = accounts.asset_resources.as_ref().unwrap();
+    assert_eq!(
+        asset_resources[1].holding.holding.as_ref().unwrap().amount,
+        Some(1_500_000)
+    );
+
+    assert_eq!(delta.header.as_ref().unwrap().round, 48_213_607);
+    assert_eq!(delta.kv_mods.as_ref().unwrap().len(), 1);
+    assert_eq!(delta.txids.as_ref().unwrap().len(), 3);
+    assert_eq!(
+        delta.creatables.as_ref().unwrap()["726543140"].created,
+        Some(true)
+    );
+    assert_eq!(delta.prev_timestamp, Some(1_760_745_597));
+    assert_eq!(delta.state_proof_next, Some(48_213_760));
+    assert_eq!(delta.totals.as_ref().unwrap().rewards_level, Some(218_288));
+
+    // The leases are keyed by their sender and lease, so are kept as key and value entries
+    let txleases = delta.txleases.as_ref().unwrap();
+    assert_eq!(txleases.len(), 2);
+    assert!(txleases.iter().all(|entry| !entry.lease.sender.is_empty()));
+    assert_eq!(txleases[0].expires, 48_214_604);
+
+    let mut streamed = Vec::new();
+    stream_msgpack_to_json(&msgpack[..], &mut streamed).unwrap();
+    let streamed: LedgerStateDelta = serde_json::from_slice(&streamed).unwrap();
+    assert_eq!(streamed, delta);
+}
@@ -1,10 +1,13 @@
-use algokit_msgpack::{
-    decode_base64_msgpack_to_json, encode_json_to_base64_msgpack, encode_json_to_msgpack,
-    ModelType, SimulateTransaction200Response,
+use algokit_transact::msgpack::{
+    decode_base64_msgpack_to_json, decode_msgpack_to_json, decode_msgpack_to_json_lossless,
+    encode_json_to_base64_msgpack, encode_json_to_msgpack, encode_json_to_msgpack_lossless,
+    stream_msgpack_to_json, BytesFormat, ModelType, SimulateTransaction200Response,
 };
 use base64::engine::general_purpose::STANDARD as BASE64;
 use base64::Engine;
 
+const SIMULATE_RESPONSE: &str = include_str!("fixtures/simulate_response.json");
+
 #[test]
 fn test_decode_simulate_response_200() {
     let base64_msgpack = "hq5ldmFsLW92ZXJyaWRlc4S2YWxsb3ctZW1wdHktc2lnbmF0dXJlc8O3YWxsb3ctdW5uYW1lZC1yZXNvdXJjZXPDrW1heC1sb2ctY2FsbHPNCACsbWF4LWxvZy1zaXplzgABAACxZXhlYy10cmFjZS1jb25maWeEpmVuYWJsZcOuc2NyYXRjaC1jaGFuZ2XDrHN0YWNrLWNoYW5nZcOsc3RhdGUtY2hhbmdlw65pbml0aWFsLXN0YXRlc4CqbGFzdC1yb3VuZDWqdHhuLWdyb3Vwc5GBq3R4bi1yZXN1bHRzkYGqdHhuLXJlc3VsdIKqcG9vbC1lcnJvcqCjdHhugqNzaWfEQMRvOrLGLclzOfFppoyvhgTXsC+h/Qw59v5hc4k7CA9oVmEJZpcqjxweDlJg1C/vElTWwXL0zA/U59Ua/DjLhw+jdHhuiaNhbXTOAA9CQKNmZWXNA+iiZnY1o2dlbqxkb2NrZXJuZXQtdjGiZ2jEIEeJCm8ejvOqNCXVH+4GP95TdhioDiMH0wMRTIiwAmAUomx2zQQdo3JjdsQgOpJtq/2KwvdRn45on+Fhv0qXhguGb2ZMduXle8VCoPSjc25kxCA6km2r/YrC91Gfjmif4WG/SpeGC4ZvZkx25eV7xUKg9KR0eXBlo3Bhead2ZXJzaW9uAg==";
@@ -27,7 +30,8 @@ fn test_decode_simulate_response_200() {
 fn test_encode() {
     let simulate_request_json = r#"{"txn-groups": [{"txns": ["gqNzaWfEQC0RQ1E6Y+/iS6luFP6Q9c6Veo838jRIABcV+jSzetx61nlrmasonRDbxN02mbCESJw98o7IfKgQvSMvk9kE0gqjdHhuiaNhbXTOAA9CQKNmZWXNA+iiZnYzo2dlbqxkb2NrZXJuZXQtdjGiZ2jEIEeJCm8ejvOqNCXVH+4GP95TdhioDiMH0wMRTIiwAmAUomx2zQQbo3JjdsQg/x0nrFM+VxALq2Buu1UscgDBy0OKIY2MGnDzg8xkNaOjc25kxCD/HSesUz5XEAurYG67VSxyAMHLQ4ohjYwacPODzGQ1o6R0eXBlo3BheQ=="]}], "allow-empty-signatures": true, "allow-more-logging": true, "allow-unnamed-resources": true, "exec-trace-config": {"enable": true, "stack-change": true, "scratch-change": true, "state-change": true}}"#;
 
-    let expected_base64 = "hbZhbGxvdy1lbXB0eS1zaWduYXR1cmVzw7JhbGxvdy1tb3JlLWxvZ2dpbmfDt2FsbG93LXVubmFtZWQtcmVzb3VyY2Vzw7FleGVjLXRyYWNlLWNvbmZpZ4SmZW5hYmxlw6xzdGFjay1jaGFuZ2XDrnNjcmF0Y2gtY2hhbmdlw6xzdGF0ZS1jaGFuZ2XDqnR4bi1ncm91cHORgaR0eG5zkYKjc2lnxEAtEUNROmPv4kupbhT+kPXOlXqPN/I0SAAXFfo0s3rcetZ5a5mrKJ0Q28TdNpmwhEicPfKOyHyoEL0jL5PZBNIKo3R4bomjYW10zgAPQkCjZmVlzQPoomZ2M6NnZW6sZG9ja2VybmV0LXYxomdoxCBHiQpvHo7zqjQl1R/uBj/eU3YYqA4jB9MDEUyIsAJgFKJsds0EG6NyY3bEIP8dJ6xTPlcQC6tgbrtVLHIAwctDiiGNjBpw84PMZDWjo3NuZMQg/x0nrFM+VxALq2Buu1UscgDBy0OKIY2MGnDzg8xkNaOkdHlwZaNwYXk=";
+    // The keys of the exec trace config are sorted, as in the canonical encoding
+    let expected_base64 = "hbZhbGxvdy1lbXB0eS1zaWduYXR1cmVzw7JhbGxvdy1tb3JlLWxvZ2dpbmfDt2FsbG93LXVubmFtZWQtcmVzb3VyY2Vzw7FleGVjLXRyYWNlLWNvbmZpZ4SmZW5hYmxlw65zY3JhdGNoLWNoYW5nZcOsc3RhY2stY2hhbmdlw6xzdGF0ZS1jaGFuZ2XDqnR4bi1ncm91cHORgaR0eG5zkYKjc2lnxEAtEUNROmPv4kupbhT+kPXOlXqPN/I0SAAXFfo0s3rcetZ5a5mrKJ0Q28TdNpmwhEicPfKOyHyoEL0jL5PZBNIKo3R4bomjYW10zgAPQkCjZmVlzQPoomZ2M6NnZW6sZG9ja2VybmV0LXYxomdoxCBHiQpvHo7zqjQl1R/uBj/eU3YYqA4jB9MDEUyIsAJgFKJsds0EG6NyY3bEIP8dJ6xTPlcQC6tgbrtVLHIAwctDiiGNjBpw84PMZDWjo3NuZMQg/x0nrFM+VxALq2Buu1UscgDBy0OKIY2MGnDzg8xkNaOkdHlwZaNwYXk=";
 
     let msgpack_bytes = encode_json_to_msgpack(ModelType::SimulateRequest, simulate_request_json)
         .expect("Failed to encode SimulateRequest");
@@ -49,17 +53,86 @@ fn test_decode() {
         encode_json_to_base64_msgpack(ModelType::SimulateRequest, simulate_request_json)
             .expect("Failed to encode SimulateRequest");
 
-    // SimulateRequest decoding is intentionally not supported
-    let result = decode_base64_msgpack_to_json(ModelType::SimulateRequest, &msgpack_bytes);
-    assert!(
-        result.is_err(),
-        "SimulateRequest decoding should return an error"
+    let decoded_json = decode_base64_msgpack_to_json(ModelType::SimulateRequest, &msgpack_bytes)
+        .expect("Failed to decode SimulateRequest");
+
+    let expected: serde_json::Value = serde_json::from_str(simulate_request_json).unwrap();
+    let decoded: serde_json::Value = serde_json::from_str(&decoded_json).unwrap();
+    assert_eq!(decoded, expected);
+}
+
+#[test]
+fn test_decode_simulate_response_fixture() {
+    let model_type = ModelType::SimulateTransaction200Response;
+    let msgpack =
+        encode_json_to_msgpack_lossless(model_type, SIMULATE_RESPONSE, BytesFormat::Base64)
+            .unwrap();
+    let lossless =
+        decode_msgpack_to_json_lossless(model_type, &msgpack, BytesFormat::Base64).unwrap();
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&lossless).unwrap(),
+        serde_json::from_str::<serde_json::Value>(SIMULATE_RESPONSE).unwrap()
+    );
+
+    let json = decode_msgpack_to_json(model_type, &msgpack).unwrap();
+    let resp: SimulateTransaction200Response = serde_json::from_str(&json).unwrap();
+    assert_eq!(resp.version, 2);
+    assert_eq!(resp.last_round, 48_213_607);
+    assert_eq!(
+        resp.eval_overrides.as_ref().unwrap().extra_opcode_budget,
+        Some(700)
+    );
+
+    let group = &resp.txn_groups[0];
+    assert_eq!(group.app_budget_added, Some(1400));
+    let result = &group.txn_results[0];
+    assert_eq!(result.app_budget_consumed, Some(131));
+    let trace = result.exec_trace.as_ref().unwrap();
+    let approval_trace = trace.approval_program_trace.as_ref().unwrap();
+    assert_eq!(approval_trace.len(), 5);
+    assert_eq!(
+        approval_trace[1].scratch_changes.as_ref().unwrap()[0]
+            .new_value
+            .bytes,
+        Some(BASE64.encode("claimed"))
+    );
+    let state_changes = approval_trace[2].state_changes.as_ref().unwrap();
+    assert_eq!(state_changes[0].key, BASE64.encode("counter"));
+    assert_eq!(state_changes[1].operation, "d");
+    assert_eq!(approval_trace[3].spawned_inners, Some(vec![0]));
+
+    let txn_result = &result.txn_result;
+    assert_eq!(txn_result.logs, Some(vec![BASE64.encode("claimed")]));
+    assert_eq!(
+        txn_result.global_state_delta.as_ref().unwrap()[0]
+            .value
+            .uint,
+        Some(42)
+    );
+    assert_eq!(
+        txn_result.local_state_delta.as_ref().unwrap()[0].delta[0]
+            .value
+            .action,
+        3
+    );
+    assert_eq!(txn_result.inner_txns.as_ref().unwrap().len(), 1);
+
+    let unnamed = result.unnamed_resources_accessed.as_ref().unwrap();
+    assert_eq!(
+        unnamed.boxes.as_ref().unwrap()[0].name,
+        BASE64.encode("balances")
+    );
+    assert_eq!(unnamed.extra_box_refs, Some(1));
+
+    let initial_states = resp.initial_states.as_ref().unwrap();
+    let app_states = &initial_states.app_initial_states.as_ref().unwrap()[0];
+    assert_eq!(
+        app_states.app_globals.as_ref().unwrap().kvs[0].value.uint,
+        Some(41)
     );
 
-    if let Err(e) = result {
-        assert!(
-            e.to_string().contains("not supported"),
-            "Error should mention 'not supported'"
-        );
-    }
+    let mut streamed = Vec::new();
+    stream_msgpack_to_json(&msgpack[..], &mut streamed).unwrap();
+    let streamed: SimulateTransaction200Response = serde_json::from_slice(&streamed).unwrap();
+    assert_eq!(streamed, resp);
 }
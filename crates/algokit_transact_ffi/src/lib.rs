@@ -2,11 +2,16 @@ use algokit_transact::constants::*;
 use algokit_transact::msgpack::{
     decode_base64_msgpack_to_json as internal_decode_base64_msgpack_to_json,
     decode_msgpack_to_json as internal_decode_msgpack_to_json,
+    decode_msgpack_to_json_lossless as internal_decode_msgpack_to_json_lossless,
     encode_json_to_base64_msgpack as internal_encode_json_to_base64_msgpack,
     encode_json_to_msgpack as internal_encode_json_to_msgpack,
-    AlgoKitMsgPackError as InternalMsgPackError, ModelType as InternalModelType,
+    encode_json_to_msgpack_lossless as internal_encode_json_to_msgpack_lossless,
+    AlgoKitMsgPackError as InternalMsgPackError, BytesFormat as InternalBytesFormat,
+    ModelType as InternalModelType,
+};
+use algokit_transact::{
+    AlgorandMsgpack, Byte32, EstimateTransactionSize, TransactionId, Transactions,
 };
-use algokit_transact::{AlgorandMsgpack, Byte32, EstimateTransactionSize, TransactionId, Transactions};
 use ffi_macros::{ffi_enum, ffi_func, ffi_record};
 use serde::{Deserialize, Serialize};
 use serde_bytes::ByteBuf;
@@ -61,6 +66,31 @@ impl From<algokit_transact::AlgoKitTransactError> for AlgoKitTransactError {
             algokit_transact::AlgoKitTransactError::InvalidAddress(_) => {
                 AlgoKitTransactError::DecodingError(e.to_string())
             }
+            algokit_transact::AlgoKitTransactError::InvalidSignature(e) => {
+                AlgoKitTransactError::InputError(e.to_string())
+            }
+            algokit_transact::AlgoKitTransactError::InvalidMnemonicLength(_)
+            | algokit_transact::AlgoKitTransactError::InvalidMnemonicWord(_)
+            | algokit_transact::AlgoKitTransactError::InvalidMnemonicChecksum => {
+                AlgoKitTransactError::InputError(e.to_string())
+            }
+            algokit_transact::AlgoKitTransactError::InvalidAbiType(_)
+            | algokit_transact::AlgoKitTransactError::InvalidAbiMethod(_) => {
+                AlgoKitTransactError::InputError(e.to_string())
+            }
+            algokit_transact::AlgoKitTransactError::AbiEncodingError(_) => {
+                AlgoKitTransactError::EncodingError(e.to_string())
+            }
+            algokit_transact::AlgoKitTransactError::AbiDecodingError(_) => {
+                AlgoKitTransactError::DecodingError(e.to_string())
+            }
+            algokit_transact::AlgoKitTransactError::InvalidAppSpec(_)
+            | algokit_transact::AlgoKitTransactError::NonCanonicalEncoding(_) => {
+                AlgoKitTransactError::DecodingError(e.to_string())
+            }
+            algokit_transact::AlgoKitTransactError::InvalidTransaction(_) => {
+                AlgoKitTransactError::InputError(e.to_string())
+            }
         }
     }
 }
@@ -85,6 +115,10 @@ impl From<InternalMsgPackError> for AlgoKitTransactError {
             InternalMsgPackError::UnknownModelError(s) => AlgoKitTransactError::MsgPackError(s),
             InternalMsgPackError::IoError(s) => AlgoKitTransactError::MsgPackError(s),
             InternalMsgPackError::ValueWriteError(s) => AlgoKitTransactError::MsgPackError(s),
+            InternalMsgPackError::TransactionError(e) => {
+                AlgoKitTransactError::MsgPackError(e.to_string())
+            }
+            InternalMsgPackError::SchemaError(s) => AlgoKitTransactError::MsgPackError(s),
         }
     }
 }
@@ -133,6 +167,8 @@ pub enum TransactionType {
     AssetConfig,
     KeyRegistration,
     ApplicationCall,
+    StateProof,
+    Heartbeat,
 }
 
 #[ffi_record]
@@ -143,10 +179,10 @@ pub struct Address {
 
 impl From<algokit_transact::Address> for Address {
     fn from(value: algokit_transact::Address) -> Self {
-        return Self {
+        Self {
             address: value.to_string(),
             pub_key: value.pub_key.to_vec().into(),
-        };
+        }
     }
 }
 
@@ -177,6 +213,42 @@ pub struct FeeParams {
     max_fee: Option<u64>,
 }
 
+#[ffi_record]
+pub struct GroupFeeParams {
+    fee_per_byte: u64,
+    min_fee: u64,
+    transactions: Vec<TransactionFeeParams>,
+}
+
+#[ffi_record]
+pub struct TransactionFeeParams {
+    extra_fee: Option<u64>,
+    max_fee: Option<u64>,
+    inner_transactions: u64,
+}
+
+/// The consensus parameters which limit the contents of a transaction.
+#[ffi_record]
+pub struct ConsensusParams {
+    max_txn_life: u64,
+    max_txn_note_bytes: u64,
+    max_asset_decimals: u32,
+    max_asset_unit_name_bytes: u64,
+    max_asset_name_bytes: u64,
+    max_asset_url_bytes: u64,
+    max_app_args: u64,
+    max_app_total_arg_len: u64,
+    max_app_txn_accounts: u64,
+    max_app_txn_foreign_apps: u64,
+    max_app_txn_foreign_assets: u64,
+    max_app_box_references: u64,
+    max_app_total_txn_references: u64,
+    max_app_program_len: u64,
+    max_extra_app_program_pages: u64,
+    max_global_schema_entries: u64,
+    max_local_schema_entries: u64,
+}
+
 #[ffi_record]
 pub struct PaymentTransactionFields {
     receiver: Address,
@@ -199,6 +271,204 @@ pub struct AssetTransferTransactionFields {
     close_remainder_to: Option<Address>,
 }
 
+#[ffi_enum]
+pub enum OnApplicationComplete {
+    NoOp,
+    OptIn,
+    CloseOut,
+    ClearState,
+    UpdateApplication,
+    DeleteApplication,
+}
+
+#[ffi_record]
+pub struct StateSchema {
+    num_uints: u64,
+
+    num_byte_slices: u64,
+
+    /// The msgpack encoded map of the fields which aren't recognised, which are only retained
+    /// when decoding with the `_preserving_unknown_fields` functions.
+    unknown_fields: Option<ByteBuf>,
+}
+
+#[ffi_record]
+pub struct BoxReference {
+    app_id: u64,
+
+    name: ByteBuf,
+
+    /// The msgpack encoded map of the fields which aren't recognised, which are only retained
+    /// when decoding with the `_preserving_unknown_fields` functions.
+    unknown_fields: Option<ByteBuf>,
+}
+
+#[ffi_record]
+pub struct ApplicationCallTransactionFields {
+    app_id: u64,
+
+    on_complete: OnApplicationComplete,
+
+    approval_program: Option<ByteBuf>,
+
+    clear_state_program: Option<ByteBuf>,
+
+    global_state_schema: Option<StateSchema>,
+
+    local_state_schema: Option<StateSchema>,
+
+    extra_program_pages: Option<u64>,
+
+    args: Option<Vec<ByteBuf>>,
+
+    account_references: Option<Vec<Address>>,
+
+    app_references: Option<Vec<u64>>,
+
+    asset_references: Option<Vec<u64>>,
+
+    box_references: Option<Vec<BoxReference>>,
+
+    reject_version: Option<u64>,
+}
+
+#[ffi_record]
+pub struct AssetParams {
+    total: Option<u64>,
+
+    decimals: Option<u32>,
+
+    default_frozen: Option<bool>,
+
+    unit_name: Option<ByteBuf>,
+
+    asset_name: Option<ByteBuf>,
+
+    url: Option<ByteBuf>,
+
+    metadata_hash: Option<ByteBuf>,
+
+    manager: Option<Address>,
+
+    reserve: Option<Address>,
+
+    freeze: Option<Address>,
+
+    clawback: Option<Address>,
+
+    /// The msgpack encoded map of the fields which aren't recognised, which are only retained
+    /// when decoding with the `_preserving_unknown_fields` functions.
+    unknown_fields: Option<ByteBuf>,
+}
+
+#[ffi_record]
+pub struct AssetConfigTransactionFields {
+    asset_id: u64,
+
+    params: Option<AssetParams>,
+}
+
+#[ffi_record]
+pub struct AssetFreezeTransactionFields {
+    asset_id: u64,
+
+    freeze_target: Address,
+
+    frozen: bool,
+}
+
+#[ffi_record]
+pub struct KeyRegistrationTransactionFields {
+    /// The root participation public key.
+    vote_key: Option<ByteBuf>,
+
+    /// The VRF public key.
+    selection_key: Option<ByteBuf>,
+
+    /// The 64-byte state proof public key commitment.
+    state_proof_key: Option<ByteBuf>,
+
+    /// The first round that the participation key is valid.
+    vote_first: Option<u64>,
+
+    /// The last round that the participation key is valid.
+    vote_last: Option<u64>,
+
+    /// The dilution for the 2-level participation key.
+    vote_key_dilution: Option<u64>,
+
+    /// Mark the account as participating or non-participating in consensus.
+    non_participation: Option<bool>,
+}
+
+#[ffi_record]
+pub struct StateProofMessage {
+    /// The commitment to the block headers in the attested interval.
+    block_headers_commitment: ByteBuf,
+
+    /// The commitment to the voters for the next state proof.
+    voters_commitment: ByteBuf,
+
+    /// The natural log of the proven weight, scaled by 2^16.
+    ln_proven_weight: u64,
+
+    /// The first round attested to by the state proof.
+    first_attested_round: u64,
+
+    /// The last round attested to by the state proof.
+    last_attested_round: u64,
+}
+
+#[ffi_record]
+pub struct StateProofTransactionFields {
+    /// The type of the state proof.
+    state_proof_type: u64,
+
+    /// The msgpack encoded state proof.
+    ///
+    /// The proof is only meaningful to a state proof verifier, so it's kept in its encoded form.
+    state_proof: ByteBuf,
+
+    /// The message attested to by the state proof.
+    message: StateProofMessage,
+}
+
+#[ffi_record]
+pub struct HeartbeatProof {
+    /// The signature of the heartbeat seed by the one-time key.
+    signature: ByteBuf,
+
+    /// The one-time public key.
+    public_key: ByteBuf,
+
+    /// The batch public key which signs the one-time public key.
+    public_key_2: ByteBuf,
+
+    /// The signature of the one-time public key by the batch key.
+    public_key_1_signature: ByteBuf,
+
+    /// The signature of the batch public key by the vote key.
+    public_key_2_signature: ByteBuf,
+}
+
+#[ffi_record]
+pub struct HeartbeatTransactionFields {
+    /// The account this heartbeat is for.
+    address: Address,
+
+    /// The proof that the account's participation key is online.
+    proof: HeartbeatProof,
+
+    /// The block seed for the first valid round of the heartbeat.
+    seed: ByteBuf,
+
+    /// The vote ID (root participation public key) of the account.
+    vote_id: ByteBuf,
+
+    /// The key dilution of the account's participation key.
+    key_dilution: u64,
+}
+
 #[ffi_record]
 pub struct Transaction {
     /// The type of transaction
@@ -231,6 +501,25 @@ pub struct Transaction {
     payment: Option<PaymentTransactionFields>,
 
     asset_transfer: Option<AssetTransferTransactionFields>,
+
+    application_call: Option<ApplicationCallTransactionFields>,
+
+    asset_config: Option<AssetConfigTransactionFields>,
+
+    asset_freeze: Option<AssetFreezeTransactionFields>,
+
+    key_registration: Option<KeyRegistrationTransactionFields>,
+
+    state_proof: Option<StateProofTransactionFields>,
+
+    heartbeat: Option<HeartbeatTransactionFields>,
+
+    /// The msgpack encoded map of the fields which aren't recognised, such as fields added by a
+    /// newer protocol version.
+    ///
+    /// These are only retained when decoding with `decode_transaction_preserving_unknown_fields`,
+    /// and are emitted again when encoding, so the transaction ID is unchanged.
+    unknown_fields: Option<ByteBuf>,
 }
 
 impl TryFrom<Transaction> for algokit_transact::Transaction {
@@ -238,10 +527,19 @@ impl TryFrom<Transaction> for algokit_transact::Transaction {
 
     fn try_from(tx: Transaction) -> Result<Self, AlgoKitTransactError> {
         // Ensure there is never more than 1 transaction type specific field set
-        if [tx.payment.is_some(), tx.asset_transfer.is_some()]
-            .iter()
-            .filter(|&&x| x)
-            .count()
+        if [
+            tx.payment.is_some(),
+            tx.asset_transfer.is_some(),
+            tx.application_call.is_some(),
+            tx.asset_config.is_some(),
+            tx.asset_freeze.is_some(),
+            tx.key_registration.is_some(),
+            tx.state_proof.is_some(),
+            tx.heartbeat.is_some(),
+        ]
+        .iter()
+        .filter(|&&x| x)
+        .count()
             > 1
         {
             return Err(Self::Error::DecodingError(
@@ -254,10 +552,23 @@ impl TryFrom<Transaction> for algokit_transact::Transaction {
             TransactionType::AssetTransfer => {
                 Ok(algokit_transact::Transaction::AssetTransfer(tx.try_into()?))
             }
-            _ => {
-                return Err(Self::Error::DecodingError(
-                    "Transaction type is not implemented".to_string(),
-                ));
+            TransactionType::ApplicationCall => Ok(algokit_transact::Transaction::ApplicationCall(
+                tx.try_into()?,
+            )),
+            TransactionType::AssetConfig => {
+                Ok(algokit_transact::Transaction::AssetConfig(tx.try_into()?))
+            }
+            TransactionType::AssetFreeze => {
+                Ok(algokit_transact::Transaction::AssetFreeze(tx.try_into()?))
+            }
+            TransactionType::KeyRegistration => Ok(algokit_transact::Transaction::KeyRegistration(
+                tx.try_into()?,
+            )),
+            TransactionType::StateProof => {
+                Ok(algokit_transact::Transaction::StateProof(tx.try_into()?))
+            }
+            TransactionType::Heartbeat => {
+                Ok(algokit_transact::Transaction::Heartbeat(tx.try_into()?))
             }
         }
     }
@@ -278,6 +589,7 @@ impl TryFrom<Transaction> for algokit_transact::TransactionHeader {
             rekey_to: tx.rekey_to.map(TryInto::try_into).transpose()?,
             lease: tx.lease.map(bytebuf_to_byte32).transpose()?,
             group: tx.group.map(bytebuf_to_byte32).transpose()?,
+            unknown_fields: bytebuf_to_unknown_fields(tx.unknown_fields)?,
         })
     }
 }
@@ -350,127 +662,976 @@ impl TryFrom<Transaction> for algokit_transact::AssetTransferTransactionFields {
     }
 }
 
-impl TryFrom<algokit_transact::Transaction> for Transaction {
-    type Error = AlgoKitTransactError;
+impl From<algokit_transact::OnApplicationComplete> for OnApplicationComplete {
+    fn from(value: algokit_transact::OnApplicationComplete) -> Self {
+        match value {
+            algokit_transact::OnApplicationComplete::NoOp => OnApplicationComplete::NoOp,
+            algokit_transact::OnApplicationComplete::OptIn => OnApplicationComplete::OptIn,
+            algokit_transact::OnApplicationComplete::CloseOut => OnApplicationComplete::CloseOut,
+            algokit_transact::OnApplicationComplete::ClearState => {
+                OnApplicationComplete::ClearState
+            }
+            algokit_transact::OnApplicationComplete::UpdateApplication => {
+                OnApplicationComplete::UpdateApplication
+            }
+            algokit_transact::OnApplicationComplete::DeleteApplication => {
+                OnApplicationComplete::DeleteApplication
+            }
+        }
+    }
+}
 
-    fn try_from(tx: algokit_transact::Transaction) -> Result<Self, AlgoKitTransactError> {
-        match tx {
-            algokit_transact::Transaction::Payment(payment) => {
-                let payment_fields = payment.clone().into();
-                build_transaction(
-                    payment.header,
-                    TransactionType::Payment,
-                    Some(payment_fields),
-                    None,
-                )
+impl From<OnApplicationComplete> for algokit_transact::OnApplicationComplete {
+    fn from(value: OnApplicationComplete) -> Self {
+        match value {
+            OnApplicationComplete::NoOp => algokit_transact::OnApplicationComplete::NoOp,
+            OnApplicationComplete::OptIn => algokit_transact::OnApplicationComplete::OptIn,
+            OnApplicationComplete::CloseOut => algokit_transact::OnApplicationComplete::CloseOut,
+            OnApplicationComplete::ClearState => {
+                algokit_transact::OnApplicationComplete::ClearState
             }
-            algokit_transact::Transaction::AssetTransfer(asset_transfer) => {
-                let asset_transfer_fields = asset_transfer.clone().into();
-                build_transaction(
-                    asset_transfer.header,
-                    TransactionType::AssetTransfer,
-                    None,
-                    Some(asset_transfer_fields),
-                )
+            OnApplicationComplete::UpdateApplication => {
+                algokit_transact::OnApplicationComplete::UpdateApplication
+            }
+            OnApplicationComplete::DeleteApplication => {
+                algokit_transact::OnApplicationComplete::DeleteApplication
             }
         }
     }
 }
 
-#[ffi_record]
-pub struct SignedTransaction {
-    /// The transaction that has been signed.
-    pub transaction: Transaction,
+impl From<algokit_transact::StateSchema> for StateSchema {
+    fn from(value: algokit_transact::StateSchema) -> Self {
+        Self {
+            num_uints: value.num_uints,
+            num_byte_slices: value.num_byte_slices,
+            unknown_fields: unknown_fields_to_bytebuf(value.unknown_fields),
+        }
+    }
+}
 
-    /// Optional Ed25519 signature authorizing the transaction.
-    pub signature: Option<ByteBuf>,
+impl TryFrom<StateSchema> for algokit_transact::StateSchema {
+    type Error = AlgoKitTransactError;
 
-    /// Optional auth address applicable if the transaction sender is a rekeyed account.
-    pub auth_address: Option<Address>,
+    fn try_from(value: StateSchema) -> Result<Self, Self::Error> {
+        Ok(Self {
+            num_uints: value.num_uints,
+            num_byte_slices: value.num_byte_slices,
+            unknown_fields: bytebuf_to_unknown_fields(value.unknown_fields)?,
+        })
+    }
 }
 
-impl From<algokit_transact::SignedTransaction> for SignedTransaction {
-    fn from(signed_tx: algokit_transact::SignedTransaction) -> Self {
+impl From<algokit_transact::BoxReference> for BoxReference {
+    fn from(value: algokit_transact::BoxReference) -> Self {
         Self {
-            transaction: signed_tx.transaction.try_into().unwrap(),
-            signature: signed_tx.signature.map(|sig| sig.to_vec().into()),
-            auth_address: signed_tx.auth_address.map(Into::into),
+            app_id: value.app_id,
+            name: value.name.into(),
+            unknown_fields: unknown_fields_to_bytebuf(value.unknown_fields),
         }
     }
 }
 
-impl TryFrom<SignedTransaction> for algokit_transact::SignedTransaction {
+impl TryFrom<BoxReference> for algokit_transact::BoxReference {
     type Error = AlgoKitTransactError;
 
-    fn try_from(signed_tx: SignedTransaction) -> Result<Self, Self::Error> {
-        let signature = signed_tx
-            .signature
-            .map(|sig| {
-                sig.to_vec().try_into().map_err(|_| {
-                    AlgoKitTransactError::EncodingError(format!(
-                        "signature should be {} bytes",
-                        ALGORAND_SIGNATURE_BYTE_LENGTH
-                    ))
-                })
-            })
-            .transpose()?;
-
+    fn try_from(value: BoxReference) -> Result<Self, Self::Error> {
         Ok(Self {
-            transaction: signed_tx.transaction.try_into()?,
-            signature,
-            auth_address: signed_tx.auth_address.map(TryInto::try_into).transpose()?,
+            app_id: value.app_id,
+            name: value.name.into_vec(),
+            unknown_fields: bytebuf_to_unknown_fields(value.unknown_fields)?,
         })
     }
 }
 
-fn bytebuf_to_byte32(buf: ByteBuf) -> Result<Byte32, AlgoKitTransactError> {
-    let vec = buf.to_vec();
-    vec.try_into().map_err(|_| {
-        AlgoKitTransactError::DecodingError(
-            "Expected 32 bytes but got a different length".to_string(),
-        )
-    })
+impl From<algokit_transact::ApplicationCallTransactionFields> for ApplicationCallTransactionFields {
+    fn from(tx: algokit_transact::ApplicationCallTransactionFields) -> Self {
+        Self {
+            app_id: tx.app_id,
+            on_complete: tx.on_complete.into(),
+            approval_program: tx.approval_program.map(Into::into),
+            clear_state_program: tx.clear_state_program.map(Into::into),
+            global_state_schema: tx.global_state_schema.map(Into::into),
+            local_state_schema: tx.local_state_schema.map(Into::into),
+            extra_program_pages: tx.extra_program_pages,
+            args: tx
+                .args
+                .map(|args| args.into_iter().map(Into::into).collect()),
+            account_references: tx
+                .account_references
+                .map(|accounts| accounts.into_iter().map(Into::into).collect()),
+            app_references: tx.app_references,
+            asset_references: tx.asset_references,
+            box_references: tx
+                .box_references
+                .map(|boxes| boxes.into_iter().map(Into::into).collect()),
+            reject_version: tx.reject_version,
+        }
+    }
 }
 
-fn byte32_to_bytebuf(b32: Byte32) -> ByteBuf {
-    ByteBuf::from(b32.to_vec())
+impl TryFrom<Transaction> for algokit_transact::ApplicationCallTransactionFields {
+    type Error = AlgoKitTransactError;
+
+    fn try_from(tx: Transaction) -> Result<Self, Self::Error> {
+        if tx.transaction_type != TransactionType::ApplicationCall || tx.application_call.is_none()
+        {
+            return Err(Self::Error::DecodingError(
+                "Application Call data missing".to_string(),
+            ));
+        }
+
+        let data = tx.clone().application_call.unwrap();
+        let header: algokit_transact::TransactionHeader = tx.try_into()?;
+
+        Ok(Self {
+            header,
+            app_id: data.app_id,
+            on_complete: data.on_complete.into(),
+            approval_program: data.approval_program.map(ByteBuf::into_vec),
+            clear_state_program: data.clear_state_program.map(ByteBuf::into_vec),
+            global_state_schema: data
+                .global_state_schema
+                .map(TryInto::try_into)
+                .transpose()?,
+            local_state_schema: data.local_state_schema.map(TryInto::try_into).transpose()?,
+            extra_program_pages: data.extra_program_pages,
+            args: data
+                .args
+                .map(|args| args.into_iter().map(ByteBuf::into_vec).collect()),
+            account_references: data
+                .account_references
+                .map(|accounts| {
+                    accounts
+                        .into_iter()
+                        .map(TryInto::try_into)
+                        .collect::<Result<Vec<_>, _>>()
+                })
+                .transpose()?,
+            app_references: data.app_references,
+            asset_references: data.asset_references,
+            box_references: data
+                .box_references
+                .map(|boxes| {
+                    boxes
+                        .into_iter()
+                        .map(TryInto::try_into)
+                        .collect::<Result<Vec<_>, _>>()
+                })
+                .transpose()?,
+            reject_version: data.reject_version,
+        })
+    }
 }
 
-fn build_transaction(
-    header: algokit_transact::TransactionHeader,
-    transaction_type: TransactionType,
-    payment: Option<PaymentTransactionFields>,
-    asset_transfer: Option<AssetTransferTransactionFields>,
-) -> Result<Transaction, AlgoKitTransactError> {
-    Ok(Transaction {
-        transaction_type,
-        sender: header.sender.into(),
-        fee: header.fee,
-        first_valid: header.first_valid,
-        last_valid: header.last_valid,
-        genesis_id: header.genesis_id,
-        genesis_hash: header.genesis_hash.map(byte32_to_bytebuf),
-        note: header.note.map(Into::into),
-        rekey_to: header.rekey_to.map(Into::into),
-        lease: header.lease.map(byte32_to_bytebuf),
-        group: header.group.map(byte32_to_bytebuf),
-        payment,
-        asset_transfer,
-    })
+impl From<algokit_transact::AssetParams> for AssetParams {
+    fn from(params: algokit_transact::AssetParams) -> Self {
+        Self {
+            total: params.total,
+            decimals: params.decimals,
+            default_frozen: params.default_frozen,
+            unit_name: params.unit_name.map(Into::into),
+            asset_name: params.asset_name.map(Into::into),
+            url: params.url.map(Into::into),
+            metadata_hash: params.metadata_hash.map(byte32_to_bytebuf),
+            manager: params.manager.map(Into::into),
+            reserve: params.reserve.map(Into::into),
+            freeze: params.freeze.map(Into::into),
+            clawback: params.clawback.map(Into::into),
+            unknown_fields: unknown_fields_to_bytebuf(params.unknown_fields),
+        }
+    }
 }
 
-// Each function need to be explicitly renamed for WASM
-// and exported for UniFFI
+impl TryFrom<AssetParams> for algokit_transact::AssetParams {
+    type Error = AlgoKitTransactError;
 
-/// Get the transaction type from the encoded transaction.
-/// This is particularly useful when decoding a transaction that has an unknown type
-#[ffi_func]
-pub fn get_encoded_transaction_type(bytes: &[u8]) -> Result<TransactionType, AlgoKitTransactError> {
-    let decoded = algokit_transact::Transaction::decode(bytes)?;
+    fn try_from(params: AssetParams) -> Result<Self, Self::Error> {
+        Ok(Self {
+            total: params.total,
+            decimals: params.decimals,
+            default_frozen: params.default_frozen,
+            unit_name: params.unit_name.map(ByteBuf::into_vec),
+            asset_name: params.asset_name.map(ByteBuf::into_vec),
+            url: params.url.map(ByteBuf::into_vec),
+            metadata_hash: params.metadata_hash.map(bytebuf_to_byte32).transpose()?,
+            manager: params.manager.map(TryInto::try_into).transpose()?,
+            reserve: params.reserve.map(TryInto::try_into).transpose()?,
+            freeze: params.freeze.map(TryInto::try_into).transpose()?,
+            clawback: params.clawback.map(TryInto::try_into).transpose()?,
+            unknown_fields: bytebuf_to_unknown_fields(params.unknown_fields)?,
+        })
+    }
+}
 
-    match decoded {
-        algokit_transact::Transaction::Payment(_) => Ok(TransactionType::Payment),
-        algokit_transact::Transaction::AssetTransfer(_) => Ok(TransactionType::AssetTransfer),
+impl From<algokit_transact::AssetConfigTransactionFields> for AssetConfigTransactionFields {
+    fn from(tx: algokit_transact::AssetConfigTransactionFields) -> Self {
+        Self {
+            asset_id: tx.asset_id,
+            params: tx.params.map(Into::into),
+        }
+    }
+}
+
+impl TryFrom<Transaction> for algokit_transact::AssetConfigTransactionFields {
+    type Error = AlgoKitTransactError;
+
+    fn try_from(tx: Transaction) -> Result<Self, Self::Error> {
+        if tx.transaction_type != TransactionType::AssetConfig || tx.asset_config.is_none() {
+            return Err(Self::Error::DecodingError(
+                "Asset Config data missing".to_string(),
+            ));
+        }
+
+        let data = tx.clone().asset_config.unwrap();
+        let header: algokit_transact::TransactionHeader = tx.try_into()?;
+
+        Ok(Self {
+            header,
+            asset_id: data.asset_id,
+            params: data.params.map(TryInto::try_into).transpose()?,
+        })
+    }
+}
+
+impl From<algokit_transact::AssetFreezeTransactionFields> for AssetFreezeTransactionFields {
+    fn from(tx: algokit_transact::AssetFreezeTransactionFields) -> Self {
+        Self {
+            asset_id: tx.asset_id,
+            freeze_target: tx.freeze_target.into(),
+            frozen: tx.frozen,
+        }
+    }
+}
+
+impl TryFrom<Transaction> for algokit_transact::AssetFreezeTransactionFields {
+    type Error = AlgoKitTransactError;
+
+    fn try_from(tx: Transaction) -> Result<Self, Self::Error> {
+        if tx.transaction_type != TransactionType::AssetFreeze || tx.asset_freeze.is_none() {
+            return Err(Self::Error::DecodingError(
+                "Asset Freeze data missing".to_string(),
+            ));
+        }
+
+        let data = tx.clone().asset_freeze.unwrap();
+        let header: algokit_transact::TransactionHeader = tx.try_into()?;
+
+        Ok(Self {
+            header,
+            asset_id: data.asset_id,
+            freeze_target: data.freeze_target.try_into()?,
+            frozen: data.frozen,
+        })
+    }
+}
+
+impl From<algokit_transact::KeyRegistrationTransactionFields> for KeyRegistrationTransactionFields {
+    fn from(tx: algokit_transact::KeyRegistrationTransactionFields) -> Self {
+        Self {
+            vote_key: tx.vote_key.map(byte32_to_bytebuf),
+            selection_key: tx.selection_key.map(byte32_to_bytebuf),
+            state_proof_key: tx.state_proof_key.map(|key| key.to_vec().into()),
+            vote_first: tx.vote_first,
+            vote_last: tx.vote_last,
+            vote_key_dilution: tx.vote_key_dilution,
+            non_participation: tx.non_participation,
+        }
+    }
+}
+
+impl TryFrom<Transaction> for algokit_transact::KeyRegistrationTransactionFields {
+    type Error = AlgoKitTransactError;
+
+    fn try_from(tx: Transaction) -> Result<Self, Self::Error> {
+        if tx.transaction_type != TransactionType::KeyRegistration || tx.key_registration.is_none()
+        {
+            return Err(Self::Error::DecodingError(
+                "Key Registration data missing".to_string(),
+            ));
+        }
+
+        let data = tx.clone().key_registration.unwrap();
+        let header: algokit_transact::TransactionHeader = tx.try_into()?;
+
+        Ok(Self {
+            header,
+            vote_key: data.vote_key.map(bytebuf_to_byte32).transpose()?,
+            selection_key: data.selection_key.map(bytebuf_to_byte32).transpose()?,
+            state_proof_key: data
+                .state_proof_key
+                .map(|key| {
+                    key.to_vec().try_into().map_err(|_| {
+                        AlgoKitTransactError::DecodingError(format!(
+                            "state proof key should be {} bytes",
+                            ALGORAND_STATE_PROOF_KEY_BYTE_LENGTH
+                        ))
+                    })
+                })
+                .transpose()?,
+            vote_first: data.vote_first,
+            vote_last: data.vote_last,
+            vote_key_dilution: data.vote_key_dilution,
+            non_participation: data.non_participation,
+        })
+    }
+}
+
+impl From<algokit_transact::StateProofMessage> for StateProofMessage {
+    fn from(message: algokit_transact::StateProofMessage) -> Self {
+        Self {
+            block_headers_commitment: message.block_headers_commitment.into(),
+            voters_commitment: message.voters_commitment.into(),
+            ln_proven_weight: message.ln_proven_weight,
+            first_attested_round: message.first_attested_round,
+            last_attested_round: message.last_attested_round,
+        }
+    }
+}
+
+impl From<StateProofMessage> for algokit_transact::StateProofMessage {
+    fn from(message: StateProofMessage) -> Self {
+        Self {
+            block_headers_commitment: message.block_headers_commitment.into_vec(),
+            voters_commitment: message.voters_commitment.into_vec(),
+            ln_proven_weight: message.ln_proven_weight,
+            first_attested_round: message.first_attested_round,
+            last_attested_round: message.last_attested_round,
+        }
+    }
+}
+
+impl TryFrom<algokit_transact::StateProofTransactionFields> for StateProofTransactionFields {
+    type Error = AlgoKitTransactError;
+
+    fn try_from(tx: algokit_transact::StateProofTransactionFields) -> Result<Self, Self::Error> {
+        let state_proof = rmp_serde::to_vec_named(&tx.state_proof).map_err(|e| {
+            AlgoKitTransactError::EncodingError(format!("Failed to encode the state proof: {}", e))
+        })?;
+
+        Ok(Self {
+            state_proof_type: tx.state_proof_type,
+            state_proof: state_proof.into(),
+            message: tx.message.into(),
+        })
+    }
+}
+
+impl TryFrom<Transaction> for algokit_transact::StateProofTransactionFields {
+    type Error = AlgoKitTransactError;
+
+    fn try_from(tx: Transaction) -> Result<Self, Self::Error> {
+        if tx.transaction_type != TransactionType::StateProof || tx.state_proof.is_none() {
+            return Err(Self::Error::DecodingError(
+                "State Proof data missing".to_string(),
+            ));
+        }
+
+        let data = tx.clone().state_proof.unwrap();
+        let header: algokit_transact::TransactionHeader = tx.try_into()?;
+
+        Ok(Self {
+            header,
+            state_proof_type: data.state_proof_type,
+            state_proof: rmp_serde::from_slice(&data.state_proof).map_err(|e| {
+                AlgoKitTransactError::DecodingError(format!(
+                    "Failed to decode the state proof: {}",
+                    e
+                ))
+            })?,
+            message: data.message.into(),
+        })
+    }
+}
+
+impl From<algokit_transact::HeartbeatProof> for HeartbeatProof {
+    fn from(proof: algokit_transact::HeartbeatProof) -> Self {
+        Self {
+            signature: proof.signature.to_vec().into(),
+            public_key: byte32_to_bytebuf(proof.public_key),
+            public_key_2: byte32_to_bytebuf(proof.public_key_2),
+            public_key_1_signature: proof.public_key_1_signature.to_vec().into(),
+            public_key_2_signature: proof.public_key_2_signature.to_vec().into(),
+        }
+    }
+}
+
+impl TryFrom<HeartbeatProof> for algokit_transact::HeartbeatProof {
+    type Error = AlgoKitTransactError;
+
+    fn try_from(proof: HeartbeatProof) -> Result<Self, Self::Error> {
+        Ok(Self {
+            signature: bytebuf_to_signature(proof.signature)?,
+            public_key: bytebuf_to_byte32(proof.public_key)?,
+            public_key_2: bytebuf_to_byte32(proof.public_key_2)?,
+            public_key_1_signature: bytebuf_to_signature(proof.public_key_1_signature)?,
+            public_key_2_signature: bytebuf_to_signature(proof.public_key_2_signature)?,
+        })
+    }
+}
+
+impl From<algokit_transact::HeartbeatTransactionFields> for HeartbeatTransactionFields {
+    fn from(tx: algokit_transact::HeartbeatTransactionFields) -> Self {
+        Self {
+            address: tx.params.address.into(),
+            proof: tx.params.proof.into(),
+            seed: byte32_to_bytebuf(tx.params.seed),
+            vote_id: byte32_to_bytebuf(tx.params.vote_id),
+            key_dilution: tx.params.key_dilution,
+        }
+    }
+}
+
+impl TryFrom<Transaction> for algokit_transact::HeartbeatTransactionFields {
+    type Error = AlgoKitTransactError;
+
+    fn try_from(tx: Transaction) -> Result<Self, Self::Error> {
+        if tx.transaction_type != TransactionType::Heartbeat || tx.heartbeat.is_none() {
+            return Err(Self::Error::DecodingError(
+                "Heartbeat data missing".to_string(),
+            ));
+        }
+
+        let data = tx.clone().heartbeat.unwrap();
+        let header: algokit_transact::TransactionHeader = tx.try_into()?;
+
+        Ok(Self {
+            header,
+            params: algokit_transact::HeartbeatParams {
+                address: data.address.try_into()?,
+                proof: data.proof.try_into()?,
+                seed: bytebuf_to_byte32(data.seed)?,
+                vote_id: bytebuf_to_byte32(data.vote_id)?,
+                key_dilution: data.key_dilution,
+            },
+        })
+    }
+}
+
+impl TryFrom<algokit_transact::Transaction> for Transaction {
+    type Error = AlgoKitTransactError;
+
+    fn try_from(tx: algokit_transact::Transaction) -> Result<Self, AlgoKitTransactError> {
+        match tx {
+            algokit_transact::Transaction::Payment(payment) => {
+                let payment_fields = payment.clone().into();
+                build_transaction(
+                    payment.header,
+                    TransactionType::Payment,
+                    Some(payment_fields),
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                )
+            }
+            algokit_transact::Transaction::AssetTransfer(asset_transfer) => {
+                let asset_transfer_fields = asset_transfer.clone().into();
+                build_transaction(
+                    asset_transfer.header,
+                    TransactionType::AssetTransfer,
+                    None,
+                    Some(asset_transfer_fields),
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                )
+            }
+            algokit_transact::Transaction::ApplicationCall(application_call) => {
+                let application_call_fields = application_call.clone().into();
+                build_transaction(
+                    application_call.header,
+                    TransactionType::ApplicationCall,
+                    None,
+                    None,
+                    Some(application_call_fields),
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                )
+            }
+            algokit_transact::Transaction::AssetConfig(asset_config) => {
+                let asset_config_fields = asset_config.clone().into();
+                build_transaction(
+                    asset_config.header,
+                    TransactionType::AssetConfig,
+                    None,
+                    None,
+                    None,
+                    Some(asset_config_fields),
+                    None,
+                    None,
+                    None,
+                    None,
+                )
+            }
+            algokit_transact::Transaction::AssetFreeze(asset_freeze) => {
+                let asset_freeze_fields = asset_freeze.clone().into();
+                build_transaction(
+                    asset_freeze.header,
+                    TransactionType::AssetFreeze,
+                    None,
+                    None,
+                    None,
+                    None,
+                    Some(asset_freeze_fields),
+                    None,
+                    None,
+                    None,
+                )
+            }
+            algokit_transact::Transaction::KeyRegistration(key_registration) => {
+                let key_registration_fields = key_registration.clone().into();
+                build_transaction(
+                    key_registration.header,
+                    TransactionType::KeyRegistration,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    Some(key_registration_fields),
+                    None,
+                    None,
+                )
+            }
+            algokit_transact::Transaction::StateProof(state_proof) => {
+                let state_proof_fields = state_proof.clone().try_into()?;
+                build_transaction(
+                    state_proof.header,
+                    TransactionType::StateProof,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    Some(state_proof_fields),
+                    None,
+                )
+            }
+            algokit_transact::Transaction::Heartbeat(heartbeat) => {
+                let heartbeat_fields = heartbeat.clone().into();
+                build_transaction(
+                    heartbeat.header,
+                    TransactionType::Heartbeat,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    Some(heartbeat_fields),
+                )
+            }
+        }
+    }
+}
+
+#[ffi_record]
+pub struct SignedTransaction {
+    /// The transaction that has been signed.
+    pub transaction: Transaction,
+
+    /// Optional Ed25519 signature authorizing the transaction.
+    pub signature: Option<ByteBuf>,
+
+    /// Optional auth address applicable if the transaction sender is a rekeyed account.
+    pub auth_address: Option<Address>,
+
+    /// Optional multisig signature applicable if the transaction is authorized by a multisig
+    /// account.
+    pub multisignature: Option<MultisigSignature>,
+
+    /// Optional logic signature applicable if the transaction is authorized by a program.
+    pub logic_signature: Option<LogicSignature>,
+
+    /// The msgpack encoded map of the fields which aren't recognised, which are only retained
+    /// when decoding with `decode_signed_transaction_preserving_unknown_fields`.
+    pub unknown_fields: Option<ByteBuf>,
+}
+
+#[ffi_record]
+pub struct LogicSignature {
+    /// The compiled TEAL program.
+    logic: ByteBuf,
+
+    /// The arguments passed to the program.
+    args: Option<Vec<ByteBuf>>,
+
+    /// The Ed25519 signature of the program by the delegating account.
+    signature: Option<ByteBuf>,
+
+    /// The multisig signature of the program by the delegating multisig account.
+    ///
+    /// The signature covers only the program, so can be reused by any multisig account of the
+    /// same participants. Prefer `logic_multisignature`.
+    multisignature: Option<MultisigSignature>,
+
+    /// The multisig signature of the program and the address of the delegating multisig account.
+    logic_multisignature: Option<MultisigSignature>,
+
+    /// The msgpack encoded map of the fields which aren't recognised, which are only retained
+    /// when decoding with the `_preserving_unknown_fields` functions.
+    unknown_fields: Option<ByteBuf>,
+}
+
+impl From<algokit_transact::LogicSignature> for LogicSignature {
+    fn from(lsig: algokit_transact::LogicSignature) -> Self {
+        Self {
+            logic: lsig.logic.into(),
+            args: lsig
+                .args
+                .map(|args| args.into_iter().map(Into::into).collect()),
+            signature: lsig.signature.map(|sig| sig.to_vec().into()),
+            multisignature: lsig.multisignature.map(Into::into),
+            logic_multisignature: lsig.logic_multisignature.map(Into::into),
+            unknown_fields: unknown_fields_to_bytebuf(lsig.unknown_fields),
+        }
+    }
+}
+
+impl TryFrom<LogicSignature> for algokit_transact::LogicSignature {
+    type Error = AlgoKitTransactError;
+
+    fn try_from(lsig: LogicSignature) -> Result<Self, Self::Error> {
+        Ok(Self {
+            logic: lsig.logic.into_vec(),
+            args: lsig
+                .args
+                .map(|args| args.into_iter().map(ByteBuf::into_vec).collect()),
+            signature: lsig.signature.map(bytebuf_to_signature).transpose()?,
+            multisignature: lsig.multisignature.map(TryInto::try_into).transpose()?,
+            logic_multisignature: lsig
+                .logic_multisignature
+                .map(TryInto::try_into)
+                .transpose()?,
+            unknown_fields: bytebuf_to_unknown_fields(lsig.unknown_fields)?,
+        })
+    }
+}
+
+#[ffi_record]
+pub struct MultisigSubsignature {
+    /// The address of the participant.
+    address: Address,
+
+    /// The participant's Ed25519 signature, if they have signed.
+    signature: Option<ByteBuf>,
+}
+
+#[ffi_record]
+pub struct MultisigSignature {
+    /// The multisig version.
+    version: u8,
+
+    /// The number of participant signatures required to authorize a transaction.
+    threshold: u8,
+
+    /// The participant signatures, in the order the participants were specified when the
+    /// multisig account was created.
+    subsignatures: Vec<MultisigSubsignature>,
+
+    /// The msgpack encoded map of the fields which aren't recognised, which are only retained
+    /// when decoding with the `_preserving_unknown_fields` functions.
+    unknown_fields: Option<ByteBuf>,
+}
+
+impl From<algokit_transact::MultisigSignature> for MultisigSignature {
+    fn from(multisig: algokit_transact::MultisigSignature) -> Self {
+        Self {
+            version: multisig.version,
+            threshold: multisig.threshold,
+            subsignatures: multisig
+                .subsignatures
+                .into_iter()
+                .map(|subsig| MultisigSubsignature {
+                    address: subsig.address.into(),
+                    signature: subsig.signature.map(|sig| sig.to_vec().into()),
+                })
+                .collect(),
+            unknown_fields: unknown_fields_to_bytebuf(multisig.unknown_fields),
+        }
+    }
+}
+
+impl TryFrom<MultisigSignature> for algokit_transact::MultisigSignature {
+    type Error = AlgoKitTransactError;
+
+    fn try_from(multisig: MultisigSignature) -> Result<Self, Self::Error> {
+        Ok(Self {
+            version: multisig.version,
+            threshold: multisig.threshold,
+            subsignatures: multisig
+                .subsignatures
+                .into_iter()
+                .map(|subsig| {
+                    Ok(algokit_transact::MultisigSubsignature {
+                        address: subsig.address.try_into()?,
+                        signature: subsig.signature.map(bytebuf_to_signature).transpose()?,
+                    })
+                })
+                .collect::<Result<Vec<_>, AlgoKitTransactError>>()?,
+            unknown_fields: bytebuf_to_unknown_fields(multisig.unknown_fields)?,
+        })
+    }
+}
+
+#[ffi_record]
+pub struct AbiMethodArg {
+    /// The ARC-4 type of the argument, e.g. `uint64`, `pay` or `account`.
+    arg_type: String,
+
+    /// The optional name of the argument.
+    name: Option<String>,
+
+    /// The optional description of the argument.
+    description: Option<String>,
+}
+
+#[ffi_record]
+pub struct AbiMethod {
+    /// The name of the method.
+    name: String,
+
+    /// The optional description of the method.
+    description: Option<String>,
+
+    /// The arguments of the method.
+    args: Vec<AbiMethodArg>,
+
+    /// The ARC-4 return type of the method, or `None` if the method returns `void`.
+    returns: Option<String>,
+
+    /// The optional description of the return value.
+    returns_description: Option<String>,
+}
+
+#[ffi_record]
+pub struct AbiMethodCallArgs {
+    /// The application args, starting with the method selector.
+    args: Vec<ByteBuf>,
+
+    /// The accounts referenced by `account` arguments.
+    account_references: Vec<Address>,
+
+    /// The applications referenced by `application` arguments.
+    app_references: Vec<u64>,
+
+    /// The assets referenced by `asset` arguments.
+    asset_references: Vec<u64>,
+}
+
+impl From<algokit_transact::AbiMethod> for AbiMethod {
+    fn from(method: algokit_transact::AbiMethod) -> Self {
+        Self {
+            name: method.name,
+            description: method.description,
+            args: method
+                .args
+                .into_iter()
+                .map(|arg| AbiMethodArg {
+                    arg_type: arg.arg_type.to_string(),
+                    name: arg.name,
+                    description: arg.description,
+                })
+                .collect(),
+            returns: method.returns.map(|returns| returns.to_string()),
+            returns_description: method.returns_description,
+        }
+    }
+}
+
+impl TryFrom<AbiMethod> for algokit_transact::AbiMethod {
+    type Error = AlgoKitTransactError;
+
+    fn try_from(method: AbiMethod) -> Result<Self, Self::Error> {
+        Ok(Self {
+            name: method.name,
+            description: method.description,
+            args: method
+                .args
+                .into_iter()
+                .map(|arg| {
+                    Ok(algokit_transact::AbiMethodArg {
+                        arg_type: arg.arg_type.parse()?,
+                        name: arg.name,
+                        description: arg.description,
+                    })
+                })
+                .collect::<Result<Vec<_>, AlgoKitTransactError>>()?,
+            returns: method
+                .returns
+                .filter(|returns| returns != "void")
+                .map(|returns| returns.parse::<algokit_transact::AbiType>())
+                .transpose()?,
+            returns_description: method.returns_description,
+        })
+    }
+}
+
+impl From<algokit_transact::AbiMethodCallArgs> for AbiMethodCallArgs {
+    fn from(call_args: algokit_transact::AbiMethodCallArgs) -> Self {
+        Self {
+            args: call_args.args.into_iter().map(Into::into).collect(),
+            account_references: call_args
+                .account_references
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+            app_references: call_args.app_references,
+            asset_references: call_args.asset_references,
+        }
+    }
+}
+
+impl TryFrom<algokit_transact::SignedTransaction> for SignedTransaction {
+    type Error = AlgoKitTransactError;
+
+    fn try_from(signed_tx: algokit_transact::SignedTransaction) -> Result<Self, Self::Error> {
+        Ok(Self {
+            transaction: signed_tx.transaction.try_into()?,
+            signature: signed_tx.signature.map(|sig| sig.to_vec().into()),
+            auth_address: signed_tx.auth_address.map(Into::into),
+            multisignature: signed_tx.multisignature.map(Into::into),
+            logic_signature: signed_tx.logic_signature.map(Into::into),
+            unknown_fields: unknown_fields_to_bytebuf(signed_tx.unknown_fields),
+        })
+    }
+}
+
+impl TryFrom<SignedTransaction> for algokit_transact::SignedTransaction {
+    type Error = AlgoKitTransactError;
+
+    fn try_from(signed_tx: SignedTransaction) -> Result<Self, Self::Error> {
+        Ok(Self {
+            transaction: signed_tx.transaction.try_into()?,
+            signature: signed_tx.signature.map(bytebuf_to_signature).transpose()?,
+            auth_address: signed_tx.auth_address.map(TryInto::try_into).transpose()?,
+            multisignature: signed_tx
+                .multisignature
+                .map(TryInto::try_into)
+                .transpose()?,
+            logic_signature: signed_tx
+                .logic_signature
+                .map(TryInto::try_into)
+                .transpose()?,
+            unknown_fields: bytebuf_to_unknown_fields(signed_tx.unknown_fields)?,
+        })
+    }
+}
+
+fn bytebuf_to_signature(
+    buf: ByteBuf,
+) -> Result<[u8; ALGORAND_SIGNATURE_BYTE_LENGTH], AlgoKitTransactError> {
+    buf.to_vec().try_into().map_err(|_| {
+        AlgoKitTransactError::EncodingError(format!(
+            "signature should be {} bytes",
+            ALGORAND_SIGNATURE_BYTE_LENGTH
+        ))
+    })
+}
+
+fn bytebuf_to_byte32(buf: ByteBuf) -> Result<Byte32, AlgoKitTransactError> {
+    let vec = buf.to_vec();
+    vec.try_into().map_err(|_| {
+        AlgoKitTransactError::DecodingError(
+            "Expected 32 bytes but got a different length".to_string(),
+        )
+    })
+}
+
+fn byte32_to_bytebuf(b32: Byte32) -> ByteBuf {
+    ByteBuf::from(b32.to_vec())
+}
+
+fn unknown_fields_to_bytebuf(fields: algokit_transact::UnknownFields) -> Option<ByteBuf> {
+    if fields.is_empty() {
+        return None;
+    }
+    let encoded =
+        rmp_serde::to_vec(&fields).expect("a map of msgpack values can always be encoded");
+    Some(ByteBuf::from(encoded))
+}
+
+fn bytebuf_to_unknown_fields(
+    buf: Option<ByteBuf>,
+) -> Result<algokit_transact::UnknownFields, AlgoKitTransactError> {
+    buf.map(|fields| rmp_serde::from_slice(&fields))
+        .transpose()
+        .map_err(|e| {
+            AlgoKitTransactError::DecodingError(format!(
+                "Failed to decode the unknown fields: {}",
+                e
+            ))
+        })
+        .map(Option::unwrap_or_default)
+}
+
+#[allow(clippy::too_many_arguments)]
+fn build_transaction(
+    header: algokit_transact::TransactionHeader,
+    transaction_type: TransactionType,
+    payment: Option<PaymentTransactionFields>,
+    asset_transfer: Option<AssetTransferTransactionFields>,
+    application_call: Option<ApplicationCallTransactionFields>,
+    asset_config: Option<AssetConfigTransactionFields>,
+    asset_freeze: Option<AssetFreezeTransactionFields>,
+    key_registration: Option<KeyRegistrationTransactionFields>,
+    state_proof: Option<StateProofTransactionFields>,
+    heartbeat: Option<HeartbeatTransactionFields>,
+) -> Result<Transaction, AlgoKitTransactError> {
+    Ok(Transaction {
+        transaction_type,
+        sender: header.sender.into(),
+        fee: header.fee,
+        first_valid: header.first_valid,
+        last_valid: header.last_valid,
+        genesis_id: header.genesis_id,
+        genesis_hash: header.genesis_hash.map(byte32_to_bytebuf),
+        note: header.note.map(Into::into),
+        rekey_to: header.rekey_to.map(Into::into),
+        lease: header.lease.map(byte32_to_bytebuf),
+        group: header.group.map(byte32_to_bytebuf),
+        payment,
+        asset_transfer,
+        application_call,
+        asset_config,
+        asset_freeze,
+        key_registration,
+        state_proof,
+        heartbeat,
+        unknown_fields: unknown_fields_to_bytebuf(header.unknown_fields),
+    })
+}
+
+// Each function need to be explicitly renamed for WASM
+// and exported for UniFFI
+
+/// Get the transaction type from the encoded transaction.
+/// This is particularly useful when decoding a transaction that has an unknown type
+#[ffi_func]
+pub fn get_encoded_transaction_type(bytes: &[u8]) -> Result<TransactionType, AlgoKitTransactError> {
+    let decoded = algokit_transact::Transaction::decode(bytes)?;
+
+    match decoded {
+        algokit_transact::Transaction::Payment(_) => Ok(TransactionType::Payment),
+        algokit_transact::Transaction::AssetTransfer(_) => Ok(TransactionType::AssetTransfer),
+        algokit_transact::Transaction::ApplicationCall(_) => Ok(TransactionType::ApplicationCall),
+        algokit_transact::Transaction::AssetConfig(_) => Ok(TransactionType::AssetConfig),
+        algokit_transact::Transaction::AssetFreeze(_) => Ok(TransactionType::AssetFreeze),
+        algokit_transact::Transaction::KeyRegistration(_) => Ok(TransactionType::KeyRegistration),
+        algokit_transact::Transaction::StateProof(_) => Ok(TransactionType::StateProof),
+        algokit_transact::Transaction::Heartbeat(_) => Ok(TransactionType::Heartbeat),
     }
 }
 
@@ -518,96 +1679,417 @@ pub fn encode_transaction_raw(tx: Transaction) -> Result<Vec<u8>, AlgoKitTransac
     Ok(ctx.encode_raw()?)
 }
 
-/// Decodes MsgPack bytes into a transaction.
-///
-/// # Parameters
-/// * `encoded_tx` - MsgPack encoded bytes representing a transaction.
+/// Decodes MsgPack bytes into a transaction.
+///
+/// # Parameters
+/// * `encoded_tx` - MsgPack encoded bytes representing a transaction.
+///
+/// # Returns
+/// A decoded transaction or an error if decoding fails.
+#[ffi_func]
+pub fn decode_transaction(encoded_tx: &[u8]) -> Result<Transaction, AlgoKitTransactError> {
+    let ctx: algokit_transact::Transaction = algokit_transact::Transaction::decode(encoded_tx)?;
+    ctx.try_into()
+}
+
+/// Decodes MsgPack bytes into a transaction, retaining the fields which aren't recognised, such
+/// as fields added by a newer protocol version, in `unknown_fields`.
+///
+/// # Parameters
+/// * `encoded_tx` - MsgPack encoded bytes representing a transaction.
+///
+/// # Returns
+/// A decoded transaction or an error if decoding fails.
+#[ffi_func]
+pub fn decode_transaction_preserving_unknown_fields(
+    encoded_tx: &[u8],
+) -> Result<Transaction, AlgoKitTransactError> {
+    let ctx: algokit_transact::Transaction =
+        algokit_transact::Transaction::decode_preserving_unknown_fields(encoded_tx)?;
+    ctx.try_into()
+}
+
+/// Decodes MsgPack bytes into a transaction, requiring them to be the canonical encoding of the
+/// transaction.
+///
+/// # Parameters
+/// * `encoded_tx` - MsgPack encoded bytes representing a transaction.
+///
+/// # Returns
+/// A decoded transaction or an error if decoding fails or the encoding is not canonical.
+#[ffi_func]
+pub fn decode_transaction_strict(encoded_tx: &[u8]) -> Result<Transaction, AlgoKitTransactError> {
+    let ctx: algokit_transact::Transaction =
+        algokit_transact::Transaction::decode_strict(encoded_tx)?;
+    ctx.try_into()
+}
+
+/// Decodes a collection of MsgPack bytes into a transaction collection.
+///
+/// # Parameters
+/// * `encoded_txs` - A collection of MsgPack encoded bytes, each representing a transaction.
+///
+/// # Returns
+/// A collection of decoded transactions or an error if decoding fails.
+#[cfg(feature = "ffi_wasm")]
+#[ffi_func]
+pub fn decode_transactions(
+    encoded_txs: Vec<Uint8Array>,
+) -> Result<Vec<Transaction>, AlgoKitTransactError> {
+    encoded_txs
+        .iter()
+        .map(|bytes| decode_transaction(bytes.to_vec().as_slice()))
+        .collect()
+}
+
+/// Decodes a collection of MsgPack bytes into a transaction collection.
+///
+/// # Parameters
+/// * `encoded_txs` - A collection of MsgPack encoded bytes, each representing a transaction.
+///
+/// # Returns
+/// A collection of decoded transactions or an error if decoding fails.
+#[cfg(not(feature = "ffi_wasm"))]
+#[ffi_func]
+pub fn decode_transactions(
+    encoded_txs: Vec<Vec<u8>>,
+) -> Result<Vec<Transaction>, AlgoKitTransactError> {
+    encoded_txs
+        .iter()
+        .map(|tx| decode_transaction(tx))
+        .collect()
+}
+
+/// Return the size of the transaction in bytes as if it was already signed and encoded.
+/// This is useful for estimating the fee for the transaction.
+#[ffi_func]
+pub fn estimate_transaction_size(transaction: Transaction) -> Result<u64, AlgoKitTransactError> {
+    let core_tx: algokit_transact::Transaction = transaction.try_into()?;
+    core_tx
+        .estimate_size()
+        .map_err(|e| {
+            AlgoKitTransactError::EncodingError(format!(
+                "Failed to estimate transaction size: {}",
+                e
+            ))
+        })?
+        .try_into()
+        .map_err(|_| {
+            AlgoKitTransactError::EncodingError("Failed to convert size to u64".to_string())
+        })
+}
+
+#[ffi_func]
+pub fn address_from_pub_key(pub_key: &[u8]) -> Result<Address, AlgoKitTransactError> {
+    Ok(
+        algokit_transact::Address::from_pubkey(pub_key.try_into().map_err(|_| {
+            AlgoKitTransactError::EncodingError(
+                format!(
+                    "public key should be {} bytes",
+                    ALGORAND_PUBLIC_KEY_BYTE_LENGTH
+                )
+                .to_string(),
+            )
+        })?)
+        .into(),
+    )
+}
+
+#[ffi_func]
+pub fn address_from_string(address: &str) -> Result<Address, AlgoKitTransactError> {
+    address
+        .parse::<algokit_transact::Address>()
+        .map(Into::into)
+        .map_err(|e| AlgoKitTransactError::EncodingError(e.to_string()))
+}
+
+/// Get the address of an application account.
+#[ffi_func]
+pub fn address_from_app_id(app_id: u64) -> Address {
+    algokit_transact::Address::from_app_id(app_id).into()
+}
+
+/// Get the address of a program (contract account).
+#[ffi_func]
+pub fn address_from_program(program: &[u8]) -> Address {
+    algokit_transact::Address::from_program(program).into()
+}
+
+/// Get the bytes a delegating account must sign to delegate to a logic signature's program.
+#[ffi_func]
+pub fn logic_signature_bytes_to_sign(
+    logic_signature: LogicSignature,
+) -> Result<Vec<u8>, AlgoKitTransactError> {
+    let logic_signature: algokit_transact::LogicSignature = logic_signature.try_into()?;
+    Ok(logic_signature.bytes_to_sign())
+}
+
+/// Get the bytes the participants of a delegating multisig account must sign for the
+/// `logic_multisignature` of a logic signature.
+#[ffi_func]
+pub fn logic_signature_multisig_bytes_to_sign(
+    logic_signature: LogicSignature,
+    multisig_address: Address,
+) -> Result<Vec<u8>, AlgoKitTransactError> {
+    let logic_signature: algokit_transact::LogicSignature = logic_signature.try_into()?;
+    Ok(logic_signature.multisig_bytes_to_sign(&multisig_address.try_into()?))
+}
+
+/// Creates an unsigned multisig signature for a multisig account.
+///
+/// # Parameters
+/// * `version` - The multisig version, which must currently be 1
+/// * `threshold` - The number of participant signatures required
+/// * `participants` - The addresses of the participants, in order
+#[ffi_func]
+pub fn new_multisig_signature(
+    version: u8,
+    threshold: u8,
+    participants: Vec<Address>,
+) -> Result<MultisigSignature, AlgoKitTransactError> {
+    let participants = participants
+        .into_iter()
+        .map(TryInto::try_into)
+        .collect::<Result<Vec<algokit_transact::Address>, _>>()?;
+    Ok(
+        algokit_transact::MultisigSignature::from_participants(version, threshold, participants)?
+            .into(),
+    )
+}
+
+/// Get the address of the multisig account a multisig signature is for.
+#[ffi_func]
+pub fn address_from_multisig_signature(
+    multisig: MultisigSignature,
+) -> Result<Address, AlgoKitTransactError> {
+    let multisig: algokit_transact::MultisigSignature = multisig.try_into()?;
+    Ok(multisig.address()?.into())
+}
+
+/// Applies a participant's signature to a multisig signature.
+///
+/// # Parameters
+/// * `multisig` - The multisig signature
+/// * `participant` - The address of the participant which signed
+/// * `signature` - The participant's Ed25519 signature of the transaction
+#[ffi_func]
+pub fn apply_multisig_subsignature(
+    multisig: MultisigSignature,
+    participant: Address,
+    signature: &[u8],
+) -> Result<MultisigSignature, AlgoKitTransactError> {
+    let multisig: algokit_transact::MultisigSignature = multisig.try_into()?;
+    let participant: algokit_transact::Address = participant.try_into()?;
+    let signature = bytebuf_to_signature(signature.to_vec().into())?;
+    Ok(multisig.apply_subsignature(&participant, signature)?.into())
+}
+
+/// Merges the participant signatures of two multisig signatures for the same multisig account.
+#[ffi_func]
+pub fn merge_multisignatures(
+    multisig_a: MultisigSignature,
+    multisig_b: MultisigSignature,
+) -> Result<MultisigSignature, AlgoKitTransactError> {
+    let multisig_a: algokit_transact::MultisigSignature = multisig_a.try_into()?;
+    let multisig_b: algokit_transact::MultisigSignature = multisig_b.try_into()?;
+    Ok(multisig_a.merge(&multisig_b)?.into())
+}
+
+/// Encodes a 32-byte seed (secret key) as a 25-word mnemonic.
+#[ffi_func]
+pub fn mnemonic_from_seed(seed: &[u8]) -> Result<String, AlgoKitTransactError> {
+    let seed: Byte32 = seed.try_into().map_err(|_| {
+        AlgoKitTransactError::InputError(format!(
+            "seed should be {} bytes",
+            ALGORAND_SECRET_KEY_BYTE_LENGTH
+        ))
+    })?;
+    Ok(algokit_transact::mnemonic_from_seed(&seed))
+}
+
+/// Decodes a 25-word mnemonic into the 32-byte seed (secret key) it encodes.
+#[ffi_func]
+pub fn seed_from_mnemonic(mnemonic: &str) -> Result<Vec<u8>, AlgoKitTransactError> {
+    Ok(algokit_transact::seed_from_mnemonic(mnemonic)?.to_vec())
+}
+
+fn parse_json(json: &str) -> Result<serde_json::Value, AlgoKitTransactError> {
+    serde_json::from_str(json).map_err(|e| AlgoKitTransactError::InputError(e.to_string()))
+}
+
+/// Parses an ABI method from its signature, e.g. `transfer(address,uint64)bool`.
+#[ffi_func]
+pub fn abi_method_from_signature(signature: &str) -> Result<AbiMethod, AlgoKitTransactError> {
+    Ok(signature.parse::<algokit_transact::AbiMethod>()?.into())
+}
+
+/// Parses an ABI method from its ARC-4 JSON description.
+#[ffi_func]
+pub fn abi_method_from_json(json: &str) -> Result<AbiMethod, AlgoKitTransactError> {
+    serde_json::from_str::<algokit_transact::AbiMethod>(json)
+        .map(Into::into)
+        .map_err(|e| AlgoKitTransactError::InputError(e.to_string()))
+}
+
+/// Get the signature of an ABI method, e.g. `transfer(address,uint64)bool`.
+#[ffi_func]
+pub fn abi_method_signature(method: AbiMethod) -> Result<String, AlgoKitTransactError> {
+    let method: algokit_transact::AbiMethod = method.try_into()?;
+    Ok(method.signature())
+}
+
+/// Get the 4-byte selector of an ABI method.
+#[ffi_func]
+pub fn abi_method_selector(method: AbiMethod) -> Result<Vec<u8>, AlgoKitTransactError> {
+    let method: algokit_transact::AbiMethod = method.try_into()?;
+    Ok(method.selector().to_vec())
+}
+
+/// Encodes the application args and foreign arrays of an ABI method call.
+///
+/// # Parameters
+/// * `method` - The method being called
+/// * `args` - The JSON value of each argument, excluding transaction arguments. Reference
+///   arguments are an address string (`account`) or an ID (`asset` and `application`).
+/// * `sender` - The sender of the application call
+/// * `app_id` - The ID of the called application
+#[ffi_func]
+pub fn encode_abi_method_call_args(
+    method: AbiMethod,
+    args: Vec<String>,
+    sender: Address,
+    app_id: u64,
+) -> Result<AbiMethodCallArgs, AlgoKitTransactError> {
+    let method: algokit_transact::AbiMethod = method.try_into()?;
+    let sender: algokit_transact::Address = sender.try_into()?;
+
+    let value_args: Vec<&algokit_transact::AbiMethodArg> = method
+        .args
+        .iter()
+        .filter(|arg| !arg.arg_type.is_transaction())
+        .collect();
+    if value_args.len() != args.len() {
+        return Err(AlgoKitTransactError::InputError(format!(
+            "{} expects {} argument values but got {}",
+            method.signature(),
+            value_args.len(),
+            args.len()
+        )));
+    }
+
+    let values = value_args
+        .into_iter()
+        .zip(&args)
+        .map(|(arg, json)| {
+            let json = parse_json(json)?;
+            let invalid = || {
+                AlgoKitTransactError::InputError(format!(
+                    "{} is not a valid {} argument",
+                    json, arg.arg_type
+                ))
+            };
+            Ok(match &arg.arg_type {
+                algokit_transact::AbiMethodArgType::Value(abi_type) => {
+                    algokit_transact::AbiMethodArgValue::Value(abi_type.value_from_json(&json)?)
+                }
+                algokit_transact::AbiMethodArgType::Reference(reference_type) => {
+                    match reference_type {
+                        algokit_transact::AbiReferenceType::Account => {
+                            algokit_transact::AbiMethodArgValue::Account(
+                                json.as_str()
+                                    .and_then(|address| address.parse().ok())
+                                    .ok_or_else(invalid)?,
+                            )
+                        }
+                        algokit_transact::AbiReferenceType::Asset => {
+                            algokit_transact::AbiMethodArgValue::Asset(
+                                json.as_u64().ok_or_else(invalid)?,
+                            )
+                        }
+                        algokit_transact::AbiReferenceType::Application => {
+                            algokit_transact::AbiMethodArgValue::Application(
+                                json.as_u64().ok_or_else(invalid)?,
+                            )
+                        }
+                    }
+                }
+                algokit_transact::AbiMethodArgType::Transaction(_) => return Err(invalid()),
+            })
+        })
+        .collect::<Result<Vec<_>, AlgoKitTransactError>>()?;
+
+    Ok(method.encode_call_args(&values, &sender, app_id)?.into())
+}
+
+/// Decodes the return value of an ABI method call from the last log of the call.
 ///
 /// # Returns
-/// A decoded transaction or an error if decoding fails.
+/// The JSON return value, or `None` if the method returns `void`.
 #[ffi_func]
-pub fn decode_transaction(encoded_tx: &[u8]) -> Result<Transaction, AlgoKitTransactError> {
-    let ctx: algokit_transact::Transaction = algokit_transact::Transaction::decode(encoded_tx)?;
-    Ok(ctx.try_into()?)
+pub fn decode_abi_method_return_value(
+    method: AbiMethod,
+    log: &[u8],
+) -> Result<Option<String>, AlgoKitTransactError> {
+    let method: algokit_transact::AbiMethod = method.try_into()?;
+    let Some(value) = method.decode_return_value(&[log.to_vec()])? else {
+        return Ok(None);
+    };
+    let returns = method
+        .returns
+        .expect("a value is only returned by non-void methods");
+    Ok(Some(returns.value_to_json(&value)?.to_string()))
 }
 
-/// Decodes a collection of MsgPack bytes into a transaction collection.
-///
-/// # Parameters
-/// * `encoded_txs` - A collection of MsgPack encoded bytes, each representing a transaction.
+/// Encodes a JSON value as a value of an ARC-4 ABI type.
 ///
-/// # Returns
-/// A collection of decoded transactions or an error if decoding fails.
-#[cfg(feature = "ffi_wasm")]
+/// Integers are JSON numbers or, when too large for a JSON number, decimal strings. Addresses are
+/// address strings, and arrays and tuples are JSON arrays.
 #[ffi_func]
-pub fn decode_transactions(
-    encoded_txs: Vec<Uint8Array>,
-) -> Result<Vec<Transaction>, AlgoKitTransactError> {
-    encoded_txs
-        .iter()
-        .map(|bytes| decode_transaction(bytes.to_vec().as_slice()))
-        .collect()
+pub fn encode_abi_value(abi_type: &str, value: &str) -> Result<Vec<u8>, AlgoKitTransactError> {
+    let abi_type: algokit_transact::AbiType = abi_type.parse()?;
+    Ok(abi_type.encode(&abi_type.value_from_json(&parse_json(value)?)?)?)
 }
 
-/// Decodes a collection of MsgPack bytes into a transaction collection.
-///
-/// # Parameters
-/// * `encoded_txs` - A collection of MsgPack encoded bytes, each representing a transaction.
-///
-/// # Returns
-/// A collection of decoded transactions or an error if decoding fails.
-#[cfg(not(feature = "ffi_wasm"))]
+/// Decodes a value of an ARC-4 ABI type to JSON.
 #[ffi_func]
-pub fn decode_transactions(
-    encoded_txs: Vec<Vec<u8>>,
-) -> Result<Vec<Transaction>, AlgoKitTransactError> {
-    encoded_txs
-        .iter()
-        .map(|tx| decode_transaction(tx))
-        .collect()
+pub fn decode_abi_value(abi_type: &str, bytes: &[u8]) -> Result<String, AlgoKitTransactError> {
+    let abi_type: algokit_transact::AbiType = abi_type.parse()?;
+    Ok(abi_type
+        .value_to_json(&abi_type.decode(bytes)?)?
+        .to_string())
 }
 
-/// Return the size of the transaction in bytes as if it was already signed and encoded.
-/// This is useful for estimating the fee for the transaction.
+/// Parses an ARC-56 application specification.
+///
+/// Application specifications are exchanged as ARC-56 JSON rather than as records, because
+/// ARC-56 defines the specification as a JSON document which the bindings can already parse
+/// natively. This function validates and normalises the JSON, and `arc56_abi_methods` returns the
+/// methods as `AbiMethod` records for use with the ABI functions.
+///
+/// # Returns
+/// The normalised ARC-56 JSON of the application specification.
 #[ffi_func]
-pub fn estimate_transaction_size(transaction: Transaction) -> Result<u64, AlgoKitTransactError> {
-    let core_tx: algokit_transact::Transaction = transaction.try_into()?;
-    return core_tx
-        .estimate_size()
-        .map_err(|e| {
-            AlgoKitTransactError::EncodingError(format!(
-                "Failed to estimate transaction size: {}",
-                e
-            ))
-        })?
-        .try_into()
-        .map_err(|_| {
-            AlgoKitTransactError::EncodingError("Failed to convert size to u64".to_string())
-        });
+pub fn arc56_from_json(json: &str) -> Result<String, AlgoKitTransactError> {
+    Ok(algokit_transact::Arc56Contract::from_json(json)?.to_json()?)
 }
 
+/// Parses an ARC-32 application specification, converting it to ARC-56.
+///
+/// # Returns
+/// The ARC-56 JSON of the application specification.
 #[ffi_func]
-pub fn address_from_pub_key(pub_key: &[u8]) -> Result<Address, AlgoKitTransactError> {
-    Ok(
-        algokit_transact::Address::from_pubkey(pub_key.try_into().map_err(|_| {
-            AlgoKitTransactError::EncodingError(
-                format!(
-                    "public key should be {} bytes",
-                    ALGORAND_PUBLIC_KEY_BYTE_LENGTH
-                )
-                .to_string(),
-            )
-        })?)
-        .into(),
-    )
+pub fn arc56_from_arc32_json(json: &str) -> Result<String, AlgoKitTransactError> {
+    Ok(algokit_transact::Arc56Contract::from_arc32_json(json)?.to_json()?)
 }
 
+/// Returns the ABI methods of an ARC-56 application specification.
 #[ffi_func]
-pub fn address_from_string(address: &str) -> Result<Address, AlgoKitTransactError> {
-    address
-        .parse::<algokit_transact::Address>()
-        .map(Into::into)
-        .map_err(|e| AlgoKitTransactError::EncodingError(e.to_string()))
+pub fn arc56_abi_methods(json: &str) -> Result<Vec<AbiMethod>, AlgoKitTransactError> {
+    algokit_transact::Arc56Contract::from_json(json)?
+        .methods
+        .iter()
+        .map(|method| Ok(method.to_abi_method()?.into()))
+        .collect()
 }
 
 /// Get the raw 32-byte transaction ID for a transaction.
@@ -716,7 +2198,125 @@ pub fn assign_fee(
 
     let updated_txn = txn_internal.assign_fee(fee_params_internal)?;
 
-    Ok(updated_txn.try_into()?)
+    updated_txn.try_into()
+}
+
+impl From<GroupFeeParams> for algokit_transact::GroupFeeParams {
+    fn from(value: GroupFeeParams) -> Self {
+        Self {
+            fee_per_byte: value.fee_per_byte,
+            min_fee: value.min_fee,
+            transactions: value
+                .transactions
+                .into_iter()
+                .map(|params| algokit_transact::TransactionFeeParams {
+                    extra_fee: params.extra_fee,
+                    max_fee: params.max_fee,
+                    inner_transactions: params.inner_transactions,
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Assigns the fees of a group of transactions, pooling them so one transaction can pay for
+/// others and for the inner transactions they issue.
+///
+/// # Parameters
+/// * `txns` - The transactions, in group order
+/// * `fee_params` - The group fee parameters, with fee parameters for each transaction
+///
+/// # Returns
+/// The transactions with fees assigned, or an error if the fees cannot be covered within the max
+/// fees of the transactions.
+#[ffi_func]
+pub fn assign_fees(
+    txns: Vec<Transaction>,
+    fee_params: GroupFeeParams,
+) -> Result<Vec<Transaction>, AlgoKitTransactError> {
+    let txns_internal: Vec<algokit_transact::Transaction> = txns
+        .into_iter()
+        .map(|tx| tx.try_into())
+        .collect::<Result<Vec<_>, _>>()?;
+
+    txns_internal
+        .assign_fees(&fee_params.into())?
+        .into_iter()
+        .map(|tx| tx.try_into())
+        .collect()
+}
+
+impl From<algokit_transact::ConsensusParams> for ConsensusParams {
+    fn from(value: algokit_transact::ConsensusParams) -> Self {
+        Self {
+            max_txn_life: value.max_txn_life,
+            max_txn_note_bytes: value.max_txn_note_bytes as u64,
+            max_asset_decimals: value.max_asset_decimals,
+            max_asset_unit_name_bytes: value.max_asset_unit_name_bytes as u64,
+            max_asset_name_bytes: value.max_asset_name_bytes as u64,
+            max_asset_url_bytes: value.max_asset_url_bytes as u64,
+            max_app_args: value.max_app_args as u64,
+            max_app_total_arg_len: value.max_app_total_arg_len as u64,
+            max_app_txn_accounts: value.max_app_txn_accounts as u64,
+            max_app_txn_foreign_apps: value.max_app_txn_foreign_apps as u64,
+            max_app_txn_foreign_assets: value.max_app_txn_foreign_assets as u64,
+            max_app_box_references: value.max_app_box_references as u64,
+            max_app_total_txn_references: value.max_app_total_txn_references as u64,
+            max_app_program_len: value.max_app_program_len as u64,
+            max_extra_app_program_pages: value.max_extra_app_program_pages,
+            max_global_schema_entries: value.max_global_schema_entries,
+            max_local_schema_entries: value.max_local_schema_entries,
+        }
+    }
+}
+
+impl From<ConsensusParams> for algokit_transact::ConsensusParams {
+    fn from(value: ConsensusParams) -> Self {
+        // Limits beyond the address space can't be exceeded, so they saturate
+        let to_usize = |limit: u64| usize::try_from(limit).unwrap_or(usize::MAX);
+        Self {
+            max_txn_life: value.max_txn_life,
+            max_txn_note_bytes: to_usize(value.max_txn_note_bytes),
+            max_asset_decimals: value.max_asset_decimals,
+            max_asset_unit_name_bytes: to_usize(value.max_asset_unit_name_bytes),
+            max_asset_name_bytes: to_usize(value.max_asset_name_bytes),
+            max_asset_url_bytes: to_usize(value.max_asset_url_bytes),
+            max_app_args: to_usize(value.max_app_args),
+            max_app_total_arg_len: to_usize(value.max_app_total_arg_len),
+            max_app_txn_accounts: to_usize(value.max_app_txn_accounts),
+            max_app_txn_foreign_apps: to_usize(value.max_app_txn_foreign_apps),
+            max_app_txn_foreign_assets: to_usize(value.max_app_txn_foreign_assets),
+            max_app_box_references: to_usize(value.max_app_box_references),
+            max_app_total_txn_references: to_usize(value.max_app_total_txn_references),
+            max_app_program_len: to_usize(value.max_app_program_len),
+            max_extra_app_program_pages: value.max_extra_app_program_pages,
+            max_global_schema_entries: value.max_global_schema_entries,
+            max_local_schema_entries: value.max_local_schema_entries,
+        }
+    }
+}
+
+/// Returns the consensus parameters of the current mainnet consensus protocol.
+#[ffi_func]
+pub fn mainnet_consensus_params() -> ConsensusParams {
+    algokit_transact::ConsensusParams::mainnet().into()
+}
+
+/// Checks that a transaction is well-formed under the supplied consensus parameters.
+///
+/// # Parameters
+/// * `txn` - The transaction to validate
+/// * `consensus_params` - The consensus parameters of the network
+///
+/// # Returns
+/// Nothing if the transaction is valid, or an error listing every violation found.
+#[ffi_func]
+pub fn validate_transaction(
+    txn: Transaction,
+    consensus_params: ConsensusParams,
+) -> Result<(), AlgoKitTransactError> {
+    let txn_internal: algokit_transact::Transaction = txn.try_into()?;
+    Ok(txn_internal.validate(&consensus_params.into())?)
 }
 
 /// Decodes a signed transaction.
@@ -729,7 +2329,39 @@ pub fn assign_fee(
 #[ffi_func]
 pub fn decode_signed_transaction(bytes: &[u8]) -> Result<SignedTransaction, AlgoKitTransactError> {
     let signed_tx = algokit_transact::SignedTransaction::decode(bytes)?;
-    Ok(signed_tx.into())
+    signed_tx.try_into()
+}
+
+/// Decodes a signed transaction, retaining the fields which aren't recognised, such as fields
+/// added by a newer protocol version.
+///
+/// # Parameters
+/// * `bytes` - The MsgPack encoded signed transaction bytes
+///
+/// # Returns
+/// The decoded SignedTransaction or an error if decoding fails.
+#[ffi_func]
+pub fn decode_signed_transaction_preserving_unknown_fields(
+    bytes: &[u8],
+) -> Result<SignedTransaction, AlgoKitTransactError> {
+    let signed_tx = algokit_transact::SignedTransaction::decode_preserving_unknown_fields(bytes)?;
+    signed_tx.try_into()
+}
+
+/// Decodes a signed transaction, requiring the bytes to be the canonical encoding of the signed
+/// transaction.
+///
+/// # Parameters
+/// * `bytes` - The MsgPack encoded signed transaction bytes
+///
+/// # Returns
+/// The decoded SignedTransaction or an error if decoding fails or the encoding is not canonical.
+#[ffi_func]
+pub fn decode_signed_transaction_strict(
+    bytes: &[u8],
+) -> Result<SignedTransaction, AlgoKitTransactError> {
+    let signed_tx = algokit_transact::SignedTransaction::decode_strict(bytes)?;
+    signed_tx.try_into()
 }
 
 /// Decodes a collection of MsgPack bytes into a signed transaction collection.
@@ -828,7 +2460,9 @@ pub fn encode_signed_transactions(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use algokit_transact::test_utils::{TestDataMother, TransactionMother};
+    use algokit_transact::test_utils::{
+        AddressMother, AppSpecMother, TestDataMother, TransactionMother,
+    };
     use pretty_assertions::assert_eq;
 
     #[test]
@@ -847,6 +2481,341 @@ mod tests {
         assert_eq!(tx_type, TransactionType::Payment);
     }
 
+    #[test]
+    fn test_application_call_round_trip_ffi() {
+        let data = TestDataMother::application_call();
+        let tx_ffi: Transaction = data.transaction.clone().try_into().unwrap();
+
+        let encoded = encode_transaction(tx_ffi.clone()).unwrap();
+        assert_eq!(encoded, data.unsigned_bytes);
+        assert_eq!(
+            get_encoded_transaction_type(&encoded).unwrap(),
+            TransactionType::ApplicationCall
+        );
+
+        let decoded = decode_transaction(&encoded).unwrap();
+        assert_eq!(decoded, tx_ffi);
+        assert_eq!(get_transaction_id(decoded).unwrap(), data.id);
+    }
+
+    #[test]
+    fn test_asset_create_round_trip_ffi() {
+        let data = TestDataMother::asset_create();
+        let tx_ffi: Transaction = data.transaction.clone().try_into().unwrap();
+
+        let encoded = encode_transaction(tx_ffi.clone()).unwrap();
+        assert_eq!(encoded, data.unsigned_bytes);
+        assert_eq!(
+            get_encoded_transaction_type(&encoded).unwrap(),
+            TransactionType::AssetConfig
+        );
+
+        let decoded = decode_transaction(&encoded).unwrap();
+        assert_eq!(decoded, tx_ffi);
+    }
+
+    #[test]
+    fn test_asset_freeze_round_trip_ffi() {
+        let data = TestDataMother::asset_freeze();
+        let tx_ffi: Transaction = data.transaction.clone().try_into().unwrap();
+
+        let encoded = encode_transaction(tx_ffi.clone()).unwrap();
+        assert_eq!(encoded, data.unsigned_bytes);
+        assert_eq!(
+            get_encoded_transaction_type(&encoded).unwrap(),
+            TransactionType::AssetFreeze
+        );
+
+        let decoded = decode_transaction(&encoded).unwrap();
+        assert_eq!(decoded, tx_ffi);
+        assert_eq!(get_transaction_id(decoded).unwrap(), data.id);
+    }
+
+    #[test]
+    fn test_online_key_registration_round_trip_ffi() {
+        let data = TestDataMother::online_key_registration();
+        let tx_ffi: Transaction = data.transaction.clone().try_into().unwrap();
+
+        let encoded = encode_transaction(tx_ffi.clone()).unwrap();
+        assert_eq!(encoded, data.unsigned_bytes);
+        assert_eq!(
+            get_encoded_transaction_type(&encoded).unwrap(),
+            TransactionType::KeyRegistration
+        );
+
+        let decoded = decode_transaction(&encoded).unwrap();
+        assert_eq!(decoded, tx_ffi);
+        assert_eq!(get_transaction_id(decoded).unwrap(), data.id);
+    }
+
+    #[test]
+    fn test_unknown_fields_round_trip_ffi() {
+        let tx = TransactionMother::simple_payment().build().unwrap();
+        let mut encoded = tx.encode().unwrap();
+        // Append a "zzz" field, which sorts after the known fields, to the map
+        encoded[2] += 1;
+        encoded.extend_from_slice(&[0xa3, b'z', b'z', b'z', 0x07]);
+
+        assert_eq!(decode_transaction(&encoded).unwrap().unknown_fields, None);
+
+        let decoded = decode_transaction_preserving_unknown_fields(&encoded).unwrap();
+        assert_eq!(
+            decoded.unknown_fields,
+            Some(ByteBuf::from(vec![0x81, 0xa3, b'z', b'z', b'z', 0x07]))
+        );
+        assert_eq!(encode_transaction(decoded.clone()).unwrap(), encoded);
+
+        let without_unknown_fields = Transaction {
+            unknown_fields: None,
+            ..decoded
+        };
+        assert_eq!(
+            encode_transaction(without_unknown_fields).unwrap(),
+            tx.encode().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_signed_transaction_unknown_fields_round_trip_ffi() {
+        let unknown_fields =
+            algokit_transact::UnknownFields::from([(String::from("zzz"), 7.into())]);
+        let mut tx = TransactionMother::asset_create().build().unwrap();
+        if let algokit_transact::Transaction::AssetConfig(fields) = &mut tx {
+            fields.params.as_mut().unwrap().unknown_fields = unknown_fields.clone();
+        }
+        let signed_tx = algokit_transact::SignedTransaction {
+            transaction: tx,
+            signature: Some([1; ALGORAND_SIGNATURE_BYTE_LENGTH]),
+            auth_address: None,
+            multisignature: None,
+            logic_signature: None,
+            unknown_fields,
+        };
+        let encoded = signed_tx.encode().unwrap();
+
+        let decoded = decode_signed_transaction_preserving_unknown_fields(&encoded).unwrap();
+        assert!(decoded.unknown_fields.is_some());
+        assert_eq!(encode_signed_transaction(decoded).unwrap(), encoded);
+
+        let decoded = decode_signed_transaction(&encoded).unwrap();
+        assert_eq!(decoded.unknown_fields, None);
+        assert_ne!(encode_signed_transaction(decoded).unwrap(), encoded);
+    }
+
+    #[test]
+    fn test_decode_only_transaction_types_ffi() {
+        for tx in [
+            TransactionMother::state_proof(),
+            TransactionMother::heartbeat(),
+        ] {
+            let encoded = tx.encode().unwrap();
+            let decoded = decode_transaction(&encoded).unwrap();
+            assert_eq!(
+                get_encoded_transaction_type(&encoded).unwrap(),
+                decoded.transaction_type
+            );
+            assert_eq!(encode_transaction(decoded.clone()).unwrap(), encoded);
+            assert_eq!(get_transaction_id(decoded).unwrap(), tx.id().unwrap());
+        }
+
+        let heartbeat = decode_transaction(&TransactionMother::heartbeat().encode().unwrap())
+            .unwrap()
+            .heartbeat
+            .unwrap();
+        assert_eq!(heartbeat.key_dilution, 1733);
+    }
+
+    #[test]
+    fn test_multisig_ffi() {
+        let participants: Vec<Address> = vec![
+            AddressMother::address().into(),
+            AddressMother::neil().into(),
+        ];
+        let multisig = new_multisig_signature(1, 1, participants.clone()).unwrap();
+        assert_eq!(
+            address_from_multisig_signature(multisig.clone()).unwrap(),
+            algokit_transact::Address::from_multisig(
+                1,
+                1,
+                &[AddressMother::address(), AddressMother::neil()]
+            )
+            .unwrap()
+            .into()
+        );
+
+        let signed_a =
+            apply_multisig_subsignature(multisig.clone(), participants[0].clone(), &[1; 64])
+                .unwrap();
+        let signed_b =
+            apply_multisig_subsignature(multisig, participants[1].clone(), &[2; 64]).unwrap();
+        let merged = merge_multisignatures(signed_a, signed_b).unwrap();
+
+        let data = TestDataMother::simple_payment();
+        let signed_tx = SignedTransaction {
+            transaction: data.transaction.try_into().unwrap(),
+            signature: None,
+            auth_address: None,
+            multisignature: Some(merged),
+            logic_signature: None,
+            unknown_fields: None,
+        };
+        let encoded = encode_signed_transaction(signed_tx.clone()).unwrap();
+        assert_eq!(decode_signed_transaction(&encoded).unwrap(), signed_tx);
+    }
+
+    #[test]
+    fn test_logic_signature_ffi() {
+        let program = [1, 32, 1, 1, 34];
+        let lsig = LogicSignature {
+            logic: program.to_vec().into(),
+            args: Some(vec![b"arg".to_vec().into()]),
+            signature: None,
+            multisignature: None,
+            logic_multisignature: None,
+            unknown_fields: None,
+        };
+        assert_eq!(
+            logic_signature_bytes_to_sign(lsig.clone()).unwrap(),
+            [b"Program".as_slice(), &program].concat()
+        );
+        let multisig_address = address_from_app_id(1);
+        assert_eq!(
+            logic_signature_multisig_bytes_to_sign(lsig.clone(), multisig_address.clone()).unwrap(),
+            [
+                b"MsigProgram".as_slice(),
+                &multisig_address.pub_key,
+                &program
+            ]
+            .concat()
+        );
+
+        let mut transaction: Transaction = TestDataMother::simple_payment()
+            .transaction
+            .try_into()
+            .unwrap();
+        transaction.sender = address_from_program(&program);
+
+        let signed_tx = SignedTransaction {
+            transaction,
+            signature: None,
+            auth_address: None,
+            multisignature: None,
+            logic_signature: Some(lsig),
+            unknown_fields: None,
+        };
+        let encoded = encode_signed_transaction(signed_tx.clone()).unwrap();
+        assert_eq!(decode_signed_transaction(&encoded).unwrap(), signed_tx);
+    }
+
+    #[test]
+    fn test_mnemonic_ffi() {
+        let seed = [7u8; ALGORAND_SECRET_KEY_BYTE_LENGTH];
+
+        let mnemonic = mnemonic_from_seed(&seed).unwrap();
+        assert_eq!(mnemonic.split(' ').count(), 25);
+        assert_eq!(seed_from_mnemonic(&mnemonic).unwrap(), seed.to_vec());
+
+        assert!(matches!(
+            mnemonic_from_seed(&seed[..31]),
+            Err(AlgoKitTransactError::InputError(_))
+        ));
+        assert!(matches!(
+            seed_from_mnemonic("abandon"),
+            Err(AlgoKitTransactError::InputError(_))
+        ));
+    }
+
+    #[test]
+    fn test_abi_method_ffi() {
+        let method = abi_method_from_signature("add(uint64,account,pay)uint128").unwrap();
+        assert_eq!(method.args[1].arg_type, "account");
+        assert_eq!(method.returns.as_deref(), Some("uint128"));
+        assert_eq!(
+            abi_method_signature(method.clone()).unwrap(),
+            "add(uint64,account,pay)uint128"
+        );
+
+        let json_method = abi_method_from_json(
+            r#"{"name":"add","args":[{"type":"uint64"},{"type":"account"},{"type":"pay"}],"returns":{"type":"uint128"}}"#,
+        )
+        .unwrap();
+        assert_eq!(json_method, method);
+
+        let sender: Address = AddressMother::address().into();
+        let other = AddressMother::neil();
+        let call_args = encode_abi_method_call_args(
+            method.clone(),
+            vec!["5".to_string(), format!("\"{}\"", other)],
+            sender.clone(),
+            1,
+        )
+        .unwrap();
+        assert_eq!(
+            call_args.args,
+            vec![
+                ByteBuf::from(abi_method_selector(method.clone()).unwrap()),
+                ByteBuf::from(encode_abi_value("uint64", "5").unwrap()),
+                ByteBuf::from(vec![1]),
+            ]
+        );
+        assert_eq!(call_args.account_references, vec![other.into()]);
+
+        assert!(encode_abi_method_call_args(method.clone(), vec![], sender, 1).is_err());
+
+        let log = [ABI_RETURN_PREFIX, &[0u8; 15], &[7]].concat();
+        assert_eq!(
+            decode_abi_method_return_value(method, &log).unwrap(),
+            Some("7".to_string())
+        );
+        assert_eq!(
+            decode_abi_value("(bool,string)", &[0x80, 0, 3, 0, 2, b'h', b'i']).unwrap(),
+            r#"[true,"hi"]"#
+        );
+    }
+
+    #[test]
+    fn test_app_spec_ffi() {
+        let arc56 = arc56_from_json(AppSpecMother::arc56_json()).unwrap();
+        let methods = arc56_abi_methods(&arc56).unwrap();
+        assert_eq!(
+            abi_method_signature(methods[1].clone()).unwrap(),
+            "increment(uint64,pay)uint64"
+        );
+
+        let arc56 = arc56_from_arc32_json(AppSpecMother::arc32_json()).unwrap();
+        let methods = arc56_abi_methods(&arc56).unwrap();
+        assert_eq!(methods.len(), 4);
+        assert_eq!(methods[0].name, "hello");
+
+        assert!(matches!(
+            arc56_from_json("{}"),
+            Err(AlgoKitTransactError::DecodingError(_))
+        ));
+    }
+
+    #[test]
+    fn test_address_from_invalid_string_ffi() {
+        let address = AddressMother::address().to_string();
+        for invalid in [
+            format!("{}1", &address[..57]),
+            address.to_lowercase(),
+            address[..57].to_string(),
+        ] {
+            assert!(matches!(
+                address_from_string(&invalid),
+                Err(AlgoKitTransactError::EncodingError(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_address_from_app_id_ffi() {
+        assert_eq!(
+            address_from_app_id(1002541853).address,
+            "XSKED5VKZZCSYNDWXZJI65JM2HP7HZFJWCOBIMOONKHTK5UVKENBNVDEYM"
+        );
+    }
+
     #[test]
     fn test_transaction_id_ffi() {
         let data = TestDataMother::simple_payment();
@@ -882,6 +2851,76 @@ mod tests {
             assert_eq!(grouped_tx.group.unwrap(), &expected_group);
         }
     }
+
+    #[test]
+    fn test_assign_fees_ffi() {
+        let tx1: Transaction = TestDataMother::simple_payment()
+            .transaction
+            .try_into()
+            .unwrap();
+        let tx2: Transaction = TestDataMother::opt_in_asset_transfer()
+            .transaction
+            .try_into()
+            .unwrap();
+
+        let txs = assign_fees(
+            vec![tx1, tx2],
+            GroupFeeParams {
+                fee_per_byte: 0,
+                min_fee: 1000,
+                transactions: vec![
+                    TransactionFeeParams {
+                        extra_fee: None,
+                        max_fee: None,
+                        inner_transactions: 1,
+                    },
+                    TransactionFeeParams {
+                        extra_fee: None,
+                        max_fee: Some(0),
+                        inner_transactions: 0,
+                    },
+                ],
+            },
+        )
+        .unwrap();
+
+        assert_eq!(txs[0].fee, Some(3000));
+        assert_eq!(txs[1].fee, Some(0));
+    }
+
+    #[test]
+    fn test_validate_transaction_ffi() {
+        let mut tx: Transaction = TestDataMother::simple_payment()
+            .transaction
+            .try_into()
+            .unwrap();
+        let params = mainnet_consensus_params();
+        assert_eq!(params.max_txn_note_bytes, 1024);
+        assert!(validate_transaction(tx.clone(), params.clone()).is_ok());
+
+        tx.note = Some(vec![0; 1025].into());
+        tx.last_valid = tx.first_valid + 1001;
+        let error = validate_transaction(tx, params).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Invalid transaction: validity window of 1001 rounds exceeds the max of 1000; note is 1025 bytes but must not exceed 1024"
+        );
+    }
+
+    #[test]
+    fn test_msgpack_lossless_ffi() {
+        let json = r#"{"name":"abcd","round":5,"value":"0102"}"#;
+        let msgpack =
+            encode_json_to_msgpack_lossless(ModelType::Box, json, BytesFormat::Hex).unwrap();
+        assert_eq!(
+            decode_msgpack_to_json_lossless(ModelType::Box, &msgpack, BytesFormat::Hex).unwrap(),
+            json
+        );
+        assert_eq!(
+            decode_msgpack_to_json_lossless(ModelType::Box, &msgpack, BytesFormat::Array).unwrap(),
+            r#"{"name":[171,205],"round":5,"value":[1,2]}"#
+        );
+    }
 }
 
 // ========== MessagePack FFI Functions ==========
@@ -893,6 +2932,15 @@ mod tests {
 pub enum ModelType {
     SimulateRequest,
     SimulateTransaction200Response,
+    Account,
+    AccountAssetInformation,
+    AccountApplicationInformation,
+    Block,
+    PendingTransactionResponse,
+    LedgerStateDelta,
+    TransactionParams,
+    Box,
+    StateProof,
 }
 
 impl From<ModelType> for InternalModelType {
@@ -902,6 +2950,17 @@ impl From<ModelType> for InternalModelType {
             ModelType::SimulateTransaction200Response => {
                 InternalModelType::SimulateTransaction200Response
             }
+            ModelType::Account => InternalModelType::Account,
+            ModelType::AccountAssetInformation => InternalModelType::AccountAssetInformation,
+            ModelType::AccountApplicationInformation => {
+                InternalModelType::AccountApplicationInformation
+            }
+            ModelType::Block => InternalModelType::Block,
+            ModelType::PendingTransactionResponse => InternalModelType::PendingTransactionResponse,
+            ModelType::LedgerStateDelta => InternalModelType::LedgerStateDelta,
+            ModelType::TransactionParams => InternalModelType::TransactionParams,
+            ModelType::Box => InternalModelType::Box,
+            ModelType::StateProof => InternalModelType::StateProof,
         }
     }
 }
@@ -913,6 +2972,17 @@ impl From<InternalModelType> for ModelType {
             InternalModelType::SimulateTransaction200Response => {
                 ModelType::SimulateTransaction200Response
             }
+            InternalModelType::Account => ModelType::Account,
+            InternalModelType::AccountAssetInformation => ModelType::AccountAssetInformation,
+            InternalModelType::AccountApplicationInformation => {
+                ModelType::AccountApplicationInformation
+            }
+            InternalModelType::Block => ModelType::Block,
+            InternalModelType::PendingTransactionResponse => ModelType::PendingTransactionResponse,
+            InternalModelType::LedgerStateDelta => ModelType::LedgerStateDelta,
+            InternalModelType::TransactionParams => ModelType::TransactionParams,
+            InternalModelType::Box => ModelType::Box,
+            InternalModelType::StateProof => ModelType::StateProof,
         }
     }
 }
@@ -962,6 +3032,57 @@ pub fn decode_base64_msgpack_to_json(
     )?)
 }
 
+/// How binary values are represented in JSON by the lossless msgpack conversion.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "ffi_wasm", derive(tsify_next::Tsify))]
+#[cfg_attr(feature = "ffi_wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[cfg_attr(feature = "ffi_uniffi", derive(uniffi::Enum))]
+pub enum BytesFormat {
+    Base64,
+    Hex,
+    Array,
+}
+
+impl From<BytesFormat> for InternalBytesFormat {
+    fn from(bytes_format: BytesFormat) -> Self {
+        match bytes_format {
+            BytesFormat::Base64 => InternalBytesFormat::Base64,
+            BytesFormat::Hex => InternalBytesFormat::Hex,
+            BytesFormat::Array => InternalBytesFormat::Array,
+        }
+    }
+}
+
+/// Encodes JSON produced by `decode_msgpack_to_json_lossless` to msgpack using the schema of the
+/// model type.
+#[ffi_func]
+pub fn encode_json_to_msgpack_lossless(
+    model_type: ModelType,
+    json_str: &str,
+    bytes_format: BytesFormat,
+) -> Result<Vec<u8>, AlgoKitTransactError> {
+    Ok(internal_encode_json_to_msgpack_lossless(
+        model_type.into(),
+        json_str,
+        bytes_format.into(),
+    )?)
+}
+
+/// Decodes msgpack to JSON with the raw structure of the msgpack, using the schema of the model
+/// type so that encoding the JSON returns the same msgpack.
+#[ffi_func]
+pub fn decode_msgpack_to_json_lossless(
+    model_type: ModelType,
+    msgpack_bytes: &[u8],
+    bytes_format: BytesFormat,
+) -> Result<String, AlgoKitTransactError> {
+    Ok(internal_decode_msgpack_to_json_lossless(
+        model_type.into(),
+        msgpack_bytes,
+        bytes_format.into(),
+    )?)
+}
+
 #[ffi_func]
 pub fn supported_models() -> Vec<ModelType> {
     algokit_transact::msgpack::supported_models()
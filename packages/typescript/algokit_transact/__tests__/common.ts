@@ -30,4 +30,4 @@ type TransactionTestData = {
   rekeyedSenderSignedBytes: Uint8Array;
 };
 
-export const testData = parseJson<Record<"simplePayment" | "optInAssetTransfer", TransactionTestData>>(jsonString);
+export const testData = parseJson<Record<"simplePayment" | "optInAssetTransfer" | "applicationCall" | "assetCreate" | "assetFreeze" | "onlineKeyRegistration", TransactionTestData>>(jsonString);